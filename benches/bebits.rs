@@ -2,6 +2,9 @@
 //! protocols (CCSDS, IPv4, DVB-S2). Mirrors the CCSDS TM Transfer Frame
 //! primary header, 6 octets / 11 fields.
 //!
+//! The same header is also read little-endian, and little-endian with `Lsb0`
+//! bit order, which share the integer bit reader with the big-endian path.
+//!
 //! Each shape is measured twice.
 //!
 //! The single-shot benches handle one struct per iteration. Consecutive
@@ -50,6 +53,59 @@ struct TmPrimaryHeader {
     fhp: u16,
 }
 
+/// `TmPrimaryHeader` read little-endian, Msb0.
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "little")]
+struct LeTmPrimaryHeader {
+    #[deku(bits = 2)]
+    tfvn: u8,
+    #[deku(bits = 10)]
+    scid: u16,
+    #[deku(bits = 3)]
+    vcid: u8,
+    #[deku(bits = 1)]
+    ocf: u8,
+    mcfc: u8,
+    vcfc: u8,
+    #[deku(bits = 1)]
+    tfs: u8,
+    #[deku(bits = 1)]
+    syn: u8,
+    #[deku(bits = 1)]
+    po: u8,
+    #[deku(bits = 2)]
+    sli: u8,
+    #[deku(bits = 11)]
+    fhp: u16,
+}
+
+/// `TmPrimaryHeader` read little-endian, Lsb0: the layout of most little-endian
+/// register maps and file formats.
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "little", bit_order = "lsb")]
+struct Lsb0TmPrimaryHeader {
+    #[deku(bits = 2)]
+    tfvn: u8,
+    #[deku(bits = 10)]
+    scid: u16,
+    #[deku(bits = 3)]
+    vcid: u8,
+    #[deku(bits = 1)]
+    ocf: u8,
+    mcfc: u8,
+    vcfc: u8,
+    #[deku(bits = 1)]
+    tfs: u8,
+    #[deku(bits = 1)]
+    syn: u8,
+    #[deku(bits = 1)]
+    po: u8,
+    #[deku(bits = 2)]
+    sli: u8,
+    #[deku(bits = 11)]
+    fhp: u16,
+}
+
 /// Same 6 octets, byte-aligned: the deku fast path, for scale. Also the control
 /// for any change to the bit paths, which must leave this one alone.
 #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
@@ -99,6 +155,18 @@ fn bench(c: &mut Criterion) {
             SixBytes::from_reader_with_ctx(&mut r, ()).unwrap()
         })
     });
+    c.bench_function("le_tm_primary_header_11_fields", |b| {
+        b.iter(|| {
+            let mut r = Reader::new(Cursor::new(black_box(&buf)));
+            LeTmPrimaryHeader::from_reader_with_ctx(&mut r, ()).unwrap()
+        })
+    });
+    c.bench_function("lsb0_tm_primary_header_11_fields", |b| {
+        b.iter(|| {
+            let mut r = Reader::new(Cursor::new(black_box(&buf)));
+            Lsb0TmPrimaryHeader::from_reader_with_ctx(&mut r, ()).unwrap()
+        })
+    });
     c.bench_function("be_one_bit_in_u64", |b| {
         b.iter(|| {
             let mut r = Reader::new(Cursor::new(black_box(&buf)));
@@ -123,6 +191,38 @@ fn bench(c: &mut Criterion) {
             acc
         })
     });
+    c.bench_function("le_tm_primary_header_x128", |b| {
+        b.iter(|| {
+            let mut r = Reader::new(Cursor::new(black_box(&stream)));
+            let mut acc: u64 = 0;
+            for _ in 0..FRAMES {
+                let h = LeTmPrimaryHeader::from_reader_with_ctx(&mut r, ()).unwrap();
+                acc ^= u64::from(h.scid)
+                    ^ u64::from(h.fhp)
+                    ^ u64::from(h.mcfc)
+                    ^ u64::from(h.vcid)
+                    ^ u64::from(h.tfvn)
+                    ^ u64::from(h.sli);
+            }
+            acc
+        })
+    });
+    c.bench_function("lsb0_tm_primary_header_x128", |b| {
+        b.iter(|| {
+            let mut r = Reader::new(Cursor::new(black_box(&stream)));
+            let mut acc: u64 = 0;
+            for _ in 0..FRAMES {
+                let h = Lsb0TmPrimaryHeader::from_reader_with_ctx(&mut r, ()).unwrap();
+                acc ^= u64::from(h.scid)
+                    ^ u64::from(h.fhp)
+                    ^ u64::from(h.mcfc)
+                    ^ u64::from(h.vcid)
+                    ^ u64::from(h.tfvn)
+                    ^ u64::from(h.sli);
+            }
+            acc
+        })
+    });
     c.bench_function("be_six_bytes_aligned_x128", |b| {
         b.iter(|| {
             let mut r = Reader::new(Cursor::new(black_box(&stream)));
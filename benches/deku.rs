@@ -175,9 +175,60 @@ pub fn read_all_vs_count_vs_read_exact(c: &mut Criterion) {
     });
 }
 
+pub fn vec_of_primitives(c: &mut Criterion) {
+    const SAMPLES: usize = 100_000;
+
+    #[derive(DekuRead, DekuWrite)]
+    #[deku(endian = "big")]
+    pub struct SamplesU16 {
+        #[deku(count = "SAMPLES")]
+        pub data: Vec<u16>,
+    }
+
+    #[derive(DekuRead, DekuWrite)]
+    #[deku(endian = "little")]
+    pub struct SamplesF32 {
+        #[deku(bytes_read = "SAMPLES * 4")]
+        pub data: Vec<f32>,
+    }
+
+    let input = vec![0x5a; SAMPLES * 4];
+
+    c.bench_function("read_vec_u16_be_100k", |b| {
+        b.iter(|| {
+            let mut cursor = Cursor::new(input.as_slice());
+            let mut reader = Reader::new(&mut cursor);
+            SamplesU16::from_reader_with_ctx(std::hint::black_box(&mut reader), ())
+        })
+    });
+
+    c.bench_function("read_vec_f32_le_100k", |b| {
+        b.iter(|| {
+            let mut cursor = Cursor::new(input.as_slice());
+            let mut reader = Reader::new(&mut cursor);
+            SamplesF32::from_reader_with_ctx(std::hint::black_box(&mut reader), ())
+        })
+    });
+
+    let samples_u16 = SamplesU16 {
+        data: (0..SAMPLES).map(|i| i as u16).collect(),
+    };
+    c.bench_function("write_vec_u16_be_100k", |b| {
+        b.iter(|| deku_write(std::hint::black_box(&samples_u16)))
+    });
+
+    let samples_f32 = SamplesF32 {
+        data: (0..SAMPLES).map(|i| i as f32).collect(),
+    };
+    c.bench_function("write_vec_f32_le_100k", |b| {
+        b.iter(|| deku_write(std::hint::black_box(&samples_f32)))
+    });
+}
+
 criterion_group!(
     benches,
     criterion_benchmark,
-    read_all_vs_count_vs_read_exact
+    read_all_vs_count_vs_read_exact,
+    vec_of_primitives
 );
 criterion_main!(benches);
@@ -21,35 +21,6 @@ use crate::macros::deku_write::emit_deku_write;
 
 mod macros;
 
-// Pad attribute values are not bounded by the implementation, however, for
-// implementation purposes we need a concrete object to contain padding data.
-// To allow use of padding in constrained environments we define the object as
-// an array (by contrast to a vec). PAD_ARRAY_SIZE controls the length of
-// these arrays.
-//
-// The length of the padding array is chosen with the following considerations:
-//
-// - A loop is necessary to avoid arbitrarily picking an upper-bound for
-//   requested padding values
-//
-// - Given the loop there aren't any strong constraints on the size of the
-//   padding array
-//
-// - My estimate is padding requests tend to be in the range of 1-16 bytes.
-//   From a [brief inspection][github-search-pad-bytes] it seems that estimate
-//   is reasonable
-//
-// - We desire a value large enough to cover common cases and avoid unnecessary
-//   loop trips, but small enough not to cause trouble on the stack (for
-//   instance, requesting a page of zeros feels unreasonable)
-//
-// - Try to pick something that only requires 1 execution of the loop body for
-//   common cases.
-//
-// [github-search-pad-bytes]:
-//   https://github.com/search?q=pad_bytes_before+OR+pad_bytes_after&type=code
-const PAD_ARRAY_SIZE: usize = 64;
-
 #[derive(Debug)]
 enum Id {
     TokenStream(TokenStream),
@@ -207,10 +178,85 @@ impl FromMeta for SkipMode {
     }
 }
 
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum ReservedCheck {
+    /// check = "strict" - error on mismatch
+    Strict,
+    /// check = "warn" - log on mismatch
+    Warn,
+    /// check = "ignore" - don't check
+    Ignore,
+}
+
+impl FromMeta for ReservedCheck {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "strict" => Ok(ReservedCheck::Strict),
+            "warn" => Ok(ReservedCheck::Warn),
+            "ignore" => Ok(ReservedCheck::Ignore),
+            _ => Err(darling::Error::custom(
+                "check must be 'strict', 'warn' or 'ignore'",
+            )),
+        }
+    }
+}
+
+#[derive(Debug, Clone, Copy, PartialEq)]
+enum Compression {
+    /// compress = "deflate"
+    Deflate,
+    /// compress = "zlib"
+    Zlib,
+    /// compress = "lz4"
+    Lz4,
+}
+
+impl FromMeta for Compression {
+    fn from_string(value: &str) -> darling::Result<Self> {
+        match value {
+            "deflate" => Ok(Compression::Deflate),
+            "zlib" => Ok(Compression::Zlib),
+            "lz4" => Ok(Compression::Lz4),
+            _ => Err(darling::Error::custom(
+                "compress must be 'deflate', 'zlib' or 'lz4'",
+            )),
+        }
+    }
+}
+
 fn cerror(span: proc_macro2::Span, msg: &str) -> TokenStream {
     syn::Error::new(span, msg).to_compile_error()
 }
 
+/// Validate the padding fill attributes
+fn validate_pad(
+    pad_value: Option<&Num>,
+    pad_pattern: Option<&syn::LitByteStr>,
+) -> Result<(), TokenStream> {
+    if pad_value.is_some() && pad_pattern.is_some() {
+        return Err(cerror(
+            pad_value.span(),
+            "conflicting: both `pad_value` and `pad_pattern` specified",
+        ));
+    }
+
+    // expressions must be a `u8`, check the literals here for a clearer error
+    let literal = match pad_value {
+        Some(Num::LitInt(value)) => Some(value.clone()),
+        Some(Num::TokenStream(value)) => syn::parse2::<syn::LitInt>(value.clone()).ok(),
+        None => None,
+    };
+    if literal.is_some_and(|value| value.base10_parse::<u8>().is_err()) {
+        return Err(cerror(pad_value.span(), "`pad_value` must fit in a byte"));
+    }
+
+    if pad_pattern.is_some_and(|p| p.value().is_empty()) {
+        return Err(cerror(pad_pattern.span(), "`pad_pattern` cannot be empty"));
+    }
+
+    Ok(())
+}
+
 /// A post-processed version of `DekuReceiver`
 #[derive(Debug)]
 struct DekuData {
@@ -232,6 +278,12 @@ struct DekuData {
     /// A magic value that must appear at the start of this struct/enum's data
     magic: Option<syn::LitByteStr>,
 
+    /// default padding fill value, also used by `finalize`
+    pad_value: Option<Num>,
+
+    /// default padding fill pattern, also used by `finalize`
+    pad_pattern: Option<syn::LitByteStr>,
+
     /// enum only: `id` value
     id: Option<Id>,
 
@@ -241,11 +293,14 @@ struct DekuData {
     /// enum only: endianness of the enum `id`
     id_endian: Option<syn::LitStr>,
 
-    /// enum only: bit size of the enum `id`
+    /// enum only: read variants in order until one succeeds, instead of matching an `id`
+    try_variants: bool,
+
+    /// enum: bit size of the enum `id`, struct: bit size of the whole struct
     #[cfg(feature = "bits")]
     bits: Option<Num>,
 
-    /// enum only: byte size of the enum `id`
+    /// enum: byte size of the enum `id`, struct: byte size of the whole struct
     bytes: Option<Num>,
 
     /// struct only: seek from current position
@@ -393,9 +448,12 @@ impl DekuData {
             ctx: receiver.ctx,
             ctx_default: receiver.ctx_default,
             magic: receiver.magic,
+            pad_value: receiver.pad_value,
+            pad_pattern: receiver.pad_pattern,
             id: receiver.id,
             id_type: receiver.id_type?,
             id_endian: receiver.id_endian,
+            try_variants: receiver.try_variants,
             #[cfg(feature = "bits")]
             bits: receiver.bits,
             bytes: receiver.bytes,
@@ -421,6 +479,8 @@ impl DekuData {
             ));
         }
 
+        validate_pad(data.pad_value.as_ref(), data.pad_pattern.as_ref())?;
+
         match data.data {
             ast::Data::Struct(_) => {
                 // Validate id_* attributes are being used on an enum
@@ -433,20 +493,55 @@ impl DekuData {
                     Err(cerror(data.id.span(), "`id` only supported on enum"))
                 } else if data.id_endian.is_some() {
                     Err(cerror(data.id.span(), "`id_endian` only supported on enum"))
-                } else if data.bytes.is_some() {
-                    Err(cerror(data.bytes.span(), "`bytes` only supported on enum"))
+                } else if data.try_variants {
+                    Err(cerror(
+                        data.ident.span(),
+                        "`try_variants` only supported on enum",
+                    ))
                 } else {
                     Ok(())
                 };
 
+                // Validate either `bits` or `bytes` is specified
                 #[cfg(feature = "bits")]
-                if ret.is_ok() && data.bits.is_some() {
-                    return Err(cerror(data.bits.span(), "`bits` only supported on enum"));
+                if ret.is_ok() && data.bits.is_some() && data.bytes.is_some() {
+                    return Err(cerror(
+                        data.bits.span(),
+                        "conflicting: both `bits` and `bytes` specified on struct",
+                    ));
                 }
 
                 ret
             }
-            ast::Data::Enum(_) => {
+            ast::Data::Enum(ref variants) => {
+                // Validate `try_variants` is used without any `id`
+                if data.try_variants {
+                    #[cfg(feature = "bits")]
+                    let has_id_size = data.bits.is_some() || data.bytes.is_some();
+                    #[cfg(not(feature = "bits"))]
+                    let has_id_size = data.bytes.is_some();
+                    if data.id_type.is_some()
+                        || data.id.is_some()
+                        || data.id_endian.is_some()
+                        || has_id_size
+                    {
+                        return Err(cerror(
+                            data.ident.span(),
+                            "conflicting: `try_variants` cannot be used with `id_type`, `id`, `id_endian`, `bits` or `bytes`",
+                        ));
+                    }
+                    if let Some(variant) = variants
+                        .iter()
+                        .find(|v| v.id.is_some() || v.id_pat.is_some() || v.default.is_some())
+                    {
+                        return Err(cerror(
+                            variant.ident.span(),
+                            "conflicting: `try_variants` cannot be used with `id`, `id_pat` or `default` on variants",
+                        ));
+                    }
+                    return Ok(());
+                }
+
                 // Validate `id_type` or `id` is specified
                 if data.id_type.is_none() && data.id.is_none() {
                     return Err(cerror(
@@ -707,6 +802,33 @@ struct FieldData {
 
     /// magic value that needs to appear before field
     magic: Option<syn::LitByteStr>,
+
+    /// number of reserved bits in place of the field
+    reserved: Option<Num>,
+
+    /// expected value of the reserved bits
+    value: Option<Num>,
+
+    /// handling of reserved bits that don't match `value` when reading
+    check: Option<ReservedCheck>,
+
+    /// padding fill value
+    pad_value: Option<Num>,
+
+    /// padding fill pattern
+    pad_pattern: Option<syn::LitByteStr>,
+
+    /// compression of the field, `bytes` is the compressed size
+    compress: Option<Compression>,
+
+    /// limit of the decompressed size
+    compress_limit: Option<Num>,
+
+    /// transform of the field, `bytes` is the transformed size
+    transform: Option<TokenStream>,
+
+    /// ctx passed to the transform
+    transform_ctx: Option<TokenStream>,
 }
 
 impl FieldData {
@@ -750,6 +872,8 @@ impl FieldData {
         // NOTE: Ignore default
         any_option_set = any_option_set
             || self.pad_bytes_after.is_some()
+            || self.pad_value.is_some()
+            || self.pad_pattern.is_some()
             || self.temp_value.is_some()
             || self.cond.is_some()
             || self.assert.is_some()
@@ -758,7 +882,12 @@ impl FieldData {
             || self.seek_from_end.is_some()
             || self.seek_from_start.is_some()
             || self.bit_order.is_some()
-            || self.magic.is_some();
+            || self.magic.is_some()
+            || self.reserved.is_some()
+            || self.compress.is_some()
+            || self.compress_limit.is_some()
+            || self.transform.is_some()
+            || self.transform_ctx.is_some();
 
         let any_bool_set = self.read_all || self.skip.is_some() || self.temp || self.seek_rewind;
 
@@ -809,6 +938,15 @@ impl FieldData {
             seek_from_start: receiver.seek_from_start?,
             bit_order: receiver.bit_order,
             magic: receiver.magic,
+            reserved: receiver.reserved,
+            value: receiver.value,
+            check: receiver.check,
+            pad_value: receiver.pad_value,
+            pad_pattern: receiver.pad_pattern,
+            compress: receiver.compress,
+            compress_limit: receiver.compress_limit,
+            transform: receiver.transform?,
+            transform_ctx: receiver.transform_ctx?,
         };
 
         FieldData::validate(&data)?;
@@ -862,6 +1000,88 @@ impl FieldData {
             ));
         }
 
+        validate_pad(data.pad_value.as_ref(), data.pad_pattern.as_ref())?;
+        #[cfg(feature = "bits")]
+        let has_padding = data.pad_bits_before.is_some() || data.pad_bits_after.is_some();
+        #[cfg(not(feature = "bits"))]
+        let has_padding = false;
+        let has_padding =
+            has_padding || data.pad_bytes_before.is_some() || data.pad_bytes_after.is_some();
+        if !has_padding && (data.pad_value.is_some() || data.pad_pattern.is_some()) {
+            return Err(cerror(
+                data.ty.span(),
+                "`pad_value` and `pad_pattern` can only be used with a `pad_*` attribute",
+            ));
+        }
+
+        // Validate usage of `reserved`
+        if data.reserved.is_none() && (data.value.is_some() || data.check.is_some()) {
+            return Err(cerror(
+                data.value.span(),
+                "`value` and `check` can only be used with `reserved`",
+            ));
+        }
+        #[cfg(feature = "bits")]
+        let has_size = data.bits.is_some() || data.bytes.is_some();
+        #[cfg(not(feature = "bits"))]
+        let has_size = data.bytes.is_some();
+        if data.reserved.is_some()
+            && (has_size
+                || data.reader.is_some()
+                || data.writer.is_some()
+                || data.map.is_some()
+                || data.ctx.is_some())
+        {
+            return Err(cerror(
+                data.reserved.span(),
+                "conflicting: `reserved` cannot be used with `bits`, `bytes`, `reader`, `writer`, `map` or `ctx`",
+            ));
+        }
+
+        // Validate usage of `compress`
+        #[cfg(feature = "bits")]
+        let has_bits = data.bits.is_some();
+        #[cfg(not(feature = "bits"))]
+        let has_bits = false;
+        if data.compress.is_some()
+            && (has_bits
+                || data.reader.is_some()
+                || data.writer.is_some()
+                || data.reserved.is_some())
+        {
+            return Err(cerror(
+                data.ty.span(),
+                "conflicting: `compress` cannot be used with `bits`, `reader`, `writer` or `reserved`",
+            ));
+        }
+
+        if data.compress.is_none() && data.compress_limit.is_some() {
+            return Err(cerror(
+                data.compress_limit.span(),
+                "`compress_limit` can only be used with `compress`",
+            ));
+        }
+
+        // Validate usage of `transform`
+        if data.transform.is_none() && data.transform_ctx.is_some() {
+            return Err(cerror(
+                data.transform_ctx.span(),
+                "`transform_ctx` can only be used with `transform`",
+            ));
+        }
+        if data.transform.is_some()
+            && (data.compress.is_some()
+                || has_bits
+                || data.reader.is_some()
+                || data.writer.is_some()
+                || data.reserved.is_some())
+        {
+            return Err(cerror(
+                data.transform.span(),
+                "conflicting: `transform` cannot be used with `compress`, `bits`, `reader`, `writer` or `reserved`",
+            ));
+        }
+
         // Validate usage of `default` attribute
         if data.default.is_some() && (data.skip.is_none() && data.cond.is_none()) {
             // FIXME: Use `Span::join` once out of nightly
@@ -1006,6 +1226,14 @@ struct DekuReceiver {
     #[darling(default)]
     magic: Option<syn::LitByteStr>,
 
+    /// default padding fill value, also used by `finalize`
+    #[darling(default)]
+    pad_value: Option<Num>,
+
+    /// default padding fill pattern, also used by `finalize`
+    #[darling(default)]
+    pad_pattern: Option<syn::LitByteStr>,
+
     /// enum only: `id` value
     #[darling(default)]
     id: Option<Id>,
@@ -1018,12 +1246,16 @@ struct DekuReceiver {
     #[darling(default)]
     id_endian: Option<syn::LitStr>,
 
-    /// enum only: bit size of the enum `id`
+    /// enum only: read variants in order until one succeeds, instead of matching an `id`
+    #[darling(default)]
+    try_variants: bool,
+
+    /// enum: bit size of the enum `id`, struct: bit size of the whole struct
     #[cfg(feature = "bits")]
     #[darling(default)]
     bits: Option<Num>,
 
-    /// enum only: byte size of the enum `id`
+    /// enum: byte size of the enum `id`, struct: byte size of the whole struct
     #[darling(default)]
     bytes: Option<Num>,
 
@@ -1254,6 +1486,42 @@ struct DekuFieldReceiver {
     /// magic value that needs to appear before field
     #[darling(default)]
     magic: Option<syn::LitByteStr>,
+
+    /// number of reserved bits in place of the field
+    #[darling(default)]
+    reserved: Option<Num>,
+
+    /// expected value of the reserved bits
+    #[darling(default)]
+    value: Option<Num>,
+
+    /// handling of reserved bits that don't match `value` when reading
+    #[darling(default)]
+    check: Option<ReservedCheck>,
+
+    /// padding fill value
+    #[darling(default)]
+    pad_value: Option<Num>,
+
+    /// padding fill pattern
+    #[darling(default)]
+    pad_pattern: Option<syn::LitByteStr>,
+
+    /// compression of the field, `bytes` is the compressed size
+    #[darling(default)]
+    compress: Option<Compression>,
+
+    /// limit of the decompressed size
+    #[darling(default)]
+    compress_limit: Option<Num>,
+
+    /// transform of the field, `bytes` is the transformed size
+    #[darling(default = "default_res_opt", map = "map_litstr_as_tokenstream")]
+    transform: Result<Option<TokenStream>, ReplacementError>,
+
+    /// ctx passed to the transform
+    #[darling(default = "default_res_opt", map = "map_litstr_as_tokenstream")]
+    transform_ctx: Result<Option<TokenStream>, ReplacementError>,
 }
 
 /// Receiver for the variant-level attributes inside a enum
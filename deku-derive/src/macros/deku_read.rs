@@ -12,10 +12,12 @@ use syn::{Ident, LitByteStr};
 use crate::macros::gen_bit_order_from_str;
 
 use crate::macros::{
-    assertion_failed, gen_ctx_types_and_arg, gen_field_args, gen_internal_field_idents,
-    token_contains_string, wrap_default_ctx,
+    assertion_failed, gen_compression, gen_ctx_types_and_arg, gen_field_args,
+    gen_internal_field_idents, gen_reserved_args, gen_transform, gen_transform_bytes,
+    reserved_size_check, struct_size_bits, struct_size_check, token_contains_string,
+    wrap_default_ctx,
 };
-use crate::{from_token, DekuData, DekuDataEnum, DekuDataStruct, FieldData, Id};
+use crate::{from_token, DekuData, DekuDataEnum, DekuDataStruct, FieldData, Id, ReservedCheck};
 
 use super::{gen_internal_field_ident, gen_type_from_ctx_id};
 
@@ -91,6 +93,8 @@ fn emit_struct(input: &DekuData) -> Result<TokenStream, syn::Error> {
 
     let magic_read = emit_magic_read(input);
 
+    let (size_start, size_check) = emit_struct_size(input);
+
     // check if the first field has an ident, if not, it's a unnamed struct
     let is_named_struct = fields
         .fields
@@ -166,9 +170,14 @@ fn emit_struct(input: &DekuData) -> Result<TokenStream, syn::Error> {
 
         #seek
 
+        #size_start
+
         #magic_read
 
         #(#field_reads)*
+
+        #size_check
+
         let __deku_value = #initialize_struct;
 
         Ok(__deku_value)
@@ -230,6 +239,7 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
     let mut default_reader = None;
     let mut pre_match_tokens = Vec::with_capacity(variants.len());
     let mut variant_matches = Vec::with_capacity(variants.len());
+    let mut variant_tries = Vec::new();
     let mut deku_ids = Vec::with_capacity(variants.len());
 
     let has_discriminant = variants.iter().any(|v| v.discriminant.is_some());
@@ -244,7 +254,10 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
             .is_some();
 
         let mut pad_id = false;
-        let variant_id = if let Some(variant_id) = &variant.id {
+        let variant_id = if input.try_variants {
+            // variants are tried in order, not matched on
+            TokenStream::new()
+        } else if let Some(variant_id) = &variant.id {
             match variant_id {
                 Id::TokenStream(v) => quote! {&#v}.into_token_stream(),
                 Id::LitByteStr(v) => v.into_token_stream(),
@@ -349,6 +362,11 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
             default_reader = Some(variant_read_func.clone())
         }
 
+        if input.try_variants {
+            variant_tries.push(variant_read_func);
+            continue;
+        }
+
         variant_matches.push(quote! {
             #variant_id => {
                 #variant_read_func
@@ -357,7 +375,7 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
     }
 
     // if no default match, return error
-    if !has_default_match && default_reader.is_none() {
+    if !input.try_variants && !has_default_match && default_reader.is_none() {
         variant_matches.push(quote! {
             _ => {
                 return Err(::#crate_::deku_error!(::#crate_::DekuError::Parse, "Could not match enum variant", "ID {:?} not found on {}", __deku_variant_id, #ident_as_string));
@@ -376,27 +394,58 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
         }
     }
 
-    let variant_id_read = if id.is_some() {
-        quote! {
-            let __deku_variant_id = (#id);
-        }
-    } else if id_type.is_some() {
+    let variant_read = if input.try_variants {
         quote! {
-            let __deku_variant_id = <#id_type>::from_reader_with_ctx(__deku_reader,  (#id_args))?;
+            let __deku_checkpoint = __deku_reader.checkpoint()?;
+            // the last error, unless a variant needed more data
+            #[allow(unused_mut)]
+            let mut __deku_error: core::option::Option<::#crate_::DekuError> = None;
+            let __deku_value = 'deku_try_variants: {
+                #(
+                    // a closure, so that `?` in the field reads only ends this variant
+                    #[allow(clippy::redundant_closure_call)]
+                    let __deku_result = (|| core::result::Result::<Self, ::#crate_::DekuError>::Ok({ #variant_tries }))();
+                    match __deku_result {
+                        Ok(__deku_value) => break 'deku_try_variants __deku_value,
+                        Err(::#crate_::DekuError::Io(__deku_kind)) => return Err(::#crate_::DekuError::Io(__deku_kind)),
+                        Err(__deku_e) => {
+                            __deku_reader.restore(&__deku_checkpoint)?;
+                            if !matches!(__deku_error, Some(::#crate_::DekuError::Incomplete(_))) {
+                                __deku_error = Some(__deku_e);
+                            }
+                        }
+                    }
+                )*
+                return Err(match __deku_error {
+                    Some(__deku_e @ ::#crate_::DekuError::Incomplete(_)) => __deku_e,
+                    Some(__deku_e) => ::#crate_::deku_error!(::#crate_::DekuError::Parse, "Could not match enum variant", "no variant of {} could be read, last error: {:?}", #ident_as_string, __deku_e),
+                    None => ::#crate_::deku_error!(::#crate_::DekuError::Parse, "Could not match enum variant", "no variant of {} could be read", #ident_as_string),
+                });
+            };
         }
     } else {
-        // either `id` or `id_type` needs to be specified
-        unreachable!();
-    };
+        let variant_id_read = if id.is_some() {
+            quote! {
+                let __deku_variant_id = (#id);
+            }
+        } else if id_type.is_some() {
+            quote! {
+                let __deku_variant_id = <#id_type>::from_reader_with_ctx(__deku_reader,  (#id_args))?;
+            }
+        } else {
+            // either `id`, `id_type` or `try_variants` needs to be specified
+            unreachable!();
+        };
 
-    let variant_read = quote! {
-        #variant_id_read
+        quote! {
+            #variant_id_read
 
-        #(#pre_match_tokens)*
+            #(#pre_match_tokens)*
 
-        let __deku_value = match &__deku_variant_id {
-            #(#variant_matches),*
-        };
+            let __deku_value = match &__deku_variant_id {
+                #(#variant_matches),*
+            };
+        }
     };
 
     // Implement `DekuContainerRead` for types that don't need a context
@@ -519,6 +568,29 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
     Ok(tokens)
 }
 
+/// Read the padding up to the struct size declared with `bits`/`bytes`, or error if it was exceeded
+fn emit_struct_size(input: &DekuData) -> (TokenStream, TokenStream) {
+    let Some(size) = struct_size_bits(input) else {
+        return (quote! {}, quote! {});
+    };
+
+    let check = struct_size_check(input, &size, &quote! { __deku_reader.bits_read });
+    #[cfg(feature = "bits")]
+    let padding = emit_padding(&quote! { __deku_struct_pad }, input.bit_order.as_ref());
+    #[cfg(not(feature = "bits"))]
+    let padding = emit_padding_bytes(&quote! { __deku_struct_pad / 8 });
+
+    (
+        quote! { let __deku_struct_start = __deku_reader.bits_read; },
+        quote! {
+            {
+                #check
+                #padding
+            }
+        },
+    )
+}
+
 fn emit_magic_read(input: &DekuData) -> TokenStream {
     let crate_ = super::get_crate_name();
     if let Some(magic) = &input.magic {
@@ -627,24 +699,16 @@ fn emit_padding(bit_size: &TokenStream, bit_order: Option<&LitStr>) -> TokenStre
     }
 }
 
-// TODO: if this is a simple calculation such as "8 + 2", this could be const
 #[cfg(not(feature = "bits"))]
 fn emit_padding_bytes(bit_size: &TokenStream) -> TokenStream {
     let crate_ = super::get_crate_name();
-    let pad = crate::PAD_ARRAY_SIZE;
     quote! {
         {
             use core::convert::TryFrom;
-            let mut __deku_pad = usize::try_from(#bit_size).map_err(|e|
+            let __deku_pad = usize::try_from(#bit_size).map_err(|e|
                 ::#crate_::deku_error!(::#crate_::DekuError::InvalidParam, "Invalid padding param, cannot convert to usize", "{}", stringify!(#bit_size))
             )?;
-
-            while __deku_pad > 0 {
-                let mut __deku_pad_source = [0u8; #pad];
-                let __deku_pad_chunk = core::cmp::min(__deku_pad_source.len(), __deku_pad);
-                __deku_reader.read_bytes(__deku_pad_chunk, &mut __deku_pad_source[..__deku_pad_chunk], ::#crate_::ctx::Order::default())?;
-                __deku_pad -= __deku_pad_chunk;
-            }
+            __deku_reader.skip_bytes(__deku_pad)?;
         }
     }
 }
@@ -774,14 +838,28 @@ fn emit_field_read(
 
     let field_read_func = if field_reader.is_some() {
         quote! { #field_reader? }
+    } else if let Some(reserved) = &f.reserved {
+        emit_reserved_read(
+            f,
+            reserved,
+            field_endian,
+            field_bit_order,
+            &ident,
+            &field_ident_str,
+        )?
     } else {
+        // with `compress` or `transform`, `bytes` is the size of the transformed bytes
+        let field_bytes = f
+            .bytes
+            .as_ref()
+            .filter(|_| f.compress.is_none() && f.transform.is_none());
         let read_args = gen_field_args(
             field_endian,
             #[cfg(feature = "bits")]
             f.bits.as_ref(),
             #[cfg(not(feature = "bits"))]
             None,
-            f.bytes.as_ref(),
+            field_bytes,
             f.ctx.as_ref(),
             field_bit_order,
         )?;
@@ -917,6 +995,26 @@ fn emit_field_read(
         }
     };
 
+    let field_read_func = if let Some((compression, limit)) = gen_compression(f) {
+        let compressed_bytes = gen_transform_bytes(f.bytes.as_ref());
+        quote! {
+            ::#crate_::compression::read(__deku_reader, #compression, #limit, #compressed_bytes, |__deku_reader| {
+                let __deku_value = #field_read_func;
+                core::result::Result::<_, ::#crate_::DekuError>::Ok(__deku_value)
+            })?
+        }
+    } else if let Some((transform, transform_ctx)) = gen_transform(f) {
+        let transform_bytes = gen_transform_bytes(f.bytes.as_ref());
+        quote! {
+            ::#crate_::transform::read(__deku_reader, &(#transform), #transform_ctx, #transform_bytes, |__deku_reader| {
+                let __deku_value = #field_read_func;
+                core::result::Result::<_, ::#crate_::DekuError>::Ok(__deku_value)
+            })?
+        }
+    } else {
+        field_read_func
+    };
+
     #[cfg(feature = "bits")]
     let pad_bits_before = crate::macros::pad_bits(
         f.pad_bits_before.as_ref(),
@@ -1010,6 +1108,43 @@ fn emit_field_read(
     Ok((field_ident, field_read))
 }
 
+/// Read `reserved` bits in place of the field, checking them against `value`
+fn emit_reserved_read(
+    f: &FieldData,
+    reserved: &crate::Num,
+    field_endian: Option<&syn::LitStr>,
+    field_bit_order: Option<&syn::LitStr>,
+    ident: &str,
+    field_ident_str: &str,
+) -> Result<TokenStream, syn::Error> {
+    let crate_ = super::get_crate_name();
+    let read_args = gen_reserved_args(field_endian, reserved, field_bit_order)?;
+    let size_check = reserved_size_check(reserved);
+    let value = f.value.as_ref().map_or(quote! { 0 }, |v| quote! { #v });
+
+    let on_mismatch = match f.check.unwrap_or(ReservedCheck::Strict) {
+        ReservedCheck::Strict => quote! {
+            return Err(::#crate_::deku_error!(::#crate_::DekuError::Assertion, "Reserved bits mismatch", "{}.{}: expected {:#x}, found {:#x}", #ident, #field_ident_str, __deku_reserved_expected, __deku_reserved));
+        },
+        ReservedCheck::Warn if cfg!(feature = "logging") => quote! {
+            log::warn!("Reserved bits mismatch: {}.{}: expected {:#x}, found {:#x}", #ident, #field_ident_str, __deku_reserved_expected, __deku_reserved);
+        },
+        ReservedCheck::Warn | ReservedCheck::Ignore => quote! {},
+    };
+
+    Ok(quote! {
+        {
+            #size_check
+            let __deku_reserved = <u128 as ::#crate_::DekuReader<'_, _>>::from_reader_with_ctx(__deku_reader, (#read_args))?;
+            let __deku_reserved_expected = (#value) as u128;
+            if __deku_reserved != __deku_reserved_expected {
+                #on_mismatch
+            }
+            Default::default()
+        }
+    })
+}
+
 /// emit `from_reader()` and `from_bytes()` for struct/enum
 pub fn emit_container_read(
     imp: &syn::ImplGenerics,
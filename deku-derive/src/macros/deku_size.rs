@@ -60,7 +60,9 @@ fn calculate_fields_size<'a>(
 
         let field_type = &f.ty;
         #[cfg(feature = "bits")]
-        if let Some(bits) = &f.bits {
+        if let Some(reserved) = &f.reserved {
+            components.push(quote! { (#reserved) });
+        } else if let Some(bits) = &f.bits {
             components.push(quote! { (#bits) });
         } else if let Some(bytes) = &f.bytes {
             components.push(quote! { (#bytes) * 8 });
@@ -69,7 +71,9 @@ fn calculate_fields_size<'a>(
         }
 
         #[cfg(not(feature = "bits"))]
-        if let Some(bytes) = &f.bytes {
+        if let Some(reserved) = &f.reserved {
+            components.push(quote! { (#reserved) });
+        } else if let Some(bytes) = &f.bytes {
             components.push(quote! { (#bytes) * 8 });
         } else {
             components.push(quote! { <#field_type as ::#crate_::DekuSize>::SIZE_BITS });
@@ -162,9 +166,10 @@ fn add_field_bounds<'a>(
         if !field.temp {
             let field_type = &field.ty;
             #[cfg(feature = "bits")]
-            let needs_bound = field.bits.is_none() && field.bytes.is_none();
+            let needs_bound =
+                field.bits.is_none() && field.bytes.is_none() && field.reserved.is_none();
             #[cfg(not(feature = "bits"))]
-            let needs_bound = field.bytes.is_none();
+            let needs_bound = field.bytes.is_none() && field.reserved.is_none();
 
             if needs_bound {
                 let where_clause = where_clause.get_or_insert_with(|| syn::parse_quote! { where });
@@ -192,7 +197,7 @@ fn calculate_discriminant_size(
         return quote! { (#bytes) * 8 };
     }
 
-    if id.is_some() {
+    if id.is_some() || input.try_variants {
         return quote! { 0 };
     }
 
@@ -252,12 +257,33 @@ fn emit_struct(input: &DekuData) -> Result<TokenStream, syn::Error> {
 
     let ident = &input.ident;
 
-    let tokens = quote! {
+    let size_bits = if let Some(declared) = super::struct_size_bits(input) {
+        let message = format!("{ident}: fields exceed the size declared with `bits`/`bytes`");
+        quote! {
+            {
+                assert!(#magic_size + #size_calculation <= #declared, #message);
+                #declared
+            }
+        }
+    } else {
+        quote! { #magic_size + #size_calculation }
+    };
+
+    let mut tokens = quote! {
         impl #imp_generics ::#crate_::DekuSize for #ident #ty_generics #where_clause {
-            const SIZE_BITS: usize = #magic_size + #size_calculation;
+            const SIZE_BITS: usize = #size_bits;
         }
     };
 
+    // evaluate the size check even when `SIZE_BITS` is never used
+    if super::struct_size_bits(input).is_some() && input.generics.params.is_empty() {
+        tokens.extend(quote! {
+            const _: () = {
+                let _ = <#ident as ::#crate_::DekuSize>::SIZE_BITS;
+            };
+        });
+    }
+
     Ok(tokens)
 }
 
@@ -10,8 +10,10 @@ use syn::LitStr;
 use crate::macros::gen_bit_order_from_str;
 
 use crate::macros::{
-    assertion_failed, gen_ctx_types_and_arg, gen_field_args, gen_internal_field_ident,
-    gen_struct_destruction, token_contains_string, wrap_default_ctx,
+    assertion_failed, gen_compression, gen_ctx_types_and_arg, gen_field_args,
+    gen_internal_field_ident, gen_pad, gen_reserved_args, gen_struct_destruction, gen_transform,
+    gen_transform_bytes, reserved_size_check, struct_size_bits, struct_size_check,
+    token_contains_string, wrap_default_ctx,
 };
 use crate::{from_token, DekuData, DekuDataEnum, DekuDataStruct, FieldData, Id};
 
@@ -81,7 +83,16 @@ fn emit_struct(input: &DekuData) -> Result<TokenStream, syn::Error> {
 
     let magic_write = emit_magic_write(input);
 
-    let field_writes = emit_field_writes(input, &fields, false, None, &ident)?;
+    let (size_start, size_check) = emit_struct_size(input);
+
+    let field_writes = emit_field_writes(input, &fields, false, None, &ident, false)?;
+    let field_writes_vectored = if has_vectored_fields(&fields) {
+        Some(emit_field_writes(
+            input, &fields, false, None, &ident, true,
+        )?)
+    } else {
+        None
+    };
     let field_updates = emit_field_updates(&fields, Some(quote! { self. }));
 
     let named = fields.style.is_struct();
@@ -131,25 +142,35 @@ fn emit_struct(input: &DekuData) -> Result<TokenStream, syn::Error> {
             };
         });
 
+        let container_pad = emit_container_pad(input);
         tokens.extend(quote! {
             #[automatically_derived]
-            impl #imp ::#crate_::DekuContainerWrite for #ident #wher {}
+            impl #imp ::#crate_::DekuContainerWrite for #ident #wher {
+                #container_pad
+            }
         });
     }
 
     let (ctx_types, ctx_arg) = gen_ctx_types_and_arg(input.ctx.as_ref())?;
 
-    let write_body = quote! {
-        match *self {
-            #destructured => {
-                #seek
-                #magic_write
-                #(#field_writes)*
+    let emit_write_body = |field_writes: &[TokenStream]| {
+        quote! {
+            match *self {
+                #destructured => {
+                    #seek
+                    #size_start
+                    #magic_write
+                    #(#field_writes)*
+                    #size_check
 
-                Ok(())
+                    Ok(())
+                }
             }
         }
     };
+    let write_body = emit_write_body(&field_writes);
+    let write_body_vectored = field_writes_vectored.as_deref().map(emit_write_body);
+    let write_vectored = emit_write_vectored(write_body_vectored.as_ref(), &ctx_arg);
 
     // avoid outputing `use core::convert::TryInto` if update() function is empty
     let update_use = check_update_use(&field_updates);
@@ -173,10 +194,20 @@ fn emit_struct(input: &DekuData) -> Result<TokenStream, syn::Error> {
             fn to_writer<W: ::#crate_::no_std_io::Write + ::#crate_::no_std_io::Seek>(&self, __deku_writer: &mut ::#crate_::writer::Writer<W>, #ctx_arg) -> core::result::Result<(), ::#crate_::DekuError> {
                 #write_body
             }
+
+            #write_vectored
         }
     });
 
+    tokens.extend(emit_no_seek(input));
+
     if input.ctx.is_some() && input.ctx_default.is_some() {
+        let write_vectored_default = emit_write_vectored(
+            write_body_vectored
+                .map(|body| wrap_default_ctx(body, &input.ctx, &input.ctx_default))
+                .as_ref(),
+            &quote! { _: () },
+        );
         let write_body = wrap_default_ctx(write_body, &input.ctx, &input.ctx_default);
 
         tokens.extend(quote! {
@@ -187,6 +218,8 @@ fn emit_struct(input: &DekuData) -> Result<TokenStream, syn::Error> {
                 fn to_writer<W: ::#crate_::no_std_io::Write + ::#crate_::no_std_io::Seek>(&self, __deku_writer: &mut ::#crate_::writer::Writer<W>, _: ()) -> core::result::Result<(), ::#crate_::DekuError> {
                     #write_body
                 }
+
+                #write_vectored_default
             }
         });
     }
@@ -212,6 +245,10 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
     let magic_write = emit_magic_write(input);
 
     let mut variant_writes = Vec::with_capacity(variants.len());
+    let mut variant_writes_vectored = Vec::with_capacity(variants.len());
+    let vectored = variants
+        .iter()
+        .any(|v| v.writer.is_none() && has_vectored_fields(&v.fields.as_ref()));
     let mut variant_updates = Vec::with_capacity(variants.len());
 
     let has_discriminant = variants.iter().any(|v| v.discriminant.is_some());
@@ -236,7 +273,10 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
             }
         });
 
-        let variant_id_write = if id.is_some() {
+        let variant_id_write = if input.try_variants {
+            // the variant is told apart by its fields
+            quote! {}
+        } else if id.is_some() {
             quote! {
                 // if we don't do this we may get a "unused variable" error if passed via `ctx`
                 // i.e. #[deku(ctx = "my_id: u8", id = "my_id")]
@@ -315,30 +355,41 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
                 ));
             }
         } else {
-            // either `id` or `id_type` needs to be specified
+            // either `id`, `id_type` or `try_variants` needs to be specified
             unreachable!();
         };
 
         let variant_match = super::gen_enum_init(variant_is_named, variant_ident, field_idents);
 
-        let variant_write = if variant_writer.is_some() {
-            quote! { #variant_writer ?; }
-        } else {
+        let emit_variant_write = |vectored: bool| {
+            if variant_writer.is_some() {
+                return Ok(quote! { #variant_writer ?; });
+            }
             let field_writes = emit_field_writes(
                 input,
                 &variant.fields.as_ref(),
                 variant.id_pat.is_some(),
                 None,
                 &ident,
+                vectored,
             )?;
 
-            quote! {
+            Ok::<_, syn::Error>(quote! {
                 {
                     #variant_id_write
                     #(#field_writes)*
                 }
-            }
+            })
         };
+        let variant_write = emit_variant_write(false)?;
+        if vectored {
+            let variant_write = emit_variant_write(true)?;
+            variant_writes_vectored.push(quote! {
+                Self :: #variant_match => {
+                    #variant_write
+                }
+            });
+        }
 
         let variant_field_updates = emit_field_updates(&variant.fields.as_ref(), None);
 
@@ -371,6 +422,8 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
             }
         });
 
+        #[cfg(feature = "alloc")]
+        let container_pad = emit_container_pad(input);
         #[cfg(feature = "alloc")]
         tokens.extend(quote! {
             const _: () = {
@@ -389,21 +442,28 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
             };
 
             #[automatically_derived]
-            impl #imp ::#crate_::DekuContainerWrite for #ident #wher {}
+            impl #imp ::#crate_::DekuContainerWrite for #ident #wher {
+                #container_pad
+            }
         });
     }
 
     let (ctx_types, ctx_arg) = gen_ctx_types_and_arg(input.ctx.as_ref())?;
 
-    let write_body = quote! {
-        #magic_write
+    let emit_write_body = |variant_writes: &[TokenStream]| {
+        quote! {
+            #magic_write
 
-        match self {
-            #(#variant_writes),*
-        }
+            match self {
+                #(#variant_writes),*
+            }
 
-        Ok(())
+            Ok(())
+        }
     };
+    let write_body = emit_write_body(&variant_writes);
+    let write_body_vectored = vectored.then(|| emit_write_body(&variant_writes_vectored));
+    let write_vectored = emit_write_vectored(write_body_vectored.as_ref(), &ctx_arg);
 
     // avoid outputting `use core::convert::TryInto` if update() function is empty
     let update_use = check_update_use(&variant_updates);
@@ -430,10 +490,20 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
             fn to_writer<W: ::#crate_::no_std_io::Write + ::#crate_::no_std_io::Seek>(&self, __deku_writer: &mut ::#crate_::writer::Writer<W>, #ctx_arg) -> core::result::Result<(), ::#crate_::DekuError> {
                 #write_body
             }
+
+            #write_vectored
         }
     });
 
+    tokens.extend(emit_no_seek(input));
+
     if input.ctx.is_some() && input.ctx_default.is_some() {
+        let write_vectored_default = emit_write_vectored(
+            write_body_vectored
+                .map(|body| wrap_default_ctx(body, &input.ctx, &input.ctx_default))
+                .as_ref(),
+            &quote! { _: () },
+        );
         let write_body = wrap_default_ctx(write_body, &input.ctx, &input.ctx_default);
 
         tokens.extend(quote! {
@@ -444,6 +514,8 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
                 fn to_writer<W: ::#crate_::no_std_io::Write + ::#crate_::no_std_io::Seek>(&self, __deku_writer: &mut ::#crate_::writer::Writer<W>, _: ()) -> core::result::Result<(), ::#crate_::DekuError> {
                     #write_body
                 }
+
+                #write_vectored_default
             }
         });
     }
@@ -452,6 +524,193 @@ fn emit_enum(input: &DekuData) -> Result<TokenStream, syn::Error> {
     Ok(tokens)
 }
 
+/// Implement `DekuWriterNoSeek` when neither the container nor its fields seek or use a
+/// custom `writer`
+///
+/// The written field types are bounded through a reference with a higher-ranked lifetime,
+/// which isn't checked where the impl is declared: a field type without `DekuWriterNoSeek`
+/// only leaves the container without it. Field types containing the container itself are
+/// not bounded, so recursive types don't overflow.
+fn emit_no_seek(input: &DekuData) -> TokenStream {
+    let crate_ = super::get_crate_name();
+
+    if has_seek(
+        input.seek_rewind,
+        input.seek_from_current.as_ref(),
+        input.seek_from_end.as_ref(),
+        input.seek_from_start.as_ref(),
+    ) {
+        return quote! {};
+    }
+
+    let mut field_types = Vec::new();
+    match &input.data {
+        Data::Struct(fields) => {
+            if !no_seek_field_types(fields.iter(), &mut field_types) {
+                return quote! {};
+            }
+        }
+        Data::Enum(variants) => {
+            for variant in variants {
+                if variant.writer.is_some()
+                    || !no_seek_field_types(variant.fields.iter(), &mut field_types)
+                {
+                    return quote! {};
+                }
+            }
+        }
+    }
+
+    let ident = &input.ident;
+    let field_types = field_types
+        .into_iter()
+        .map(|ty| quote! { #ty })
+        .filter(|ty| !tokens_contain_ident(ty.clone(), ident))
+        .chain(input.id_type.clone());
+
+    let mut generics = input.generics.clone();
+    let where_clause = generics.make_where_clause();
+    for ty in field_types {
+        where_clause.predicates.push(syn::parse_quote! {
+            for<'__deku_no_seek> &'__deku_no_seek #ty: ::#crate_::DekuWriterNoSeek
+        });
+    }
+    let (imp, ty, wher) = generics.split_for_impl();
+
+    quote! {
+        #[automatically_derived]
+        impl #imp ::#crate_::DekuWriterNoSeek for #ident #ty #wher {}
+    }
+}
+
+fn has_seek(
+    seek_rewind: bool,
+    seek_from_current: Option<&TokenStream>,
+    seek_from_end: Option<&TokenStream>,
+    seek_from_start: Option<&TokenStream>,
+) -> bool {
+    seek_rewind
+        || seek_from_current.is_some()
+        || seek_from_end.is_some()
+        || seek_from_start.is_some()
+}
+
+/// Collect the types of the written fields, false if a field seeks or uses a custom `writer`
+fn no_seek_field_types<'a>(
+    fields: impl Iterator<Item = &'a FieldData>,
+    field_types: &mut Vec<&'a syn::Type>,
+) -> bool {
+    for f in fields {
+        if f.writer.is_some()
+            || has_seek(
+                f.seek_rewind,
+                f.seek_from_current.as_ref(),
+                f.seek_from_end.as_ref(),
+                f.seek_from_start.as_ref(),
+            )
+        {
+            return false;
+        }
+
+        let skipped = matches!(
+            f.skip,
+            Some(crate::SkipMode::All) | Some(crate::SkipMode::Write)
+        ) && f.cond.is_none();
+        if !skipped && (!f.temp || f.temp_value.is_some()) {
+            field_types.push(&f.ty);
+        }
+    }
+    true
+}
+
+/// Whether `tokens` contain `ident` or `Self`
+fn tokens_contain_ident(tokens: TokenStream, ident: &syn::Ident) -> bool {
+    tokens.into_iter().any(|token| match token {
+        proc_macro2::TokenTree::Ident(i) => i == *ident || i == "Self",
+        proc_macro2::TokenTree::Group(group) => tokens_contain_ident(group.stream(), ident),
+        _ => false,
+    })
+}
+
+/// Whether any field may borrow from the container with `to_writer_vectored`, which
+/// `DekuWriter` otherwise forwards to `to_writer`
+fn has_vectored_fields(fields: &Fields<&FieldData>) -> bool {
+    const PRIMITIVES: &[&str] = &[
+        "u8", "u16", "u32", "u64", "u128", "usize", "i8", "i16", "i32", "i64", "i128", "isize",
+        "f32", "f64", "bool", "char",
+    ];
+    fields.iter().any(|f| {
+        let primitive = matches!(&f.ty, syn::Type::Path(p) if p.qself.is_none()
+            && PRIMITIVES.iter().any(|name| p.path.is_ident(name)));
+        !primitive
+            && !f.temp
+            && f.writer.is_none()
+            && f.reserved.is_none()
+            && f.compress.is_none()
+            && f.transform.is_none()
+    })
+}
+
+/// Implement `to_writer_vectored` with `write_body`, if the fields can borrow from `self`
+fn emit_write_vectored(write_body: Option<&TokenStream>, ctx_arg: &TokenStream) -> TokenStream {
+    let Some(write_body) = write_body else {
+        return quote! {};
+    };
+    if !cfg!(feature = "alloc") {
+        return quote! {};
+    }
+
+    let crate_ = super::get_crate_name();
+    quote! {
+        #[allow(unused_variables)]
+        #[inline]
+        fn to_writer_vectored<'__deku_io>(&'__deku_io self, __deku_writer: &mut ::#crate_::writer::Writer<::#crate_::vectored::IoSliceWriter<'__deku_io>>, #ctx_arg) -> core::result::Result<(), ::#crate_::DekuError> {
+            #write_body
+        }
+    }
+}
+
+/// Fill used by `finalize` when writing the container, if set with `pad_value`/`pad_pattern`
+fn emit_container_pad(input: &DekuData) -> TokenStream {
+    if input.pad_value.is_none() && input.pad_pattern.is_none() {
+        return quote! {};
+    }
+
+    let crate_ = super::get_crate_name();
+    let pad = gen_pad(None, None, input);
+    quote! {
+        const PAD: ::#crate_::writer::Pad = #pad;
+    }
+}
+
+/// Write the padding up to the struct size declared with `bits`/`bytes`, or error if it was exceeded
+fn emit_struct_size(input: &DekuData) -> (TokenStream, TokenStream) {
+    let Some(size) = struct_size_bits(input) else {
+        return (quote! {}, quote! {});
+    };
+
+    let check = struct_size_check(input, &size, &quote! { __deku_writer.bit_position() });
+    let pad = gen_pad(None, None, input);
+    #[cfg(feature = "bits")]
+    let padding = emit_padding(
+        &quote! { __deku_struct_pad },
+        input.bit_order.as_ref(),
+        &pad,
+    );
+    #[cfg(not(feature = "bits"))]
+    let padding = emit_padding_bytes(&quote! { __deku_struct_pad / 8 }, &pad);
+
+    (
+        quote! { let __deku_struct_start = __deku_writer.bit_position(); },
+        quote! {
+            {
+                #check
+                #padding
+            }
+        },
+    )
+}
+
 fn emit_magic_write(input: &DekuData) -> TokenStream {
     let crate_ = super::get_crate_name();
     if let Some(magic) = &input.magic {
@@ -469,12 +728,15 @@ fn emit_field_writes(
     is_id_pat: bool,
     object_prefix: Option<TokenStream>,
     ident: &TokenStream,
+    vectored: bool,
 ) -> Result<Vec<TokenStream>, syn::Error> {
     let mut is_id_pat = is_id_pat;
     fields
         .iter()
         .enumerate()
-        .map(|(i, f)| emit_field_write(input, i, f, &object_prefix, ident, &mut is_id_pat))
+        .map(|(i, f)| {
+            emit_field_write(input, i, f, &object_prefix, ident, &mut is_id_pat, vectored)
+        })
         .collect()
 }
 
@@ -542,61 +804,39 @@ fn emit_bit_byte_offsets(
 }
 
 #[cfg(feature = "bits")]
-fn emit_padding(bit_size: &TokenStream, bit_order: Option<&LitStr>) -> TokenStream {
+fn emit_padding(
+    bit_size: &TokenStream,
+    bit_order: Option<&LitStr>,
+    pad: &TokenStream,
+) -> TokenStream {
     let crate_ = super::get_crate_name();
-    const PAD: usize = crate::PAD_ARRAY_SIZE * 8;
-    if let Some(bit_order) = bit_order {
-        let order = gen_bit_order_from_str(bit_order).unwrap();
-        quote! {
-            {
-                use core::convert::TryFrom;
-                let mut __deku_pad = usize::try_from(#bit_size).map_err(|e|
-                    ::#crate_::deku_error!(::#crate_::DekuError::InvalidParam, "Invalid padding param, cannot convert to usize", "{}", stringify!(#bit_size))
-                )?;
-                let __deku_pad_source = ::#crate_::bitvec::bitarr!(u8, ::#crate_::bitvec::Msb0; 0; #PAD);
-                while __deku_pad > 0 {
-                    let __deku_pad_chunk = core::cmp::min(__deku_pad_source.len(), __deku_pad);
-                    __deku_writer.write_bits_order(&__deku_pad_source[..__deku_pad_chunk], #order)?;
-                    __deku_pad -= __deku_pad_chunk;
-                }
-            }
-        }
+    let order = if let Some(bit_order) = bit_order {
+        gen_bit_order_from_str(bit_order).unwrap()
     } else {
-        quote! {
-            {
-                use core::convert::TryFrom;
-                let mut __deku_pad = usize::try_from(#bit_size).map_err(|e|
-                    ::#crate_::deku_error!(::#crate_::DekuError::InvalidParam, "Invalid padding param, cannot convert to usize", "{}", stringify!(#bit_size))
-                )?;
-                let __deku_pad_source = ::#crate_::bitvec::bitarr!(u8, ::#crate_::bitvec::Msb0; 0; #PAD);
-                while __deku_pad > 0 {
-                    let __deku_pad_chunk = core::cmp::min(__deku_pad_source.len(), __deku_pad);
-                    __deku_writer.write_bits(&__deku_pad_source[..__deku_pad_chunk])?;
-                    __deku_pad -= __deku_pad_chunk;
-                }
-            }
+        quote! { ::#crate_::ctx::Order::default() }
+    };
+    quote! {
+        {
+            use core::convert::TryFrom;
+            let __deku_pad = usize::try_from(#bit_size).map_err(|e|
+                ::#crate_::deku_error!(::#crate_::DekuError::InvalidParam, "Invalid padding param, cannot convert to usize", "{}", stringify!(#bit_size))
+            )?;
+            __deku_writer.write_pad_bits(__deku_pad, #pad, #order)?;
         }
     }
 }
 
 // TODO: if this is a simple calculation such as "8 + 2", this could be const
 #[cfg(not(feature = "bits"))]
-fn emit_padding_bytes(bit_size: &TokenStream) -> TokenStream {
+fn emit_padding_bytes(bit_size: &TokenStream, pad: &TokenStream) -> TokenStream {
     let crate_ = super::get_crate_name();
-    let pad = crate::PAD_ARRAY_SIZE;
     quote! {
         {
             use core::convert::TryFrom;
-            let mut __deku_pad = usize::try_from(#bit_size).map_err(|e|
+            let __deku_pad = usize::try_from(#bit_size).map_err(|e|
                 ::#crate_::deku_error!(::#crate_::DekuError::InvalidParam, "Invalid padding param, cannot convert to usize", "{}", stringify!(#bit_size))
             )?;
-
-            let __deku_pad_source = [0u8; #pad];
-            while __deku_pad > 0 {
-                let __deku_pad_chunk = core::cmp::min(__deku_pad_source.len(), __deku_pad);
-                __deku_writer.write_bytes(&__deku_pad_source[..__deku_pad_chunk])?;
-                __deku_pad -= __deku_pad_chunk;
-            }
+            __deku_writer.write_pad_bytes(__deku_pad, #pad)?;
         }
     }
 }
@@ -608,6 +848,7 @@ fn emit_field_write(
     object_prefix: &Option<TokenStream>,
     ident: &TokenStream,
     is_id_pat: &mut bool,
+    vectored: bool,
 ) -> Result<TokenStream, syn::Error> {
     // If this is the storage field of the Enum id_pat, we use
     // the field args that were used for reading, to ensure that
@@ -739,14 +980,29 @@ fn emit_field_write(
 
     let field_write_func = if field_writer.is_some() {
         quote! { #field_writer }
+    } else if let Some(reserved) = &f.reserved {
+        let write_args = gen_reserved_args(field_endian, reserved, field_bit_order)?;
+        let size_check = reserved_size_check(reserved);
+        let value = f.value.as_ref().map_or(quote! { 0 }, |v| quote! { #v });
+        quote! {
+            {
+                #size_check
+                ::#crate_::DekuWriter::to_writer(&((#value) as u128), __deku_writer, (#write_args))
+            }
+        }
     } else {
+        // with `compress` or `transform`, `bytes` is the size of the transformed bytes
+        let field_bytes = f
+            .bytes
+            .as_ref()
+            .filter(|_| f.compress.is_none() && f.transform.is_none());
         let write_args = gen_field_args(
             field_endian,
             #[cfg(feature = "bits")]
             f.bits.as_ref(),
             #[cfg(not(feature = "bits"))]
             None,
-            f.bytes.as_ref(),
+            field_bytes,
             f.ctx.as_ref(),
             field_bit_order,
         )?;
@@ -759,31 +1015,54 @@ fn emit_field_write(
             } else {
                 quote! { core::result::Result::<(), ::#crate_::DekuError>::Ok(()) }
             }
+        } else if vectored && f.compress.is_none() && f.transform.is_none() {
+            quote! { ::#crate_::DekuWriter::to_writer_vectored(#object_prefix #field_ident, __deku_writer, (#write_args)) }
         } else {
             quote! { ::#crate_::DekuWriter::to_writer(#object_prefix #field_ident, __deku_writer, (#write_args)) }
         }
     };
 
+    let field_write_func = if let Some((compression, _)) = gen_compression(f) {
+        let compressed_bytes = gen_transform_bytes(f.bytes.as_ref());
+        quote! {
+            ::#crate_::compression::write(__deku_writer, #compression, #compressed_bytes, |__deku_writer| #field_write_func)
+        }
+    } else if let Some((transform, transform_ctx)) = gen_transform(f) {
+        let transform_bytes = gen_transform_bytes(f.bytes.as_ref());
+        quote! {
+            ::#crate_::transform::write(__deku_writer, &(#transform), #transform_ctx, #transform_bytes, |__deku_writer| #field_write_func)
+        }
+    } else {
+        field_write_func
+    };
+
+    let pad = gen_pad(f.pad_value.as_ref(), f.pad_pattern.as_ref(), input);
+    #[cfg(feature = "bits")]
+    let emit_field_padding = |bit_size: &TokenStream, bit_order: Option<&LitStr>| {
+        emit_padding(bit_size, bit_order, &pad)
+    };
     #[cfg(feature = "bits")]
     let pad_bits_before = crate::macros::pad_bits(
         f.pad_bits_before.as_ref(),
         f.pad_bytes_before.as_ref(),
         field_bit_order,
-        emit_padding,
+        emit_field_padding,
     );
     #[cfg(feature = "bits")]
     let pad_bits_after = crate::macros::pad_bits(
         f.pad_bits_after.as_ref(),
         f.pad_bytes_after.as_ref(),
         field_bit_order,
-        emit_padding,
+        emit_field_padding,
     );
 
     #[cfg(not(feature = "bits"))]
-    let pad_bits_before = crate::macros::pad_bytes(f.pad_bytes_before.as_ref(), emit_padding_bytes);
+    let emit_field_padding = |bit_size: &TokenStream| emit_padding_bytes(bit_size, &pad);
+    #[cfg(not(feature = "bits"))]
+    let pad_bits_before = crate::macros::pad_bytes(f.pad_bytes_before.as_ref(), emit_field_padding);
 
     #[cfg(not(feature = "bits"))]
-    let pad_bits_after = crate::macros::pad_bytes(f.pad_bytes_after.as_ref(), emit_padding_bytes);
+    let pad_bits_after = crate::macros::pad_bytes(f.pad_bytes_after.as_ref(), emit_field_padding);
 
     let field_write_normal = quote! {
         #field_write_func ?;
@@ -8,7 +8,7 @@ use syn::Lifetime;
 #[cfg(feature = "bits")]
 use syn::LitStr;
 
-use crate::Num;
+use crate::{Compression, DekuData, FieldData, Num};
 
 pub(crate) mod deku_read;
 pub(crate) mod deku_size;
@@ -340,6 +340,8 @@ fn gen_endian_from_str(s: &syn::LitStr) -> syn::Result<TokenStream> {
     match s.value().as_str() {
         "little" => Ok(quote! {::#crate_::ctx::Endian::Little}),
         "big" => Ok(quote! {::#crate_::ctx::Endian::Big}),
+        "big-word-swapped" | "cdab" => Ok(quote! {::#crate_::ctx::Endian::BigWordSwapped}),
+        "little-word-swapped" | "badc" => Ok(quote! {::#crate_::ctx::Endian::LittleWordSwapped}),
         _ => {
             // treat as variable, possibly from `ctx`
             let v: TokenStream = s.value().parse()?;
@@ -382,7 +384,7 @@ fn pad_bits(
     bits: Option<&TokenStream>,
     bytes: Option<&TokenStream>,
     bit_order: Option<&LitStr>,
-    emit_padding: fn(&TokenStream, bit_order: Option<&LitStr>) -> TokenStream,
+    emit_padding: impl Fn(&TokenStream, Option<&LitStr>) -> TokenStream,
 ) -> TokenStream {
     match (bits, bytes) {
         (Some(pad_bits), Some(pad_bytes)) => {
@@ -397,7 +399,7 @@ fn pad_bits(
 #[cfg(not(feature = "bits"))]
 fn pad_bytes(
     bytes: Option<&TokenStream>,
-    emit_padding: fn(&TokenStream) -> TokenStream,
+    emit_padding: impl Fn(&TokenStream) -> TokenStream,
 ) -> TokenStream {
     match bytes {
         Some(pad_bytes) => emit_padding(&quote! {((#pad_bytes))}),
@@ -405,6 +407,129 @@ fn pad_bytes(
     }
 }
 
+/// Generate the ctx used to read and write `reserved` bits as a `u128`
+fn gen_reserved_args(
+    endian: Option<&syn::LitStr>,
+    reserved: &Num,
+    bit_order: Option<&syn::LitStr>,
+) -> syn::Result<TokenStream> {
+    #[cfg(feature = "bits")]
+    let (bits, bytes) = (Some(Num::TokenStream(quote! { #reserved })), None);
+    #[cfg(not(feature = "bits"))]
+    let (bits, bytes) = (
+        None::<Num>,
+        Some(Num::TokenStream(quote! { (#reserved) / 8 })),
+    );
+
+    gen_field_args(endian, bits.as_ref(), bytes.as_ref(), None, bit_order)
+}
+
+/// Without the `bits` feature, `reserved` must be a whole number of bytes
+fn reserved_size_check(reserved: &Num) -> TokenStream {
+    if cfg!(feature = "bits") {
+        return quote! {};
+    }
+
+    let crate_ = get_crate_name();
+    quote! {
+        if (#reserved) % 8 != 0 {
+            return Err(::#crate_::deku_error!(::#crate_::DekuError::InvalidParam, "Reserved size must be a multiple of 8 without the `bits` feature", "{}", stringify!(#reserved)));
+        }
+    }
+}
+
+/// Generate the padding fill, the field attributes take precedence over the top-level ones
+fn gen_pad(
+    pad_value: Option<&Num>,
+    pad_pattern: Option<&syn::LitByteStr>,
+    input: &DekuData,
+) -> TokenStream {
+    let crate_ = get_crate_name();
+    let (pad_value, pad_pattern) = if pad_value.is_some() || pad_pattern.is_some() {
+        (pad_value, pad_pattern)
+    } else {
+        (input.pad_value.as_ref(), input.pad_pattern.as_ref())
+    };
+
+    match (pad_value, pad_pattern) {
+        (Some(value), _) => quote! { ::#crate_::writer::Pad::Value(#value) },
+        (None, Some(pattern)) => quote! { ::#crate_::writer::Pad::Pattern(#pattern) },
+        (None, None) => quote! { ::#crate_::writer::Pad::ZERO },
+    }
+}
+
+/// Generate the compression of a `compress` field and its decompressed size limit
+fn gen_compression(f: &FieldData) -> Option<(TokenStream, TokenStream)> {
+    let crate_ = get_crate_name();
+    let variant = match f.compress? {
+        Compression::Deflate => quote! { Deflate },
+        Compression::Zlib => quote! { Zlib },
+        Compression::Lz4 => quote! { Lz4 },
+    };
+    let limit = match &f.compress_limit {
+        Some(Num::LitInt(limit)) => quote! { #limit },
+        Some(Num::TokenStream(limit)) => quote! {
+            {
+                use core::borrow::Borrow;
+                usize::try_from(*((#limit).borrow()))?
+            }
+        },
+        None => quote! { ::#crate_::compression::DEFAULT_LIMIT },
+    };
+    Some((
+        quote! { ::#crate_::compression::Compression::#variant },
+        limit,
+    ))
+}
+
+/// Generate the transform of a `transform` field and its ctx
+fn gen_transform(f: &FieldData) -> Option<(TokenStream, TokenStream)> {
+    let transform = f.transform.as_ref()?;
+    let ctx = f.transform_ctx.clone().unwrap_or_else(|| quote! { () });
+    Some((quote! { #transform }, ctx))
+}
+
+/// Generate the size of a transformed field from `bytes`, the rest of the input when not set
+fn gen_transform_bytes(bytes: Option<&Num>) -> TokenStream {
+    match bytes {
+        Some(Num::LitInt(bytes)) => quote! { core::option::Option::Some(#bytes) },
+        Some(Num::TokenStream(bytes)) => quote! {
+            {
+                use core::borrow::Borrow;
+                core::option::Option::Some(usize::try_from(*((#bytes).borrow()))?)
+            }
+        },
+        None => quote! { core::option::Option::None },
+    }
+}
+
+/// Size in bits of a struct declared with the top-level `bits` or `bytes` attribute
+fn struct_size_bits(input: &DekuData) -> Option<TokenStream> {
+    #[cfg(feature = "bits")]
+    if let Some(bits) = &input.bits {
+        return Some(quote! { (#bits) });
+    }
+
+    input.bytes.as_ref().map(|bytes| quote! { ((#bytes) * 8) })
+}
+
+/// Compare the bits used since `__deku_struct_start` with the declared struct size.
+/// Leaves the bits remaining to the declared size in `__deku_struct_pad`
+fn struct_size_check(input: &DekuData, size: &TokenStream, position: &TokenStream) -> TokenStream {
+    let crate_ = get_crate_name();
+    let ident = input.ident.to_string();
+    quote! {
+        let __deku_struct_size: usize = core::convert::TryFrom::try_from(#size).map_err(|e|
+            ::#crate_::deku_error!(::#crate_::DekuError::InvalidParam, "Invalid struct size, cannot convert to usize", "{}", stringify!(#size))
+        )?;
+        let __deku_struct_len = #position - __deku_struct_start;
+        if __deku_struct_len > __deku_struct_size {
+            return Err(::#crate_::deku_error!(::#crate_::DekuError::Assertion, "Struct exceeds declared size", "{}: {} bits, expected {}", #ident, __deku_struct_len, __deku_struct_size));
+        }
+        let __deku_struct_pad = __deku_struct_size - __deku_struct_len;
+    }
+}
+
 /// assertion is false, raise error
 fn assertion_failed(
     v: &TokenStream,
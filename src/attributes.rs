@@ -41,8 +41,8 @@ enum DekuEnum {
 | [seek_rewind](#seek_rewind) | top-level, field | Rewind the reader and writer to the beginning
 | [assert](#assert) | field | Assert a condition
 | [assert_eq](#assert_eq) | field | Assert equals on the field
-| [bits](#bits) | field | Set the bit-size of the field
-| [bytes](#bytes) | field | Set the byte-size of the field
+| [bits](#bits) | struct: top-level, field | Set the bit-size of the field or struct
+| [bytes](#bytes) | struct: top-level, field | Set the byte-size of the field or struct
 | [count](#count) | field | Set the field representing the element count of a container
 | [bits_read](#bits_read) | field | Set the field representing the number of bits to read into a container
 | [bytes_read](#bytes_read) | field | Set the field representing the number of bytes to read into a container
@@ -56,6 +56,12 @@ enum DekuEnum {
 | [pad_bits_before](#pad_bits_before) | field | Skip bits before reading, pad before writing
 | [pad_bytes_after](#pad_bytes_after) | field | Skip bytes after reading, pad after writing
 | [pad_bits_after](#pad_bits_after) | field | Skip bits after reading, pad after writing
+| [pad_value](#pad_value-and-pad_pattern) | top-level, field | Byte used to fill padding when writing
+| [pad_pattern](#pad_value-and-pad_pattern) | top-level, field | Byte pattern used to fill padding when writing
+| [reserved](#reserved) | field | Reserved bits with a fixed value, checked when reading
+| [compress](#compress) | field | Decompress the field when reading, compress it when writing
+| [compress_limit](#compress) | field | Limit of the decompressed size of a `compress` field
+| [transform](#transform) | field | Decode the field bytes when reading, encode them when writing
 | [cond](#cond) | field | Conditional expression for the field
 | [default](#default) | field | Provide default value. Used with [skip](#skip) or [cond](#cond)
 | [map](#map) | field | Specify a function or lambda to apply to the result of the read
@@ -69,12 +75,17 @@ enum DekuEnum {
 | enum: [id_type](#id_type) | top-level | Set the type of the variant `id`
 | enum: [bits](#bits-1) | top-level | Set the bit-size of the variant `id`
 | enum: [bytes](#bytes-1) | top-level | Set the byte-size of the variant `id`
+| enum: [try_variants](#try_variants) | top-level | Read the first variant that succeeds, instead of matching an `id`
 
 # endian
 
 Set to read/write bytes in a specific byte order.
 
-Values: `big`, `little` or an expression which returns a [`Endian`](super::ctx::Endian)
+Values: `big`, `little`, `big-word-swapped` (`cdab`), `little-word-swapped` (`badc`)
+or an expression which returns a [`Endian`](super::ctx::Endian)
+
+The word swapped variants keep the byte order within each 16-bit word but reverse
+the order of the words, as used by many Modbus devices for 32 and 64-bit values.
 
 Precedence: field > top-level > system endianness (default)
 
@@ -300,6 +311,10 @@ assert_eq!(bytes, data);
 # #[cfg(not(all(feature = "alloc", feature = "bits")))]
 # fn main() {}
 ````
+With `endian = "big"`, a field wider than a byte keeps its trailing partial byte as the least
+significant byte of the value, as `offset` above reads `0x1001` from `0x10` and the low 5 bits of
+`0x81`. Signed fields close that gap before sign extending: as an `i16`, `offset` would be
+`0x0201` and its sign bit the top bit of `0x10`.
 ### Field Example
 ```rust
 # use core::convert::{TryInto, TryFrom};
@@ -875,6 +890,50 @@ assert_eq!(&*data, value);
 # fn main() {}
 ```
 
+When used on a struct, `bits` and `bytes` declare the size of the whole struct, including a
+top-level [magic](#magic). The remainder after the last field is skipped when reading and
+padded with zeros when writing. Fields exceeding the declared size return a
+[DekuError::Assertion](crate::DekuError::Assertion). When deriving [DekuSize](crate::DekuSize),
+the field sizes are checked against the declared size at compile time.
+
+The size of a whole enum can't be declared: on an enum, top-level `bits` and `bytes` set the
+size of the variant `id`, see [enum bits](#bits-1).
+
+Example:
+```rust
+# #[cfg(feature = "alloc")]
+# extern crate alloc;
+# #[cfg(feature = "alloc")]
+# use alloc::vec::Vec;
+# use core::convert::{TryInto, TryFrom};
+# use deku::prelude::*;
+# #[cfg(feature = "bits")]
+# #[derive(Debug, PartialEq, DekuRead, DekuWrite, DekuSize)]
+#[deku(bits = 16, endian = "big")]
+struct Register {
+    #[deku(bits = 3)]
+    mode: u8,
+    #[deku(bits = 9)]
+    value: u16,
+    // 4 reserved bits
+}
+
+# #[cfg(all(feature = "alloc", feature = "bits"))]
+# fn main() {
+assert_eq!(16, Register::SIZE_BITS);
+
+let data: &[u8] = &[0b101_00000, 0b0011_1111];
+let value = Register::try_from(data).unwrap();
+assert_eq!(Register { mode: 0b101, value: 3 }, value);
+
+let value: Vec<u8> = value.try_into().unwrap();
+assert_eq!(vec![0b101_00000, 0b0011_0000], value);
+# }
+#
+# #[cfg(not(all(feature = "alloc", feature = "bits")))]
+# fn main() {}
+```
+
 
 # bytes
 
@@ -1005,6 +1064,9 @@ assert_eq!(data, value);
 
 ## Specializations
 - `Vec<u8>`: `count` used with a byte vector will result in one invocation to `read_bytes`, thus improving performance.
+- `Vec<T>` and `[T; N]` of integers and floats, without `bits` or `bytes`: read and
+  written as one buffer, byte swapped in place, instead of one value at a time. This also applies to
+  [bytes_read](#bytes_read) when it is a multiple of the size of `T`.
 
 # bytes_read
 
@@ -1355,6 +1417,8 @@ assert_eq!(bytes, &[0x01, 0x00, 0x03]); // field_a, field_c, field_e
 
 Skip a number of bytes before reading, pad with 0x00s before writing
 
+The bytes are read and discarded, this doesn't require the `bits` feature.
+
 Example:
 
 ```rust
@@ -1589,6 +1653,208 @@ assert_eq!(vec![0b10_00_1001], value);
 # fn main() {}
 ```
 
+# pad_value and pad_pattern
+
+Fill the padding with a byte (a `u8`) or a repeating byte pattern when writing, instead of 0s.
+The fill starts at the beginning of each padding, reading is unaffected.
+
+On a field, this applies to the `pad_*` attributes of the field. At the top-level, this is
+the default for all the field paddings, the padding of a struct declaring its size with
+[bits](#bits) or [bytes](#bytes), and the padding of the last byte added by
+[`to_bytes`](crate::DekuContainerWrite::to_bytes) and [`to_slice`](crate::DekuContainerWrite::to_slice).
+
+Example:
+
+```rust
+# use deku::prelude::*;
+# #[cfg(feature = "alloc")]
+#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[deku(pad_value = "0xFF")]
+pub struct DekuTest {
+    #[deku(pad_bytes_after = "2")]
+    pub field_a: u8,
+    #[deku(pad_bytes_after = "3", pad_pattern = b"\xDE\xAD")]
+    pub field_b: u8,
+}
+
+# #[cfg(feature = "alloc")]
+# fn main() {
+let value = DekuTest {
+    field_a: 0xAA,
+    field_b: 0xBB,
+};
+
+assert_eq!(
+    vec![0xAA, 0xFF, 0xFF, 0xBB, 0xDE, 0xAD, 0xDE],
+    value.to_bytes().unwrap()
+);
+# }
+#
+# #[cfg(not(feature = "alloc"))]
+# fn main() {}
+```
+
+# reserved
+
+Reserve a number of bits in place of the field. The field is not read, it is set to its
+[Default](core::default::Default) value, and is typically of type `()`.
+
+`value` (default `0`) is written in place of the field, and compared with the bits
+read according to `check`:
+- `strict` (default): return a [DekuError::Assertion](crate::DekuError::Assertion) on mismatch
+- `warn`: log a warning with the `logging` feature, and continue
+- `ignore`: don't check the reserved bits
+
+The reserved bits are read and written like an integer of `reserved` bits, using the field
+`endian` and `bit_order`. Up to 128 bits can be reserved, which must be a multiple of 8
+without the `bits` feature.
+
+Example:
+```rust
+# #[cfg(feature = "alloc")]
+# extern crate alloc;
+# #[cfg(feature = "alloc")]
+# use alloc::vec::Vec;
+# use core::convert::{TryInto, TryFrom};
+# use deku::prelude::*;
+# #[cfg(feature = "bits")]
+# #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+struct DekuTest {
+    #[deku(bits = 2)]
+    field_a: u8,
+    #[deku(reserved = 2)]
+    reserved: (),
+    #[deku(reserved = 4, value = 0b1010, check = "ignore")]
+    pattern: (),
+}
+
+# #[cfg(all(feature = "alloc", feature = "bits"))]
+# fn main() {
+let data: &[u8] = &[0b10_00_1111];
+let value = DekuTest::try_from(data).unwrap();
+
+assert_eq!(
+    DekuTest {
+        field_a: 0b10,
+        reserved: (),
+        pattern: (),
+    },
+    value
+);
+
+let value: Vec<u8> = value.try_into().unwrap();
+assert_eq!(vec![0b10_00_1010], value);
+
+let data: &[u8] = &[0b10_01_1010];
+assert!(matches!(DekuTest::try_from(data), Err(DekuError::Assertion(_))));
+# }
+#
+# #[cfg(not(all(feature = "alloc", feature = "bits")))]
+# fn main() {}
+```
+
+# compress
+
+Decompress a window of the input before reading the field, and compress the field after
+writing it. This requires the `deflate` feature for `"deflate"` and `"zlib"`, or the `lz4`
+feature for `"lz4"`, see [compression](crate::compression).
+
+With `compress`, [bytes](#bytes) sets the compressed size of the field. Without it,
+the rest of the input is decompressed. The other attributes apply to the decompressed data,
+such as [count](#count), [bytes_read](#bytes_read) or [read_all](#read_all) on a container.
+
+The compressed size is not updated when writing, it can be computed with
+[Compression::compress](crate::compression::Compression::compress) in an [update](#update).
+Writing fails with [InvalidParam](crate::DekuError::InvalidParam) if `bytes` isn't the
+compressed size.
+
+Decompressing more than `compress_limit` bytes fails with [Parse](crate::DekuError::Parse),
+so that a small input can't exhaust the memory. The limit defaults to
+[DEFAULT_LIMIT](crate::compression::DEFAULT_LIMIT), 16 MiB, and can be an expression using
+previous fields and the ctx.
+
+Example:
+```rust
+# #[cfg(feature = "deflate")]
+# fn main() {
+# use deku::prelude::*;
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+struct DekuTest {
+    len: u8,
+    #[deku(compress = "zlib", bytes = "len", compress_limit = "1024", read_all)]
+    data: Vec<u8>,
+}
+
+let data: &[u8] = &[0x0B, 0x78, 0x9C, 0x63, 0x64, 0x62, 0x06, 0x00, 0x00, 0x0D, 0x00, 0x07];
+let (_rest, value) = DekuTest::from_bytes((data, 0)).unwrap();
+assert_eq!(
+    DekuTest {
+        len: 11,
+        data: vec![1, 2, 3],
+    },
+    value
+);
+# }
+#
+# #[cfg(not(feature = "deflate"))]
+# fn main() {}
+```
+
+# transform
+
+Decode a window of the input with a [Transform](crate::transform::Transform) before
+reading the field, and encode the field after writing it. `transform` is an expression
+evaluating to the transform, `transform_ctx` is an expression passed to it (default `()`),
+both can use previous fields and the ctx.
+
+As with [compress](#compress), [bytes](#bytes) sets the size of the encoded field, the rest of
+the input is used without it, and writing fails if it isn't the encoded size. The other
+attributes apply to the decoded data.
+
+Example:
+```rust
+# #[cfg(feature = "alloc")]
+# fn main() {
+# use deku::prelude::*;
+use deku::transform::Transform;
+
+struct Xor;
+
+impl Transform<u8> for Xor {
+    fn decode(&self, input: &[u8], key: u8) -> Result<Vec<u8>, DekuError> {
+        Ok(input.iter().map(|b| b ^ key).collect())
+    }
+
+    fn encode(&self, input: &[u8], key: u8) -> Result<Vec<u8>, DekuError> {
+        self.decode(input, key)
+    }
+}
+
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+#[deku(ctx = "key: u8")]
+struct DekuTest {
+    len: u8,
+    #[deku(transform = "Xor", transform_ctx = "key", bytes = "len", count = "len")]
+    data: Vec<u8>,
+}
+
+let data: &[u8] = &[0x02, 0xFE, 0xFD];
+let mut cursor = deku::no_std_io::Cursor::new(data);
+let mut reader = Reader::new(&mut cursor);
+let value = DekuTest::from_reader_with_ctx(&mut reader, 0xFF).unwrap();
+assert_eq!(
+    DekuTest {
+        len: 2,
+        data: vec![1, 2],
+    },
+    value
+);
+# }
+#
+# #[cfg(not(feature = "alloc"))]
+# fn main() {}
+```
+
 # cond
 
 Specify a condition to parse or skip a field
@@ -2248,5 +2514,58 @@ assert_eq!(data, value);
 # fn main() {}
 ```
 
+# try_variants
+
+Read an enum without an `id`: the variants are tried in order, and the first one to read
+successfully is returned. After a variant fails, the reader is rewound with
+[Reader::restore](crate::reader::Reader::restore) to where the enum started, leftover bits
+included, before trying the next one. An I/O error is returned right away. If no variant can
+be read, a `DekuError::Incomplete` is returned if a variant needed more data, otherwise a
+`DekuError::Parse` with the error of the last variant.
+
+This requires the reader to seek backwards, so it doesn't work over
+[NoSeek](crate::noseek::NoSeek). When writing, only the fields of the variant are written.
+
+**Note**: Cannot be used in combination with [id_type](#id_type), [id](#id), [id_endian](#id_endian),
+[bits](#bits-1) or [bytes](#bytes-1), nor with `id`, `id_pat` or `default` on variants
+
+Example:
+```rust
+# use core::convert::{TryInto, TryFrom};
+# use deku::prelude::*;
+# #[cfg(feature = "std")]
+# #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[deku(try_variants)]
+enum DekuTest {
+    Text {
+        #[deku(assert = "*len < 0x80")]
+        len: u8,
+        #[deku(count = "len")]
+        text: Vec<u8>,
+    },
+    Number(#[deku(endian = "big")] u32),
+}
+
+# #[cfg(feature = "std")]
+# fn main() {
+let data: &[u8] = &[0x02, b'h', b'i'];
+let value = DekuTest::try_from(data).unwrap();
+assert_eq!(
+    DekuTest::Text { len: 2, text: b"hi".to_vec() },
+    value
+);
+
+let data: &[u8] = &[0x80, 0x00, 0x00, 0x01];
+let value = DekuTest::try_from(data).unwrap();
+assert_eq!(DekuTest::Number(0x8000_0001), value);
+
+let value: Vec<u8> = value.try_into().unwrap();
+assert_eq!(data, value);
+# }
+#
+# #[cfg(not(feature = "std"))]
+# fn main() {}
+```
+
 [reader.end()]: crate::reader::Reader::end()
 */
@@ -0,0 +1,264 @@
+//! Integration with the [bytes](https://crates.io/crates/bytes) crate
+//!
+//! - `Bytes` and `BytesMut` can be used as fields, read with `count`, `bytes_read`, `until` or
+//!   `read_all` like `Vec<u8>`. When reading from a [Reader::new_shared], `Bytes` fields are
+//!   slices of the input instead of copies.
+//! - [Reader::from_buf] and [Writer::from_buf_mut] read from any [Buf] and write to any
+//!   [BufMut] through [BufIo], without requiring [Seek](crate::no_std_io::Seek).
+//! - [DekuCodec] decodes and encodes frames of a stream of `BytesMut`, in the style of
+//!   `tokio_util::codec`.
+//!
+//! ```rust
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use bytes::{Bytes, BytesMut};
+//! use deku::buf::DekuCodec;
+//! use deku::prelude::*;
+//!
+//! #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+//! #[deku(endian = "big")]
+//! struct Frame {
+//!     #[deku(update = "self.payload.len()")]
+//!     len: u16,
+//!     #[deku(count = "len")]
+//!     payload: Bytes,
+//! }
+//!
+//! let mut codec = DekuCodec::<Frame>::new();
+//!
+//! let mut stream = BytesMut::new();
+//! let frame = Frame { len: 3, payload: Bytes::from_static(b"abc") };
+//! codec.encode(&frame, &mut stream).unwrap();
+//! codec.encode(&frame, &mut stream).unwrap();
+//!
+//! // frames may be split across reads
+//! let mut input = BytesMut::from(&stream[..6]);
+//! assert_eq!(codec.decode(&mut input).unwrap(), Some(Frame { len: 3, payload: Bytes::from_static(b"abc") }));
+//! assert_eq!(codec.decode(&mut input).unwrap(), None);
+//!
+//! input.extend_from_slice(&stream[6..]);
+//! assert_eq!(codec.decode(&mut input).unwrap(), Some(frame));
+//! assert_eq!(codec.decode_eof(&mut input).unwrap(), None);
+//! # }
+//! #
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+
+use core::marker::PhantomData;
+
+use bytes::{Buf, BufMut, Bytes, BytesMut};
+
+use crate::no_std_io::{Read, Result, Write};
+use crate::noseek::NoSeek;
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{DekuContainerRead, DekuContainerWrite, DekuError};
+
+/// Adapter implementing [Read] for a [Buf] and [Write] for a [BufMut]
+///
+/// Reads return fewer bytes once the `Buf` has no more remaining, writes once the `BufMut`
+/// is full. Wrap it in [NoSeek] to use it with [Reader] and [Writer], as
+/// [Reader::from_buf] and [Writer::from_buf_mut] do.
+#[derive(Debug)]
+pub struct BufIo<B> {
+    inner: B,
+}
+
+impl<B> BufIo<B> {
+    /// Creates a new adapter for `inner`
+    pub fn new(inner: B) -> Self {
+        Self { inner }
+    }
+
+    /// Gets a mutable reference to the underlying buffer
+    pub fn get_mut(&mut self) -> &mut B {
+        &mut self.inner
+    }
+
+    /// Gets a reference to the underlying buffer
+    pub fn get_ref(&self) -> &B {
+        &self.inner
+    }
+
+    /// Consumes this adapter, returning the underlying buffer
+    pub fn into_inner(self) -> B {
+        self.inner
+    }
+}
+
+impl<B: Buf> Read for BufIo<B> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let len = core::cmp::min(buf.len(), self.inner.remaining());
+        self.inner.copy_to_slice(&mut buf[..len]);
+        Ok(len)
+    }
+}
+
+impl<B: BufMut> Write for BufIo<B> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        let len = core::cmp::min(buf.len(), self.inner.remaining_mut());
+        self.inner.put_slice(&buf[..len]);
+        Ok(len)
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl<B: Buf> Reader<NoSeek<BufIo<B>>> {
+    /// Create a new `Reader` consuming `buf`
+    ///
+    /// `Bytes` fields are copied out of `buf`, see [Reader::new_shared] to slice them instead.
+    pub fn from_buf(buf: B) -> Self {
+        Reader::new(NoSeek::new(BufIo::new(buf)))
+    }
+}
+
+impl<B: BufMut> Writer<NoSeek<BufIo<B>>> {
+    /// Create a new `Writer` appending to `buf`
+    pub fn from_buf_mut(buf: B) -> Self {
+        Writer::new(NoSeek::new(BufIo::new(buf)))
+    }
+}
+
+/// Decoder and encoder of `T` frames over a stream of `BytesMut`
+///
+/// [decode](Self::decode), [decode_eof](Self::decode_eof) and [encode](Self::encode) mirror
+/// `tokio_util::codec::Decoder` and `Encoder`, which can be implemented by forwarding to them.
+///
+/// Decoding takes the bytes of `src` into the codec, to read `Bytes` fields as slices of
+/// them. Bytes following a decoded frame stay in the codec until the next call, which is
+/// also the first to see bytes added to `src` since.
+#[derive(Debug)]
+pub struct DekuCodec<T> {
+    /// Bytes taken from `src` which were not decoded yet
+    pending: Bytes,
+    _marker: PhantomData<fn() -> T>,
+}
+
+impl<T> Default for DekuCodec<T> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<T> DekuCodec<T> {
+    /// Create a new codec
+    pub fn new() -> Self {
+        Self {
+            pending: Bytes::new(),
+            _marker: PhantomData,
+        }
+    }
+
+    /// Bytes taken from `src` by [decode](Self::decode) which are not part of a frame yet
+    pub fn pending(&self) -> &[u8] {
+        &self.pending
+    }
+
+    /// Move the bytes of `src` after the pending bytes
+    fn take(&mut self, src: &mut BytesMut) {
+        if src.is_empty() {
+            return;
+        }
+        if self.pending.is_empty() {
+            self.pending = src.split().freeze();
+            return;
+        }
+        // decoded frames may still share the pending bytes, which are copied once in that case
+        let pending = core::mem::take(&mut self.pending);
+        let mut pending = pending
+            .try_into_mut()
+            .unwrap_or_else(|pending| BytesMut::from(&pending[..]));
+        pending.unsplit(src.split());
+        self.pending = pending.freeze();
+    }
+}
+
+impl<T: for<'a> DekuContainerRead<'a>> DekuCodec<T> {
+    /// Decode the next frame, `None` if more bytes are needed
+    ///
+    /// Fails if the pending bytes can't be a frame, in which case they are kept.
+    pub fn decode(&mut self, src: &mut BytesMut) -> core::result::Result<Option<T>, DekuError> {
+        self.take(src);
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+        match self.read() {
+            Err(DekuError::Incomplete(_)) => Ok(None),
+            res => res.map(Some),
+        }
+    }
+
+    /// Decode the next frame at the end of the stream, failing with
+    /// [Incomplete](DekuError::Incomplete) if the pending bytes aren't a whole frame
+    pub fn decode_eof(&mut self, src: &mut BytesMut) -> core::result::Result<Option<T>, DekuError> {
+        self.take(src);
+        if self.pending.is_empty() {
+            return Ok(None);
+        }
+        self.read().map(Some)
+    }
+
+    fn read(&mut self) -> core::result::Result<T, DekuError> {
+        let mut reader = Reader::new_shared(self.pending.clone());
+        let value = T::from_reader_with_ctx(&mut reader, ())?;
+        let read = reader.as_mut().position() as usize - reader.peeked_len();
+        self.pending.advance(read);
+        Ok(value)
+    }
+}
+
+impl<T: DekuContainerWrite> DekuCodec<T> {
+    /// Encode `item` at the end of `dst`
+    ///
+    /// On failure, `dst` is left as it was.
+    pub fn encode(&mut self, item: &T, dst: &mut BytesMut) -> core::result::Result<(), DekuError> {
+        let len = dst.len();
+        let res = {
+            let mut writer = Writer::from_buf_mut(&mut *dst);
+            writer.set_pad(T::PAD);
+            item.to_writer(&mut writer, ())
+                .and_then(|_| writer.finalize())
+        };
+        if res.is_err() {
+            dst.truncate(len);
+        }
+        res
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::ctx::{Endian, Limit};
+    use crate::{DekuReader, DekuWriter};
+
+    #[test]
+    fn test_from_buf() {
+        let mut input = Bytes::from_static(&[0x01, 0x02, 0x03, 0x04]).chain(&[0x05][..]);
+        let mut reader = Reader::from_buf(&mut input);
+        let value = u32::from_reader_with_ctx(&mut reader, Endian::Big).unwrap();
+        assert_eq!(value, 0x01020304);
+        let rest = Bytes::from_reader_with_ctx(&mut reader, Limit::end()).unwrap();
+        assert_eq!(rest, [0x05][..]);
+        assert!(!input.has_remaining());
+    }
+
+    #[test]
+    fn test_from_buf_mut() {
+        let mut out = [0u8; 3];
+        let mut buf = &mut out[..];
+        let mut writer = Writer::from_buf_mut(&mut buf);
+        0x0102u16.to_writer(&mut writer, Endian::Big).unwrap();
+        0x03u8.to_writer(&mut writer, ()).unwrap();
+        assert!(matches!(
+            0x04u8.to_writer(&mut writer, ()),
+            Err(DekuError::Io(_))
+        ));
+        assert_eq!(out, [0x01, 0x02, 0x03]);
+    }
+}
@@ -0,0 +1,244 @@
+//! Compressed fields
+//!
+//! A field with the `compress` attribute is compressed as a whole, see
+//! [compress](crate::attributes#compress). The generated code uses [`read`] and [`write`],
+//! [`Compression`] can also be used directly, such as to compute the compressed size of a field
+//! in an `update`.
+//!
+//! The available algorithms depend on the crate features:
+//! - `deflate`: [`Compression::Deflate`] (raw deflate) and [`Compression::Zlib`], using [miniz_oxide]
+//! - `lz4`: [`Compression::Lz4`], an LZ4 block prefixed with its decompressed size as a
+//!   little endian `u32`, using [lz4_flex]
+//!
+//! [miniz_oxide]: https://crates.io/crates/miniz_oxide
+//! [lz4_flex]: https://crates.io/crates/lz4_flex
+
+use alloc::vec::Vec;
+
+use no_std_io::io::{Cursor, Read, Seek, Write};
+
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{deku_error, DekuError};
+
+/// Default limit of the decompressed size of a field, see [compress](crate::attributes#compress)
+pub const DEFAULT_LIMIT: usize = 16 * 1024 * 1024;
+
+/// Compression algorithm of a field
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+#[non_exhaustive]
+pub enum Compression {
+    /// Raw deflate stream, [RFC 1951](https://www.rfc-editor.org/rfc/rfc1951)
+    #[cfg(feature = "deflate")]
+    Deflate,
+    /// Deflate stream with a zlib header, [RFC 1950](https://www.rfc-editor.org/rfc/rfc1950)
+    #[cfg(feature = "deflate")]
+    Zlib,
+    /// LZ4 block prefixed with its decompressed size
+    #[cfg(feature = "lz4")]
+    Lz4,
+}
+
+impl Compression {
+    /// Compress `input`
+    pub fn compress(&self, input: &[u8]) -> Vec<u8> {
+        match self {
+            #[cfg(feature = "deflate")]
+            Self::Deflate => miniz_oxide::deflate::compress_to_vec(input, 6),
+            #[cfg(feature = "deflate")]
+            Self::Zlib => miniz_oxide::deflate::compress_to_vec_zlib(input, 6),
+            #[cfg(feature = "lz4")]
+            Self::Lz4 => lz4_flex::compress_prepend_size(input),
+        }
+    }
+
+    /// Decompress `input`, up to [`DEFAULT_LIMIT`] bytes
+    pub fn decompress(&self, input: &[u8]) -> Result<Vec<u8>, DekuError> {
+        self.decompress_with_limit(input, DEFAULT_LIMIT)
+    }
+
+    /// Decompress `input`, failing if it decompresses to more than `limit` bytes
+    pub fn decompress_with_limit(&self, input: &[u8], limit: usize) -> Result<Vec<u8>, DekuError> {
+        match self {
+            #[cfg(feature = "deflate")]
+            Self::Deflate => miniz_oxide::inflate::decompress_to_vec_with_limit(input, limit)
+                .map_err(|e| inflate_error(e.status, limit)),
+            #[cfg(feature = "deflate")]
+            Self::Zlib => miniz_oxide::inflate::decompress_to_vec_zlib_with_limit(input, limit)
+                .map_err(|e| inflate_error(e.status, limit)),
+            #[cfg(feature = "lz4")]
+            Self::Lz4 => {
+                // the size is checked before it is allocated
+                let (size, _) = lz4_flex::block::uncompressed_size(input)
+                    .map_err(|e| deku_error!(DekuError::Parse, "invalid lz4 data", "{}", e))?;
+                if size > limit {
+                    return Err(limit_error(limit));
+                }
+                lz4_flex::decompress_size_prepended(input)
+                    .map_err(|e| deku_error!(DekuError::Parse, "invalid lz4 data", "{}", e))
+            }
+        }
+    }
+}
+
+#[cfg(feature = "deflate")]
+fn inflate_error(status: miniz_oxide::inflate::TINFLStatus, limit: usize) -> DekuError {
+    match status {
+        miniz_oxide::inflate::TINFLStatus::HasMoreOutput => limit_error(limit),
+        status => deku_error!(DekuError::Parse, "invalid deflate data", "{:?}", status),
+    }
+}
+
+fn limit_error(limit: usize) -> DekuError {
+    deku_error!(
+        DekuError::Parse,
+        "decompressed data exceeds limit",
+        "{} bytes",
+        limit
+    )
+}
+
+/// Read `bytes` bytes, or the rest of the input when `None`, and run `f` on them decompressed
+/// with `compression`, up to `limit` bytes
+pub fn read<R, T, F>(
+    reader: &mut Reader<R>,
+    compression: Compression,
+    limit: usize,
+    bytes: Option<usize>,
+    f: F,
+) -> Result<T, DekuError>
+where
+    R: Read + Seek,
+    F: FnOnce(&mut Reader<Cursor<&[u8]>>) -> Result<T, DekuError>,
+{
+    let input = reader.read_window(bytes)?;
+    let data = compression.decompress_with_limit(&input, limit)?;
+    let mut inner = Reader::new(Cursor::new(data.as_slice()));
+    f(&mut inner)
+}
+
+/// Run `f` into a buffer and write it compressed with `compression`, failing with
+/// [InvalidParam](DekuError::InvalidParam) if `bytes` is given and isn't the compressed size
+///
+/// The last byte written by `f` is completed with zeros.
+pub fn write<W, F>(
+    writer: &mut Writer<W>,
+    compression: Compression,
+    bytes: Option<usize>,
+    f: F,
+) -> Result<(), DekuError>
+where
+    W: Write + Seek,
+    F: FnOnce(&mut Writer<Cursor<&mut Vec<u8>>>) -> Result<(), DekuError>,
+{
+    let mut data = Vec::new();
+    let mut inner = Writer::new(Cursor::new(&mut data));
+    f(&mut inner)?;
+    inner.finalize()?;
+
+    writer.write_encoded(&compression.compress(&data), bytes)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use rstest::rstest;
+
+    use super::*;
+    use crate::{DekuReader, DekuWriter};
+
+    #[rstest(compression,
+        #[cfg(feature = "deflate")]
+        case(Compression::Deflate),
+        #[cfg(feature = "deflate")]
+        case(Compression::Zlib),
+        #[cfg(feature = "lz4")]
+        case(Compression::Lz4),
+    )]
+    fn test_round_trip(compression: Compression) {
+        let value: Vec<u16> = [0x1234; 100].to_vec();
+
+        let mut out = Vec::new();
+        let mut writer = Writer::new(Cursor::new(&mut out));
+        write(&mut writer, compression, None, |writer| {
+            value.to_writer(writer, crate::ctx::Endian::Big)
+        })
+        .unwrap();
+        assert!(out.len() < 200);
+        assert_eq!(value.len() * 2, compression.decompress(&out).unwrap().len());
+
+        let len = out.len();
+        out.extend_from_slice(&[0xAA, 0xBB]);
+        let mut cursor = Cursor::new(out.as_slice());
+        let mut reader = Reader::new(&mut cursor);
+        let res = read(
+            &mut reader,
+            compression,
+            DEFAULT_LIMIT,
+            Some(len),
+            |reader| {
+                Vec::<u16>::from_reader_with_ctx(
+                    reader,
+                    (crate::ctx::Limit::end(), crate::ctx::Endian::Big),
+                )
+            },
+        )
+        .unwrap();
+        assert_eq!(value, res);
+        assert_eq!(len * 8, reader.bits_read);
+    }
+
+    #[cfg(feature = "deflate")]
+    #[test]
+    fn test_invalid() {
+        let mut cursor = Cursor::new([0xFF, 0xFF, 0xFF].as_slice());
+        let mut reader = Reader::new(&mut cursor);
+        let res = read(
+            &mut reader,
+            Compression::Zlib,
+            DEFAULT_LIMIT,
+            None,
+            |reader| u8::from_reader_with_ctx(reader, ()),
+        );
+        assert!(matches!(res, Err(DekuError::Parse(_))));
+        assert!(reader.end());
+    }
+
+    #[rstest(compression,
+        #[cfg(feature = "deflate")]
+        case(Compression::Deflate),
+        #[cfg(feature = "deflate")]
+        case(Compression::Zlib),
+        #[cfg(feature = "lz4")]
+        case(Compression::Lz4),
+    )]
+    fn test_limit(compression: Compression) {
+        let bomb = compression.compress(&[0; 1024 * 1024]);
+        assert!(bomb.len() < 8 * 1024);
+
+        let res = compression.decompress_with_limit(&bomb, 1024 * 1024 - 1);
+        assert!(matches!(res, Err(DekuError::Parse(_))));
+        assert_eq!(
+            1024 * 1024,
+            compression
+                .decompress_with_limit(&bomb, 1024 * 1024)
+                .unwrap()
+                .len()
+        );
+
+        let mut cursor = Cursor::new(bomb.as_slice());
+        let mut reader = Reader::new(&mut cursor);
+        let res = read(&mut reader, compression, 1024, None, |reader| {
+            u8::from_reader_with_ctx(reader, ())
+        });
+        assert!(matches!(res, Err(DekuError::Parse(_))));
+    }
+
+    #[cfg(feature = "lz4")]
+    #[test]
+    fn test_lz4_size_limit() {
+        // a bogus size isn't allocated
+        let input = [0xFF, 0xFF, 0xFF, 0xFF, 0x00];
+        let res = Compression::Lz4.decompress(&input);
+        assert!(matches!(res, Err(DekuError::Parse(_))));
+    }
+}
@@ -5,12 +5,30 @@ use core::marker::PhantomData;
 use core::str::FromStr;
 
 /// An endian
+///
+/// Besides plain little and big endian, the word swapped variants describe values made of
+/// 16-bit words, such as 32-bit floats sent by Modbus devices. For `0xAABBCCDD` the bytes are
+///
+/// | Variant                          | Bytes         |
+/// |----------------------------------|---------------|
+/// | [`Big`](Endian::Big)             | `AA BB CC DD` |
+/// | [`Little`](Endian::Little)       | `DD CC BB AA` |
+/// | [`BigWordSwapped`](Endian::BigWordSwapped)       | `CC DD AA BB` |
+/// | [`LittleWordSwapped`](Endian::LittleWordSwapped) | `BB AA DD CC` |
+///
+/// Word swapping only applies to values wider than 16 bits, and their size must then be a
+/// multiple of 16 bits.
 #[derive(Debug, Copy, Clone, Eq, PartialEq)]
+#[non_exhaustive]
 pub enum Endian {
     /// Little endian
     Little,
     /// Big endian
     Big,
+    /// Big endian 16-bit words in little endian word order, also known as `CDAB`
+    BigWordSwapped,
+    /// Little endian 16-bit words in big endian word order, also known as `BADC` or PDP-endian
+    LittleWordSwapped,
 }
 
 /// Bit numbering
@@ -44,16 +62,31 @@ impl Endian {
         endian
     }
 
-    /// Is it little endian
+    /// Is it little endian, within 16-bit words for word swapped endians
     #[inline]
     pub fn is_le(self) -> bool {
-        self == Endian::Little
+        self.without_word_swap() == Endian::Little
     }
 
-    /// Is it big endian
+    /// Is it big endian, within 16-bit words for word swapped endians
     #[inline]
     pub fn is_be(self) -> bool {
-        self == Endian::Big
+        self.without_word_swap() == Endian::Big
+    }
+
+    /// Is the order of 16-bit words reversed
+    #[inline]
+    pub const fn is_word_swapped(self) -> bool {
+        matches!(self, Endian::BigWordSwapped | Endian::LittleWordSwapped)
+    }
+
+    /// Byte order within a 16-bit word, [`Endian::Little`] or [`Endian::Big`]
+    #[inline]
+    pub const fn without_word_swap(self) -> Self {
+        match self {
+            Endian::Little | Endian::LittleWordSwapped => Endian::Little,
+            Endian::Big | Endian::BigWordSwapped => Endian::Big,
+        }
     }
 }
 
@@ -76,6 +109,10 @@ impl FromStr for Endian {
     /// use deku::ctx::Endian;
     /// assert_eq!(FromStr::from_str("little"), Ok(Endian::Little));
     /// assert_eq!(FromStr::from_str("big"), Ok(Endian::Big));
+    /// assert_eq!(FromStr::from_str("big-word-swapped"), Ok(Endian::BigWordSwapped));
+    /// assert_eq!(FromStr::from_str("cdab"), Ok(Endian::BigWordSwapped));
+    /// assert_eq!(FromStr::from_str("little-word-swapped"), Ok(Endian::LittleWordSwapped));
+    /// assert_eq!(FromStr::from_str("badc"), Ok(Endian::LittleWordSwapped));
     /// assert!(<Endian as FromStr>::from_str("not an endian").is_err());
     /// ```
     #[inline]
@@ -83,6 +120,8 @@ impl FromStr for Endian {
         match s {
             "little" => Ok(Endian::Little),
             "big" => Ok(Endian::Big),
+            "big-word-swapped" | "cdab" => Ok(Endian::BigWordSwapped),
+            "little-word-swapped" | "badc" => Ok(Endian::LittleWordSwapped),
             _ => Err(ParseEndianError {}),
         }
     }
@@ -214,3 +253,43 @@ impl BitSize {
 
 /// Amount of bytes to read_exact
 pub struct ReadExact(pub usize);
+
+/// Encoding used to represent a `char`
+#[derive(Debug, Copy, Clone, Eq, PartialEq, Default)]
+pub enum CharEncoding {
+    /// Unicode scalar value stored as a `u32`, honouring [`Endian`]
+    #[default]
+    Utf32,
+    /// UTF-8 sequence of 1 to 4 bytes
+    Utf8,
+}
+
+/// Unit of time used when a `Duration` or `SystemTime` is represented as a single integer
+///
+/// Writing truncates to a whole number of units, towards the Unix epoch for `SystemTime`, so
+/// 1.5 ms written as [`TimeUnit::Millis`] reads back as 1 ms. Use a finer unit to keep the
+/// precision.
+#[derive(Debug, Copy, Clone, Eq, PartialEq)]
+pub enum TimeUnit {
+    /// Seconds
+    Seconds,
+    /// Milliseconds
+    Millis,
+    /// Microseconds
+    Micros,
+    /// Nanoseconds
+    Nanos,
+}
+
+impl TimeUnit {
+    /// Number of nanoseconds in one unit
+    #[inline]
+    pub const fn as_nanos(self) -> u64 {
+        match self {
+            TimeUnit::Seconds => 1_000_000_000,
+            TimeUnit::Millis => 1_000_000,
+            TimeUnit::Micros => 1_000,
+            TimeUnit::Nanos => 1,
+        }
+    }
+}
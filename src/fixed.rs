@@ -0,0 +1,359 @@
+//! Binary fixed-point numbers
+//!
+//! [`Fixed<I, FRAC_BITS>`](Fixed) stores a value as the integer `I`, scaled by
+//! `2^FRAC_BITS`, and is read and written exactly like `I`, so every ctx of
+//! the inner integer (endian, bit size, ...) applies to it.
+//!
+//! Converting to a float is exact whenever the float's mantissa is wide
+//! enough for `I`, and these conversions are available through [`From`].
+//! Converting from a float with [`TryFrom`] only succeeds when the value is
+//! exactly representable, [`Fixed::from_f64_rounded`] rounds to the nearest
+//! value instead.
+//!
+//! ```rust
+//! use deku::prelude::*;
+//! use deku::fixed::{Q15, Q16_16};
+//!
+//! # #[cfg(feature = "alloc")]
+//! #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+//! #[deku(endian = "big")]
+//! struct Reading {
+//!     gain: Q15,
+//!     offset: Q16_16,
+//! }
+//!
+//! # #[cfg(feature = "alloc")]
+//! # fn main() {
+//! let data: &[u8] = &[0x40, 0x00, 0xFF, 0xFE, 0x80, 0x00];
+//! let (_rest, value) = Reading::from_bytes((data, 0)).unwrap();
+//! assert_eq!(0.5, f32::from(value.gain));
+//! assert_eq!(-1.5, f64::from(value.offset));
+//! assert_eq!(Ok(value.offset), Q16_16::try_from(-1.5));
+//! assert_eq!(data, value.to_bytes().unwrap());
+//! # }
+//! #
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+
+use core::fmt;
+
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{deku_error, DekuError, DekuReader, DekuSize, DekuWriter};
+
+mod private {
+    pub trait Sealed {}
+}
+
+/// Integer types that can back a [`Fixed`]
+pub trait FixedInner: Copy + private::Sealed {
+    /// Size of the integer in bits
+    const BITS: u32;
+
+    #[doc(hidden)]
+    fn to_i128(self) -> i128;
+
+    #[doc(hidden)]
+    fn from_i128(value: i128) -> Option<Self>;
+}
+
+macro_rules! ImplFixedInner {
+    ($($typ:ty),*) => {
+        $(
+            impl private::Sealed for $typ {}
+
+            impl FixedInner for $typ {
+                const BITS: u32 = <$typ>::BITS;
+
+                #[inline]
+                fn to_i128(self) -> i128 {
+                    self as i128
+                }
+
+                #[inline]
+                fn from_i128(value: i128) -> Option<Self> {
+                    <$typ>::try_from(value).ok()
+                }
+            }
+        )*
+    };
+}
+
+ImplFixedInner!(i8, i16, i32, i64, u8, u16, u32, u64);
+
+/// Error returned when a float cannot be converted to a [`Fixed`] exactly
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TryFromFloatError(());
+
+impl fmt::Display for TryFromFloatError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("float is not exactly representable as fixed-point")
+    }
+}
+
+impl core::error::Error for TryFromFloatError {}
+
+impl From<TryFromFloatError> for DekuError {
+    fn from(e: TryFromFloatError) -> DekuError {
+        deku_error!(DekuError::Parse, "error parsing fixed-point", "{}", e)
+    }
+}
+
+/// Fixed-point number stored in `I` with `FRAC_BITS` fractional bits
+///
+/// The represented value is `bits / 2^FRAC_BITS`. `FRAC_BITS` may be at most
+/// the width of `I`.
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Fixed<I, const FRAC_BITS: u32>(I);
+
+/// Signed Q15, 1 sign bit and 15 fractional bits
+pub type Q15 = Fixed<i16, 15>;
+
+/// Signed Q31, 1 sign bit and 31 fractional bits
+pub type Q31 = Fixed<i32, 31>;
+
+/// Signed Q7.8, 8 integer bits and 8 fractional bits
+pub type Q7_8 = Fixed<i16, 8>;
+
+/// Signed Q16.16, 16 integer bits and 16 fractional bits
+pub type Q16_16 = Fixed<i32, 16>;
+
+impl<I: FixedInner, const FRAC_BITS: u32> Fixed<I, FRAC_BITS> {
+    /// Number of fractional bits
+    pub const FRAC_BITS: u32 = FRAC_BITS;
+
+    const SCALE: f64 = {
+        assert!(
+            FRAC_BITS <= I::BITS,
+            "FRAC_BITS is larger than the inner type"
+        );
+        (1u128 << FRAC_BITS) as f64
+    };
+
+    /// Create a value from its raw, scaled representation
+    #[inline]
+    pub const fn from_bits(bits: I) -> Self {
+        Self(bits)
+    }
+
+    /// Return the raw, scaled representation
+    #[inline]
+    pub const fn to_bits(self) -> I {
+        self.0
+    }
+
+    /// Convert to `f64`
+    ///
+    /// Exact for inner types of up to 32 bits, 64-bit inner types round to
+    /// the nearest `f64`.
+    #[inline]
+    pub fn to_f64(self) -> f64 {
+        self.0.to_i128() as f64 / Self::SCALE
+    }
+
+    /// Convert from `f64` if `value` is exactly representable
+    pub fn from_f64(value: f64) -> Option<Self> {
+        let scaled = value * Self::SCALE;
+        // NaN becomes 0 and infinities saturate, neither compares equal afterwards
+        let bits = scaled as i128;
+        if bits as f64 != scaled {
+            return None;
+        }
+        I::from_i128(bits).map(Self)
+    }
+
+    /// Convert from `f64`, rounding to the nearest value with ties away from zero
+    ///
+    /// Returns `None` if `value` is NaN or the rounded value is out of range.
+    pub fn from_f64_rounded(value: f64) -> Option<Self> {
+        let scaled = value * Self::SCALE;
+        if scaled.is_nan() {
+            return None;
+        }
+        // the cast truncates towards zero, fractions only remain below 2^53,
+        // where the difference is exact
+        let mut bits = scaled as i128;
+        let frac = scaled - bits as f64;
+        if frac >= 0.5 {
+            bits += 1;
+        } else if frac <= -0.5 {
+            bits -= 1;
+        }
+        I::from_i128(bits).map(Self)
+    }
+}
+
+impl<I: FixedInner, const FRAC_BITS: u32> fmt::Display for Fixed<I, FRAC_BITS> {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        fmt::Display::fmt(&self.to_f64(), f)
+    }
+}
+
+impl<I: FixedInner, const FRAC_BITS: u32> TryFrom<f64> for Fixed<I, FRAC_BITS> {
+    type Error = TryFromFloatError;
+
+    #[inline]
+    fn try_from(value: f64) -> Result<Self, Self::Error> {
+        Self::from_f64(value).ok_or(TryFromFloatError(()))
+    }
+}
+
+impl<I: FixedInner, const FRAC_BITS: u32> TryFrom<f32> for Fixed<I, FRAC_BITS> {
+    type Error = TryFromFloatError;
+
+    #[inline]
+    fn try_from(value: f32) -> Result<Self, Self::Error> {
+        Self::from_f64(value.into()).ok_or(TryFromFloatError(()))
+    }
+}
+
+/// Exact conversions into floats with a wide enough mantissa
+macro_rules! ImplFixedInto {
+    ($float:ty; $($typ:ty),*) => {
+        $(
+            impl<const FRAC_BITS: u32> From<Fixed<$typ, FRAC_BITS>> for $float {
+                #[inline]
+                fn from(value: Fixed<$typ, FRAC_BITS>) -> $float {
+                    value.to_f64() as $float
+                }
+            }
+        )*
+    };
+}
+
+ImplFixedInto!(f32; i8, i16, u8, u16);
+ImplFixedInto!(f64; i8, i16, i32, u8, u16, u32);
+
+impl<I: DekuSize, const FRAC_BITS: u32> DekuSize for Fixed<I, FRAC_BITS> {
+    const SIZE_BITS: usize = I::SIZE_BITS;
+}
+
+impl<I: crate::DekuWriterNoSeek, const FRAC_BITS: u32> crate::DekuWriterNoSeek
+    for Fixed<I, FRAC_BITS>
+{
+}
+
+impl<'a, I, const FRAC_BITS: u32, Ctx> DekuReader<'a, Ctx> for Fixed<I, FRAC_BITS>
+where
+    I: DekuReader<'a, Ctx>,
+{
+    #[inline]
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        ctx: Ctx,
+    ) -> Result<Self, DekuError> {
+        I::from_reader_with_ctx(reader, ctx).map(Self)
+    }
+}
+
+impl<I, const FRAC_BITS: u32, Ctx> DekuWriter<Ctx> for Fixed<I, FRAC_BITS>
+where
+    I: DekuWriter<Ctx>,
+{
+    #[inline]
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.0.to_writer(writer, ctx)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use no_std_io::io::Cursor;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ctx::Endian;
+
+    #[test]
+    fn test_conversions() {
+        assert_eq!(0.5, f32::from(Q15::from_bits(0x4000)));
+        assert_eq!(-1.0, f32::from(Q15::from_bits(i16::MIN)));
+        assert_eq!(-1.5, f64::from(Q16_16::from_bits(-0x1_8000)));
+        assert_eq!(255.0, f64::from(Fixed::<u8, 0>::from_bits(255)));
+        assert_eq!(0.99609375, f64::from(Fixed::<u8, 8>::from_bits(255)));
+
+        assert_eq!(Ok(Q15::from_bits(-0x2000)), Q15::try_from(-0.25));
+        assert_eq!(Ok(Q7_8::from_bits(0x0180)), Q7_8::try_from(1.5f32));
+        assert_eq!(Err(TryFromFloatError(())), Q15::try_from(1.0));
+        assert_eq!(Err(TryFromFloatError(())), Q15::try_from(0.1));
+        assert_eq!(Err(TryFromFloatError(())), Q15::try_from(f64::NAN));
+        assert_eq!(Err(TryFromFloatError(())), Q16_16::try_from(f64::INFINITY));
+        assert_eq!(None, Fixed::<u16, 4>::from_f64(-1.0));
+
+        assert_eq!(Some(Q15::from_bits(0x0CCD)), Q15::from_f64_rounded(0.1));
+        assert_eq!(Some(Q15::from_bits(-0x0CCD)), Q15::from_f64_rounded(-0.1));
+        assert_eq!(
+            Some(Fixed::<i8, 1>::from_bits(1)),
+            Fixed::<i8, 1>::from_f64_rounded(0.25)
+        );
+        assert_eq!(
+            Some(Fixed::<i8, 1>::from_bits(-1)),
+            Fixed::<i8, 1>::from_f64_rounded(-0.25)
+        );
+        assert_eq!(None, Q15::from_f64_rounded(1.0));
+        assert_eq!(None, Q15::from_f64_rounded(f64::NAN));
+        assert_eq!(
+            Some(Fixed::<i64, 0>::from_bits(i64::MIN)),
+            Fixed::<i64, 0>::from_f64_rounded(i64::MIN as f64)
+        );
+    }
+
+    #[test]
+    fn test_round_trip_all() {
+        for bits in i16::MIN..=i16::MAX {
+            let value = Q15::from_bits(bits);
+            assert_eq!(Ok(value), Q15::try_from(f32::from(value)));
+            assert_eq!(Some(value), Q15::from_f64_rounded(value.to_f64()));
+
+            let value = Q7_8::from_bits(bits);
+            assert_eq!(Ok(value), Q7_8::try_from(f64::from(value)));
+        }
+        for bits in [i32::MIN, -0x1_8000, -1, 0, 1, 0x1_8000, i32::MAX] {
+            let value = Q16_16::from_bits(bits);
+            assert_eq!(Ok(value), Q16_16::try_from(f64::from(value)));
+            let value = Q31::from_bits(bits);
+            assert_eq!(Ok(value), Q31::try_from(f64::from(value)));
+        }
+    }
+
+    #[rstest(input, endian, expected,
+        case::le(&[0x00, 0x40], Endian::Little, Q15::from_bits(0x4000)),
+        case::be(&[0xC0, 0x00], Endian::Big, Q15::from_bits(-0x4000)),
+    )]
+    fn test_q15(input: &[u8], endian: Endian, expected: Q15) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = Q15::from_reader_with_ctx(&mut reader, endian).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn test_bit_size() {
+        use crate::ctx::BitSize;
+
+        // Q3.4 packed into 8 bits: 0b1110_1000 = -1.5
+        let input = [0b1110_1000u8, 0b0100_0000];
+        let mut cursor = Cursor::new(&input);
+        let mut reader = Reader::new(&mut cursor);
+        let a =
+            Fixed::<i8, 4>::from_reader_with_ctx(&mut reader, (Endian::Big, BitSize(8))).unwrap();
+        let b =
+            Fixed::<i8, 1>::from_reader_with_ctx(&mut reader, (Endian::Big, BitSize(3))).unwrap();
+        assert_eq!(-1.5, f32::from(a));
+        assert_eq!(1.0, f32::from(b));
+    }
+}
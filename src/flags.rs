@@ -0,0 +1,321 @@
+//! Integration with the [bitflags](https://crates.io/crates/bitflags) crate
+//!
+//! Types declared with `bitflags!` cannot derive [`DekuRead`](crate::DekuRead),
+//! [`impl_bitflags!`](crate::impl_bitflags) implements [`DekuReader`](crate::DekuReader),
+//! [`DekuWriter`](crate::DekuWriter) and [`DekuSize`](crate::DekuSize) for them instead.
+//! The flags are read and written as their underlying integer, so `bits`, `bytes`,
+//! `endian` and `bit_order` apply exactly as they would for that integer.
+//!
+//! Bits which are not part of a declared flag are handled according to [`Unknown`]:
+//! - `reject` (default): reading or writing them is an error
+//! - `retain`: they are kept as is and written back unchanged
+//! - `truncate`: they are dropped when reading and writing
+//!
+//! ```rust
+//! use bitflags::bitflags;
+//! use deku::prelude::*;
+//!
+//! bitflags! {
+//!     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//!     pub struct Permissions: u8 {
+//!         const READ = 0b100;
+//!         const WRITE = 0b010;
+//!         const EXEC = 0b001;
+//!     }
+//! }
+//! deku::impl_bitflags!(Permissions);
+//!
+//! bitflags! {
+//!     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+//!     pub struct Status: u16 {
+//!         const READY = 0x0001;
+//!         const ERROR = 0x8000;
+//!     }
+//! }
+//! deku::impl_bitflags!(Status, unknown = retain);
+//!
+//! # #[cfg(all(feature = "alloc", feature = "bits"))]
+//! #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+//! #[deku(endian = "big")]
+//! struct Header {
+//!     #[deku(bits = 3)]
+//!     owner: Permissions,
+//!     #[deku(bits = 3)]
+//!     group: Permissions,
+//!     #[deku(bits = 2)]
+//!     reserved: u8,
+//!     status: Status,
+//! }
+//!
+//! # #[cfg(all(feature = "alloc", feature = "bits"))]
+//! # fn main() {
+//! let data: &[u8] = &[0b110_100_00, 0x80, 0x11];
+//! let (_rest, header) = Header::from_bytes((data, 0)).unwrap();
+//! assert_eq!(Permissions::READ | Permissions::WRITE, header.owner);
+//! assert_eq!(Permissions::READ, header.group);
+//! assert_eq!(Status::ERROR | Status::READY, header.status & Status::all());
+//! assert_eq!(0x8011, header.status.bits());
+//! assert_eq!(data, header.to_bytes().unwrap());
+//! # }
+//! #
+//! # #[cfg(not(all(feature = "alloc", feature = "bits")))]
+//! # fn main() {}
+//! ```
+
+use core::fmt;
+
+use bitflags::Bits;
+
+#[doc(hidden)]
+pub use bitflags::Flags;
+
+use crate::{deku_error, DekuError};
+
+/// Handling of bits that are not part of any declared flag
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash, Default)]
+pub enum Unknown {
+    /// Fail with [`DekuError::Parse`] on read and [`DekuError::InvalidParam`] on write
+    #[default]
+    Reject,
+    /// Keep unknown bits, they are written back unchanged
+    Retain,
+    /// Drop unknown bits when reading and writing
+    Truncate,
+}
+
+/// Bits of `bits` which are not part of a declared flag of `F`
+fn unknown_bits<F: Flags>(bits: F::Bits) -> F::Bits {
+    bits & !F::all().bits()
+}
+
+/// Convert raw bits read from the input into `F`
+#[doc(hidden)]
+pub fn from_bits<F>(bits: F::Bits, unknown: Unknown) -> Result<F, DekuError>
+where
+    F: Flags,
+    F::Bits: fmt::LowerHex,
+{
+    match unknown {
+        Unknown::Reject => F::from_bits(bits).ok_or_else(|| {
+            deku_error!(
+                DekuError::Parse,
+                "unknown flag bits",
+                "{:#x}",
+                unknown_bits::<F>(bits)
+            )
+        }),
+        Unknown::Retain => Ok(F::from_bits_retain(bits)),
+        Unknown::Truncate => Ok(F::from_bits_truncate(bits)),
+    }
+}
+
+/// Raw bits of `flags` to write to the output
+#[doc(hidden)]
+pub fn to_bits<F>(flags: &F, unknown: Unknown) -> Result<F::Bits, DekuError>
+where
+    F: Flags,
+    F::Bits: fmt::LowerHex,
+{
+    let bits = flags.bits();
+    match unknown {
+        Unknown::Reject if unknown_bits::<F>(bits) != F::Bits::EMPTY => Err(deku_error!(
+            DekuError::InvalidParam,
+            "unknown flag bits",
+            "{:#x}",
+            unknown_bits::<F>(bits)
+        )),
+        Unknown::Truncate => Ok(bits & F::all().bits()),
+        _ => Ok(bits),
+    }
+}
+
+/// Implement [`DekuReader`](crate::DekuReader), [`DekuWriter`](crate::DekuWriter) and
+/// [`DekuSize`](crate::DekuSize) for a type declared with `bitflags!`
+///
+/// The ctx is forwarded to the underlying integer. An optional `unknown = reject | retain | truncate`
+/// selects the handling of undeclared bits, see [`Unknown`](crate::flags::Unknown).
+///
+/// ```rust
+/// use bitflags::bitflags;
+/// use deku::prelude::*;
+///
+/// bitflags! {
+///     #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+///     pub struct Flags: u8 {
+///         const A = 0b01;
+///         const B = 0b10;
+///     }
+/// }
+/// deku::impl_bitflags!(Flags);
+///
+/// let mut cursor = deku::no_std_io::Cursor::new([0b11, 0b111]);
+/// let mut reader = Reader::new(&mut cursor);
+/// assert_eq!(Flags::A | Flags::B, Flags::from_reader_with_ctx(&mut reader, ()).unwrap());
+/// assert!(Flags::from_reader_with_ctx(&mut reader, ()).is_err());
+/// ```
+#[macro_export]
+macro_rules! impl_bitflags {
+    ($typ:ty) => {
+        $crate::impl_bitflags!($typ, unknown = reject);
+    };
+    ($typ:ty, unknown = reject) => {
+        $crate::impl_bitflags!(@impl $typ, $crate::flags::Unknown::Reject);
+    };
+    ($typ:ty, unknown = retain) => {
+        $crate::impl_bitflags!(@impl $typ, $crate::flags::Unknown::Retain);
+    };
+    ($typ:ty, unknown = truncate) => {
+        $crate::impl_bitflags!(@impl $typ, $crate::flags::Unknown::Truncate);
+    };
+    (@impl $typ:ty, $unknown:expr) => {
+        impl<'a, Ctx> $crate::DekuReader<'a, Ctx> for $typ
+        where
+            <$typ as $crate::flags::Flags>::Bits: $crate::DekuReader<'a, Ctx>,
+        {
+            fn from_reader_with_ctx<R: $crate::no_std_io::Read + $crate::no_std_io::Seek>(
+                reader: &mut $crate::reader::Reader<R>,
+                ctx: Ctx,
+            ) -> ::core::result::Result<Self, $crate::DekuError> {
+                let bits = <<$typ as $crate::flags::Flags>::Bits as $crate::DekuReader<'a, Ctx>>::from_reader_with_ctx(reader, ctx)?;
+                $crate::flags::from_bits(bits, $unknown)
+            }
+        }
+
+        impl<Ctx> $crate::DekuWriter<Ctx> for $typ
+        where
+            <$typ as $crate::flags::Flags>::Bits: $crate::DekuWriter<Ctx>,
+        {
+            fn to_writer<W: $crate::no_std_io::Write + $crate::no_std_io::Seek>(
+                &self,
+                writer: &mut $crate::writer::Writer<W>,
+                ctx: Ctx,
+            ) -> ::core::result::Result<(), $crate::DekuError> {
+                let bits = $crate::flags::to_bits(self, $unknown)?;
+                $crate::DekuWriter::to_writer(&bits, writer, ctx)
+            }
+        }
+
+        impl $crate::DekuSize for $typ {
+            const SIZE_BITS: usize =
+                <<$typ as $crate::flags::Flags>::Bits as $crate::DekuSize>::SIZE_BITS;
+        }
+
+        impl $crate::DekuWriterNoSeek for $typ {}
+    };
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use bitflags::bitflags;
+    use no_std_io::io::Cursor;
+    use rstest::rstest;
+
+    use crate::ctx::{BitSize, Endian};
+    use crate::reader::Reader;
+    use crate::writer::Writer;
+    use crate::{DekuReader, DekuWriter};
+
+    use super::*;
+
+    bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Strict: u16 {
+            const A = 0x0001;
+            const B = 0x0100;
+        }
+    }
+    crate::impl_bitflags!(Strict);
+
+    bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Retained: u16 {
+            const A = 0x0001;
+            const B = 0x0100;
+        }
+    }
+    crate::impl_bitflags!(Retained, unknown = retain);
+
+    bitflags! {
+        #[derive(Debug, Clone, Copy, PartialEq, Eq)]
+        struct Truncated: u16 {
+            const A = 0x0001;
+            const B = 0x0100;
+        }
+    }
+    crate::impl_bitflags!(Truncated, unknown = truncate);
+
+    fn read<'a, T: DekuReader<'a, Endian>>(input: &[u8]) -> Result<T, DekuError> {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        T::from_reader_with_ctx(&mut reader, Endian::Big)
+    }
+
+    fn write<T: DekuWriter<Endian>>(value: &T) -> Result<Vec<u8>, DekuError> {
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        value.to_writer(&mut writer, Endian::Big)?;
+        Ok(writer.inner.into_inner())
+    }
+
+    #[rstest(input, expected,
+        case(&[0x01, 0x01], Ok(Strict::A | Strict::B)),
+        case(&[0x00, 0x00], Ok(Strict::empty())),
+        case(&[0x80, 0x01], Err(DekuError::Parse("unknown flag bits: 0x8000".into()))),
+    )]
+    fn test_strict(input: &[u8], expected: Result<Strict, DekuError>) {
+        let res = read::<Strict>(input);
+        #[cfg(feature = "descriptive-errors")]
+        assert_eq!(expected, res);
+        #[cfg(not(feature = "descriptive-errors"))]
+        assert_eq!(expected.is_ok(), res.is_ok());
+
+        if let Ok(value) = res {
+            assert_eq!(input.to_vec(), write(&value).unwrap());
+        }
+    }
+
+    #[test]
+    fn test_strict_write_unknown() {
+        let value = Strict::from_bits_retain(0x8001);
+        assert!(matches!(write(&value), Err(DekuError::InvalidParam(_))));
+    }
+
+    #[test]
+    fn test_retain() {
+        let value = read::<Retained>(&[0x80, 0x01]).unwrap();
+        assert_eq!(0x8001, value.bits());
+        assert!(value.contains(Retained::A));
+        assert_eq!(vec![0x80, 0x01], write(&value).unwrap());
+    }
+
+    #[test]
+    fn test_truncate() {
+        let value = read::<Truncated>(&[0x80, 0x01]).unwrap();
+        assert_eq!(Truncated::A, value);
+        assert_eq!(vec![0x00, 0x01], write(&value).unwrap());
+        assert_eq!(
+            vec![0x00, 0x01],
+            write(&Truncated::from_bits_retain(0x8001)).unwrap()
+        );
+    }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn test_bit_size() {
+        let value = Strict::A | Strict::B;
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        value
+            .to_writer(&mut writer, (Endian::Little, BitSize(9)))
+            .unwrap();
+        writer.finalize().unwrap();
+        let out = writer.inner.into_inner();
+        assert_eq!(vec![0b0000_0001, 0b1000_0000], out);
+
+        let mut cursor = Cursor::new(&out);
+        let mut reader = Reader::new(&mut cursor);
+        let res = Strict::from_reader_with_ctx(&mut reader, (Endian::Little, BitSize(9))).unwrap();
+        assert_eq!(value, res);
+        assert_eq!(9, reader.bits_read);
+
+        assert_eq!(16, <Strict as crate::DekuSize>::SIZE_BITS);
+    }
+}
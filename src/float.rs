@@ -0,0 +1,356 @@
+//! Half-precision floating point types
+//!
+//! [`F16`] is IEEE 754 binary16 and [`BF16`] is bfloat16, the upper half of
+//! an [`f32`]. Both are stored as their raw `u16` bit pattern and are read and
+//! written exactly like a `u16`, so every `u16` ctx (endian, bit size, ...)
+//! applies to them.
+//!
+//! Widening to [`f32`] or [`f64`] is exact. Narrowing rounds to the nearest
+//! representable value, ties to even, so `F16 -> f32 -> F16` always returns
+//! the original bit pattern, NaN payloads included.
+//!
+//! ```rust
+//! use deku::prelude::*;
+//! use deku::float::{BF16, F16};
+//!
+//! # #[cfg(feature = "alloc")]
+//! #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+//! #[deku(endian = "little")]
+//! struct Sample {
+//!     temperature: F16,
+//!     weight: BF16,
+//! }
+//!
+//! # #[cfg(feature = "alloc")]
+//! # fn main() {
+//! let data: &[u8] = &[0x00, 0x3C, 0x49, 0x40];
+//! let (_rest, value) = Sample::from_bytes((data, 0)).unwrap();
+//! assert_eq!(1.0, f32::from(value.temperature));
+//! assert_eq!(3.140625, f32::from(value.weight));
+//! assert_eq!(F16::from_f32(1.0), value.temperature);
+//! assert_eq!(data, value.to_bytes().unwrap());
+//! # }
+//! #
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+
+use core::fmt;
+
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{DekuError, DekuReader, DekuSize, DekuWriter};
+
+/// Round an IEEE 754 binary float to a narrower binary format, ties to even
+///
+/// `bits` holds a value with `src_exp` exponent and `src_man` mantissa bits,
+/// the result has `dst_exp` exponent and `dst_man` mantissa bits.
+const fn narrow(bits: u64, src_exp: u32, src_man: u32, dst_exp: u32, dst_man: u32) -> u64 {
+    let src_exp_max = (1 << src_exp) - 1;
+    let dst_exp_max = (1 << dst_exp) - 1;
+    let src_bias = (src_exp_max >> 1) as i64;
+    let dst_bias = (dst_exp_max >> 1) as i64;
+    let drop = src_man - dst_man;
+
+    let sign = ((bits >> (src_exp + src_man)) & 1) << (dst_exp + dst_man);
+    let exp = (bits >> src_man) & src_exp_max;
+    let man = bits & ((1 << src_man) - 1);
+
+    if exp == src_exp_max {
+        // infinity or NaN, keep as much of the payload as fits and make sure
+        // a NaN does not become infinity
+        let payload = man >> drop;
+        let payload = if man != 0 && payload == 0 {
+            1 << (dst_man - 1)
+        } else {
+            payload
+        };
+        return sign | (dst_exp_max << dst_man) | payload;
+    }
+
+    // value is `sig * 2^(exp - bias - src_man)`
+    let (sig, exp) = if exp == 0 {
+        (man, 1 - src_bias)
+    } else {
+        (man | (1 << src_man), exp as i64 - src_bias)
+    };
+    let dst_biased = exp + dst_bias;
+    if dst_biased >= dst_exp_max as i64 {
+        return sign | (dst_exp_max << dst_man);
+    }
+
+    // subnormal results lose one more bit for every step below the minimum exponent
+    let shift = if dst_biased >= 1 {
+        drop as i64
+    } else {
+        drop as i64 + 1 - dst_biased
+    };
+    if shift > src_man as i64 + 1 {
+        return sign;
+    }
+    let shift = shift as u32;
+
+    let half = 1 << (shift - 1);
+    let rem = sig & ((1 << shift) - 1);
+    let mut rounded = sig >> shift;
+    if rem > half || (rem == half && rounded & 1 == 1) {
+        rounded += 1;
+    }
+
+    // the implicit bit of `rounded` carries into the exponent, which also
+    // takes care of rounding up into the next binade or to infinity
+    let base = if dst_biased >= 1 { dst_biased - 1 } else { 0 };
+    sign | (((base as u64) << dst_man) + rounded)
+}
+
+macro_rules! ImplFloat16 {
+    ($name:ident, $exp:expr, $man:expr, $doc:expr) => {
+        #[doc = $doc]
+        ///
+        /// Equality and hashing compare the bit pattern, so `NaN == NaN` and `0.0 != -0.0`.
+        #[derive(Copy, Clone, Default, PartialEq, Eq, Hash)]
+        pub struct $name(u16);
+
+        impl $name {
+            /// Positive zero
+            pub const ZERO: Self = Self(0);
+
+            /// One
+            pub const ONE: Self = Self((((1 << ($exp - 1)) - 1) as u16) << $man);
+
+            /// Positive infinity
+            pub const INFINITY: Self = Self((((1 << $exp) - 1) as u16) << $man);
+
+            /// Negative infinity
+            pub const NEG_INFINITY: Self = Self(Self::INFINITY.0 | 0x8000);
+
+            /// Quiet NaN
+            pub const NAN: Self = Self(Self::INFINITY.0 | (1 << ($man - 1)));
+
+            /// Create a value from its raw bit pattern
+            #[inline]
+            pub const fn from_bits(bits: u16) -> Self {
+                Self(bits)
+            }
+
+            /// Return the raw bit pattern
+            #[inline]
+            pub const fn to_bits(self) -> u16 {
+                self.0
+            }
+
+            /// Round `value` to the nearest representable value, ties to even
+            #[inline]
+            pub const fn from_f32(value: f32) -> Self {
+                Self(narrow(value.to_bits() as u64, 8, 23, $exp, $man) as u16)
+            }
+
+            /// Round `value` to the nearest representable value, ties to even
+            #[inline]
+            pub const fn from_f64(value: f64) -> Self {
+                Self(narrow(value.to_bits(), 11, 52, $exp, $man) as u16)
+            }
+
+            /// Returns `true` if this value is NaN
+            #[inline]
+            pub const fn is_nan(self) -> bool {
+                self.0 & 0x7FFF > Self::INFINITY.0
+            }
+        }
+
+        impl From<$name> for f64 {
+            #[inline]
+            fn from(value: $name) -> f64 {
+                let value = f32::from(value);
+                if value.is_nan() {
+                    // the float conversion may quiet a signalling NaN, keep the payload
+                    let bits = value.to_bits() as u64;
+                    let sign = (bits & 0x8000_0000) << 32;
+                    let man = (bits & 0x7F_FFFF) << 29;
+                    return f64::from_bits(sign | 0x7FF0_0000_0000_0000 | man);
+                }
+                f64::from(value)
+            }
+        }
+
+        impl fmt::Debug for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Debug::fmt(&f32::from(*self), f)
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&f32::from(*self), f)
+            }
+        }
+
+        impl DekuSize for $name {
+            const SIZE_BITS: usize = 16;
+        }
+
+        impl crate::DekuWriterNoSeek for $name {}
+
+        impl<'a, Ctx> DekuReader<'a, Ctx> for $name
+        where
+            u16: DekuReader<'a, Ctx>,
+        {
+            #[inline]
+            fn from_reader_with_ctx<R: Read + Seek>(
+                reader: &mut Reader<R>,
+                ctx: Ctx,
+            ) -> Result<Self, DekuError> {
+                u16::from_reader_with_ctx(reader, ctx).map(Self)
+            }
+        }
+
+        impl<Ctx> DekuWriter<Ctx> for $name
+        where
+            u16: DekuWriter<Ctx>,
+        {
+            #[inline]
+            fn to_writer<W: Write + Seek>(
+                &self,
+                writer: &mut Writer<W>,
+                ctx: Ctx,
+            ) -> Result<(), DekuError> {
+                self.0.to_writer(writer, ctx)
+            }
+        }
+    };
+}
+
+ImplFloat16!(F16, 5, 10, "IEEE 754 half precision (binary16) float");
+ImplFloat16!(BF16, 8, 7, "bfloat16 float, the upper 16 bits of an `f32`");
+
+impl From<F16> for f32 {
+    #[inline]
+    fn from(value: F16) -> f32 {
+        let bits = value.0 as u32;
+        let sign = (bits & 0x8000) << 16;
+        let exp = (bits >> 10) & 0x1F;
+        let man = bits & 0x3FF;
+
+        let bits = match exp {
+            0 if man == 0 => sign,
+            // subnormal, `man * 2^-24` is exact in f32
+            0 => (man as f32 / (1 << 24) as f32).to_bits() | sign,
+            0x1F => sign | 0x7F80_0000 | (man << 13),
+            _ => sign | ((exp + 127 - 15) << 23) | (man << 13),
+        };
+        f32::from_bits(bits)
+    }
+}
+
+impl From<BF16> for f32 {
+    #[inline]
+    fn from(value: BF16) -> f32 {
+        f32::from_bits((value.0 as u32) << 16)
+    }
+}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use no_std_io::io::Cursor;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ctx::Endian;
+
+    #[rstest(value, expected,
+        case::one(1.0, 0x3C00),
+        case::neg_two(-2.0, 0xC000),
+        case::tenth(0.1, 0x2E66),
+        case::max(65504.0, 0x7BFF),
+        case::round_down_to_max(65519.0, 0x7BFF),
+        case::round_up_to_inf(65520.0, 0x7C00),
+        case::min_subnormal(f32::from_bits(0x3380_0000), 0x0001),
+        case::half_min_subnormal_ties_to_zero(f32::from_bits(0x3300_0000), 0x0000),
+        case::above_half_min_subnormal(f32::from_bits(0x3300_0001), 0x0001),
+        case::neg_zero(-0.0, 0x8000),
+        case::inf(f32::INFINITY, 0x7C00),
+        case::neg_inf(f32::NEG_INFINITY, 0xFC00),
+        case::nan(f32::NAN, 0x7E00),
+        case::ties_to_even(f32::from_bits(0x3F80_1000), 0x3C00),
+        case::ties_to_even_up(f32::from_bits(0x3F80_3000), 0x3C02),
+    )]
+    fn test_f16_from_f32(value: f32, expected: u16) {
+        assert_eq!(expected, F16::from_f32(value).to_bits());
+        assert_eq!(expected, F16::from_f64(value as f64).to_bits());
+    }
+
+    #[rstest(
+        value,
+        expected,
+        case::one(1.0, 0x3F80),
+        case::pi(core::f32::consts::PI, 0x4049),
+        case::ties_to_even(f32::from_bits(0x3F80_8000), 0x3F80),
+        case::ties_to_even_up(f32::from_bits(0x3F81_8000), 0x3F82),
+        case::max(f32::MAX, 0x7F80),
+        case::subnormal(f32::from_bits(0x0000_8000), 0x0000),
+        case::subnormal_up(f32::from_bits(0x0001_8000), 0x0002),
+        case::nan_payload(f32::from_bits(0x7F80_0001), 0x7FC0)
+    )]
+    fn test_bf16_from_f32(value: f32, expected: u16) {
+        assert_eq!(expected, BF16::from_f32(value).to_bits());
+    }
+
+    #[test]
+    fn test_f64_no_double_rounding() {
+        // just above the halfway point between 1.0 and the next F16, but
+        // rounds to exactly halfway as an f32
+        let value = f64::from_bits(0x3FF0_0200_0000_1000);
+        assert_eq!(0x3C00, F16::from_f32(value as f32).to_bits());
+        assert_eq!(0x3C01, F16::from_f64(value).to_bits());
+
+        let value = f64::from_bits(0x3FF0_1000_0000_1000);
+        assert_eq!(0x3F80, BF16::from_f32(value as f32).to_bits());
+        assert_eq!(0x3F81, BF16::from_f64(value).to_bits());
+    }
+
+    #[test]
+    fn test_round_trip_all() {
+        for bits in 0..=u16::MAX {
+            let f16 = F16::from_bits(bits);
+            assert_eq!(f16, F16::from_f32(f32::from(f16)));
+            assert_eq!(f16, F16::from_f64(f64::from(f16)));
+            assert_eq!(f16.is_nan(), f32::from(f16).is_nan());
+
+            let bf16 = BF16::from_bits(bits);
+            assert_eq!(bf16, BF16::from_f32(f32::from(bf16)));
+            assert_eq!(bf16, BF16::from_f64(f64::from(bf16)));
+            assert_eq!(bf16.is_nan(), f32::from(bf16).is_nan());
+        }
+    }
+
+    #[test]
+    fn test_consts() {
+        assert_eq!(1.0, f32::from(F16::ONE));
+        assert_eq!(1.0, f32::from(BF16::ONE));
+        assert_eq!(f32::INFINITY, f32::from(F16::INFINITY));
+        assert_eq!(f32::NEG_INFINITY, f32::from(BF16::NEG_INFINITY));
+        assert!(F16::NAN.is_nan());
+        assert!(BF16::NAN.is_nan());
+        assert!(!F16::INFINITY.is_nan());
+        assert_eq!(16, F16::SIZE_BITS);
+    }
+
+    #[rstest(input, endian, expected,
+        case::le(&[0x00, 0x3C], Endian::Little, F16::ONE),
+        case::be(&[0xC0, 0x00], Endian::Big, F16::from_f32(-2.0)),
+    )]
+    fn test_f16(input: &[u8], endian: Endian, expected: F16) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = F16::from_reader_with_ctx(&mut reader, endian).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+}
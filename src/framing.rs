@@ -0,0 +1,689 @@
+//! Byte stuffing adapters for framed streams
+//!
+//! Serial links commonly delimit frames with a reserved byte, which then has to be
+//! escaped inside the payload. The adapters in this module do this transparently while
+//! streaming, so a deku type can be read from or written to a framed stream directly with
+//! [`Reader`](crate::reader::Reader) and [`Writer`](crate::writer::Writer). No buffering of
+//! the whole frame is needed, they work without `alloc`.
+//!
+//! - [`CobsReader`]/[`CobsWriter`]: [Consistent Overhead Byte Stuffing], frames end with `0x00`
+//! - [`EscapeReader`]/[`EscapeWriter`]: escape sequences as described by [`Escape`], such as
+//!   [SLIP](Escape::SLIP) and [HDLC](Escape::HDLC)
+//!
+//! Readers decode a single frame and report the end of file at its delimiter,
+//! [`next_frame`](CobsReader::next_frame) moves to the following frame. Empty frames are
+//! skipped. Readers pull the inner stream one byte at a time, so that no byte of the next frame
+//! is consumed, wrap it in a buffered reader when this is costly.
+//!
+//! Writers escape the payload as it is written, [`end_frame`](CobsWriter::end_frame) must be
+//! called to terminate each frame.
+//!
+//! The adapters implement a limited [`Seek`], in the same way as [`NoSeek`](crate::noseek::NoSeek).
+//! The position is counted in payload bytes, readers can also seek forwards by discarding bytes.
+//!
+//! ```rust
+//! use deku::framing::{Escape, EscapeReader, EscapeWriter};
+//! use deku::no_std_io::Cursor;
+//! use deku::prelude::*;
+//!
+//! #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+//! #[deku(endian = "big")]
+//! struct Message {
+//!     kind: u8,
+//!     value: u16,
+//! }
+//!
+//! let mut out = [0u8; 16];
+//! let mut cursor = Cursor::new(&mut out[..]);
+//! let mut framed = EscapeWriter::new(&mut cursor, Escape::SLIP);
+//! let mut writer = Writer::new(&mut framed);
+//! Message { kind: 1, value: 0xC0DB }.to_writer(&mut writer, ()).unwrap();
+//! writer.finalize().unwrap();
+//! framed.end_frame().unwrap();
+//!
+//! let len = cursor.position() as usize;
+//! assert_eq!([0x01, 0xDB, 0xDC, 0xDB, 0xDD, 0xC0], out[..len]);
+//!
+//! let mut framed = EscapeReader::new(Cursor::new(&out[..len]), Escape::SLIP);
+//! let mut reader = Reader::new(&mut framed);
+//! let message = Message::from_reader_with_ctx(&mut reader, ()).unwrap();
+//! assert_eq!(Message { kind: 1, value: 0xC0DB }, message);
+//! ```
+//!
+//! [Consistent Overhead Byte Stuffing]: https://en.wikipedia.org/wiki/Consistent_Overhead_Byte_Stuffing
+
+use crate::no_std_io::{Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
+
+/// Largest number of data bytes in a COBS block
+const COBS_BLOCK: usize = 254;
+
+/// Escape sequence framing
+///
+/// Frames end with `delimiter`. In the payload, `delimiter` is replaced by `escape`
+/// followed by `escaped_delimiter`, and `escape` by `escape` followed by `escaped_escape`.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub struct Escape {
+    /// Byte marking the end of a frame
+    pub delimiter: u8,
+    /// Byte starting an escape sequence
+    pub escape: u8,
+    /// Byte following `escape` for an escaped `delimiter`
+    pub escaped_delimiter: u8,
+    /// Byte following `escape` for an escaped `escape`
+    pub escaped_escape: u8,
+    /// Also write `delimiter` at the start of each frame
+    pub start_delimiter: bool,
+}
+
+impl Escape {
+    /// Serial Line Internet Protocol, [RFC 1055](https://www.rfc-editor.org/rfc/rfc1055)
+    pub const SLIP: Self = Self {
+        delimiter: 0xC0,
+        escape: 0xDB,
+        escaped_delimiter: 0xDC,
+        escaped_escape: 0xDD,
+        start_delimiter: false,
+    };
+
+    /// HDLC-like framing, [RFC 1662](https://www.rfc-editor.org/rfc/rfc1662)
+    ///
+    /// Only the flag and control escape bytes are escaped.
+    pub const HDLC: Self = Self {
+        delimiter: 0x7E,
+        escape: 0x7D,
+        escaped_delimiter: 0x5E,
+        escaped_escape: 0x5D,
+        start_delimiter: true,
+    };
+}
+
+/// Read a single byte, `None` at the end of `inner`
+fn read_byte<R: Read>(inner: &mut R) -> Result<Option<u8>> {
+    let mut byte = [0u8; 1];
+    loop {
+        match inner.read(&mut byte) {
+            Ok(0) => return Ok(None),
+            Ok(_) => return Ok(Some(byte[0])),
+            Err(e) if e.kind() == ErrorKind::Interrupted => {}
+            Err(e) => return Err(e),
+        }
+    }
+}
+
+/// Read a byte in the middle of a frame, the end of `inner` is an error
+fn read_frame_byte<R: Read>(inner: &mut R) -> Result<u8> {
+    read_byte(inner)?.ok_or_else(|| Error::new(ErrorKind::UnexpectedEof, "unterminated frame"))
+}
+
+fn unsupported_seek() -> Error {
+    Error::new(ErrorKind::Unsupported, "seek on framed stream")
+}
+
+/// Seek of a framed writer, which cannot move
+fn seek_writer(pos: u64, to: SeekFrom) -> Result<u64> {
+    match to {
+        SeekFrom::Start(n) if n == pos => Ok(n),
+        SeekFrom::Current(0) => Ok(pos),
+        _ => Err(unsupported_seek()),
+    }
+}
+
+/// Seek of a framed reader, forwards by discarding payload bytes
+fn seek_reader<R: Read>(reader: &mut R, pos: u64, to: SeekFrom) -> Result<u64> {
+    let skip = match to {
+        SeekFrom::Start(n) if n >= pos => n - pos,
+        SeekFrom::Current(n) if n >= 0 => n as u64,
+        _ => return Err(unsupported_seek()),
+    };
+
+    let mut buf = [0u8; 32];
+    let mut remaining = skip;
+    while remaining > 0 {
+        let len = core::cmp::min(buf.len() as u64, remaining) as usize;
+        reader.read_exact(&mut buf[..len])?;
+        remaining -= len as u64;
+    }
+    Ok(pos + skip)
+}
+
+/// Decode a COBS frame from `inner`
+pub struct CobsReader<R> {
+    inner: R,
+    /// Data bytes left in the current block
+    remaining: u8,
+    /// The current block is followed by a zero, unless it is the last one
+    zero: bool,
+    started: bool,
+    done: bool,
+    pos: u64,
+}
+
+impl<R: Read> CobsReader<R> {
+    /// Create a reader decoding the frames of `inner`
+    pub fn new(inner: R) -> Self {
+        Self {
+            inner,
+            remaining: 0,
+            zero: false,
+            started: false,
+            done: false,
+            pos: 0,
+        }
+    }
+
+    /// Gets a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Consumes this adapter, returning the underlying reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Discard the rest of the current frame and start reading the next one
+    pub fn next_frame(&mut self) -> Result<()> {
+        while self.decode()?.is_some() {}
+        self.remaining = 0;
+        self.zero = false;
+        self.started = false;
+        self.done = false;
+        self.pos = 0;
+        Ok(())
+    }
+
+    /// Next payload byte, `None` at the end of the frame
+    fn decode(&mut self) -> Result<Option<u8>> {
+        while !self.done {
+            if self.remaining > 0 {
+                let byte = read_frame_byte(&mut self.inner)?;
+                if byte == 0 {
+                    return Err(Error::new(ErrorKind::InvalidData, "truncated COBS block"));
+                }
+                self.remaining -= 1;
+                return Ok(Some(byte));
+            }
+
+            let code = if self.started {
+                read_frame_byte(&mut self.inner)?
+            } else {
+                match read_byte(&mut self.inner)? {
+                    Some(0) => continue,
+                    Some(code) => code,
+                    None => return Ok(None),
+                }
+            };
+            self.started = true;
+            if code == 0 {
+                self.done = true;
+                break;
+            }
+
+            let zero = core::mem::replace(&mut self.zero, code != 0xFF);
+            self.remaining = code - 1;
+            if zero {
+                return Ok(Some(0));
+            }
+        }
+        Ok(None)
+    }
+}
+
+impl<R: Read> Read for CobsReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let mut n = 0;
+        while n < buf.len() {
+            match self.decode()? {
+                Some(byte) => buf[n] = byte,
+                None => break,
+            }
+            n += 1;
+        }
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<R: Read> Seek for CobsReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        seek_reader(self, self.pos, pos)
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_position(&mut self) -> Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+/// Encode COBS frames into `inner`
+///
+/// [`end_frame`](Self::end_frame) must be called to write the end of each frame, data is
+/// buffered until the end of a block of up to 254 bytes.
+pub struct CobsWriter<W> {
+    inner: W,
+    block: [u8; COBS_BLOCK],
+    len: usize,
+    // the last block written was full, so it has no implicit zero
+    full: bool,
+    pos: u64,
+}
+
+impl<W: Write> CobsWriter<W> {
+    /// Create a writer encoding frames into `inner`
+    pub fn new(inner: W) -> Self {
+        Self {
+            inner,
+            block: [0; COBS_BLOCK],
+            len: 0,
+            full: false,
+            pos: 0,
+        }
+    }
+
+    /// Gets a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Consumes this adapter, returning the underlying writer
+    ///
+    /// The current frame is not ended.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Write the end of the current frame
+    pub fn end_frame(&mut self) -> Result<()> {
+        // a frame ending with a full block needs no empty block after it
+        if self.len > 0 || !self.full {
+            self.write_block()?;
+        }
+        self.full = false;
+        self.inner.write_all(&[0])?;
+        self.pos = 0;
+        Ok(())
+    }
+
+    fn write_block(&mut self) -> Result<()> {
+        self.inner.write_all(&[self.len as u8 + 1])?;
+        self.inner.write_all(&self.block[..self.len])?;
+        self.full = self.len == COBS_BLOCK;
+        self.len = 0;
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for CobsWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        for &byte in buf {
+            if byte == 0 {
+                self.write_block()?;
+                continue;
+            }
+
+            self.block[self.len] = byte;
+            self.len += 1;
+            if self.len == COBS_BLOCK {
+                self.write_block()?;
+            }
+        }
+        self.pos += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    /// Flush the underlying writer, the current block stays buffered until it is complete
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Seek for CobsWriter<W> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        seek_writer(self.pos, pos)
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_position(&mut self) -> Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+/// Decode an escape sequence frame from `inner`
+pub struct EscapeReader<R> {
+    inner: R,
+    escape: Escape,
+    started: bool,
+    done: bool,
+    pos: u64,
+}
+
+impl<R: Read> EscapeReader<R> {
+    /// Create a reader decoding the frames of `inner`
+    pub fn new(inner: R, escape: Escape) -> Self {
+        Self {
+            inner,
+            escape,
+            started: false,
+            done: false,
+            pos: 0,
+        }
+    }
+
+    /// Gets a reference to the underlying reader
+    pub fn get_ref(&self) -> &R {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying reader
+    pub fn get_mut(&mut self) -> &mut R {
+        &mut self.inner
+    }
+
+    /// Consumes this adapter, returning the underlying reader
+    pub fn into_inner(self) -> R {
+        self.inner
+    }
+
+    /// Discard the rest of the current frame and start reading the next one
+    pub fn next_frame(&mut self) -> Result<()> {
+        while self.decode()?.is_some() {}
+        self.started = false;
+        self.done = false;
+        self.pos = 0;
+        Ok(())
+    }
+
+    /// Next payload byte, `None` at the end of the frame
+    fn decode(&mut self) -> Result<Option<u8>> {
+        while !self.done {
+            let byte = if self.started {
+                read_frame_byte(&mut self.inner)?
+            } else {
+                match read_byte(&mut self.inner)? {
+                    Some(byte) => byte,
+                    None => return Ok(None),
+                }
+            };
+
+            if byte == self.escape.delimiter {
+                self.done = self.started;
+                continue;
+            }
+
+            self.started = true;
+            if byte != self.escape.escape {
+                return Ok(Some(byte));
+            }
+
+            let escaped = read_frame_byte(&mut self.inner)?;
+            return if escaped == self.escape.escaped_delimiter {
+                Ok(Some(self.escape.delimiter))
+            } else if escaped == self.escape.escaped_escape {
+                Ok(Some(self.escape.escape))
+            } else {
+                Err(Error::new(
+                    ErrorKind::InvalidData,
+                    "invalid escape sequence",
+                ))
+            };
+        }
+        Ok(None)
+    }
+}
+
+impl<R: Read> Read for EscapeReader<R> {
+    fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        let mut n = 0;
+        while n < buf.len() {
+            match self.decode()? {
+                Some(byte) => buf[n] = byte,
+                None => break,
+            }
+            n += 1;
+        }
+        self.pos += n as u64;
+        Ok(n)
+    }
+}
+
+impl<R: Read> Seek for EscapeReader<R> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        seek_reader(self, self.pos, pos)
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_position(&mut self) -> Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+/// Encode escape sequence frames into `inner`
+///
+/// [`end_frame`](Self::end_frame) must be called to write the end of each frame.
+pub struct EscapeWriter<W> {
+    inner: W,
+    escape: Escape,
+    started: bool,
+    pos: u64,
+}
+
+impl<W: Write> EscapeWriter<W> {
+    /// Create a writer encoding frames into `inner`
+    pub fn new(inner: W, escape: Escape) -> Self {
+        Self {
+            inner,
+            escape,
+            started: false,
+            pos: 0,
+        }
+    }
+
+    /// Gets a reference to the underlying writer
+    pub fn get_ref(&self) -> &W {
+        &self.inner
+    }
+
+    /// Gets a mutable reference to the underlying writer
+    pub fn get_mut(&mut self) -> &mut W {
+        &mut self.inner
+    }
+
+    /// Consumes this adapter, returning the underlying writer
+    ///
+    /// The current frame is not ended.
+    pub fn into_inner(self) -> W {
+        self.inner
+    }
+
+    /// Write the end of the current frame
+    pub fn end_frame(&mut self) -> Result<()> {
+        self.start()?;
+        self.inner.write_all(&[self.escape.delimiter])?;
+        self.started = false;
+        self.pos = 0;
+        Ok(())
+    }
+
+    fn start(&mut self) -> Result<()> {
+        if !self.started && self.escape.start_delimiter {
+            self.inner.write_all(&[self.escape.delimiter])?;
+        }
+        self.started = true;
+        Ok(())
+    }
+}
+
+impl<W: Write> Write for EscapeWriter<W> {
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.start()?;
+        let Escape {
+            delimiter, escape, ..
+        } = self.escape;
+
+        let mut rest = buf;
+        while let Some(i) = rest.iter().position(|&b| b == delimiter || b == escape) {
+            self.inner.write_all(&rest[..i])?;
+            let escaped = if rest[i] == delimiter {
+                self.escape.escaped_delimiter
+            } else {
+                self.escape.escaped_escape
+            };
+            self.inner.write_all(&[escape, escaped])?;
+            rest = &rest[i + 1..];
+        }
+        self.inner.write_all(rest)?;
+
+        self.pos += buf.len() as u64;
+        Ok(buf.len())
+    }
+
+    fn flush(&mut self) -> Result<()> {
+        self.inner.flush()
+    }
+}
+
+impl<W: Write> Seek for EscapeWriter<W> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        seek_writer(self.pos, pos)
+    }
+
+    #[cfg(feature = "std")]
+    fn stream_position(&mut self) -> Result<u64> {
+        Ok(self.pos)
+    }
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use std::io::Cursor;
+
+    use hexlit::hex;
+    use rstest::rstest;
+
+    use super::*;
+
+    fn cobs_encode(input: &[u8]) -> Vec<u8> {
+        let mut writer = CobsWriter::new(vec![]);
+        writer.write_all(input).unwrap();
+        writer.end_frame().unwrap();
+        writer.into_inner()
+    }
+
+    fn cobs_decode(input: &[u8]) -> Result<Vec<u8>> {
+        let mut reader = CobsReader::new(Cursor::new(input));
+        let mut out = vec![];
+        reader.read_to_end(&mut out)?;
+        Ok(out)
+    }
+
+    #[rstest(input, expected,
+        case(&[], &hex!("01 00")),
+        case(&hex!("00"), &hex!("01 01 00")),
+        case(&hex!("00 00"), &hex!("01 01 01 00")),
+        case(&hex!("11 22 00 33"), &hex!("03 11 22 02 33 00")),
+        case(&hex!("11 22 33 44"), &hex!("05 11 22 33 44 00")),
+        case(&hex!("11 00 00 00"), &hex!("02 11 01 01 01 00")),
+    )]
+    fn test_cobs(input: &[u8], expected: &[u8]) {
+        assert_eq!(expected, cobs_encode(input));
+        assert_eq!(input, cobs_decode(expected).unwrap());
+    }
+
+    #[test]
+    fn test_cobs_long_block() {
+        let input: Vec<u8> = (1..=255).collect();
+        let encoded = cobs_encode(&input);
+        assert_eq!(&[0xFF, 0x01], &encoded[..2]);
+        assert_eq!(&[0xFE, 0x02, 0xFF, 0x00], &encoded[254..]);
+        assert_eq!(input, cobs_decode(&encoded).unwrap());
+
+        let input: Vec<u8> = (1..=254).collect();
+        let encoded = cobs_encode(&input);
+        assert_eq!(256, encoded.len());
+        assert_eq!(&[0xFD, 0xFE, 0x00], &encoded[253..]);
+        assert_eq!(input, cobs_decode(&encoded).unwrap());
+
+        let mut input: Vec<u8> = (1..=254).collect();
+        input.push(0);
+        let encoded = cobs_encode(&input);
+        assert_eq!(&[0xFE, 0x01, 0x01, 0x00], &encoded[254..]);
+        assert_eq!(input, cobs_decode(&encoded).unwrap());
+    }
+
+    #[rstest(input, kind,
+        case(&hex!("03 11 22"), ErrorKind::UnexpectedEof),
+        case(&hex!("03 11 00"), ErrorKind::InvalidData),
+    )]
+    fn test_cobs_invalid(input: &[u8], kind: ErrorKind) {
+        assert_eq!(kind, cobs_decode(input).unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_cobs_frames() {
+        let mut reader = CobsReader::new(Cursor::new(hex!("00 03 11 22 00 00 02 33 00")));
+        let mut buf = [0u8; 1];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!([0x11], buf);
+        reader.next_frame().unwrap();
+
+        let mut out = vec![];
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(vec![0x33], out);
+
+        reader.next_frame().unwrap();
+        assert_eq!(0, reader.read(&mut buf).unwrap());
+    }
+
+    #[rstest(escape, input, expected,
+        case(Escape::SLIP, &hex!("01 C0 02 DB 03"), &hex!("01 DB DC 02 DB DD 03 C0")),
+        case(Escape::SLIP, &[], &hex!("C0")),
+        case(Escape::HDLC, &hex!("7E 7D 01"), &hex!("7E 7D 5E 7D 5D 01 7E")),
+        case(Escape::HDLC, &[], &hex!("7E 7E")),
+    )]
+    fn test_escape(escape: Escape, input: &[u8], expected: &[u8]) {
+        let mut writer = EscapeWriter::new(vec![], escape);
+        writer.write_all(input).unwrap();
+        writer.end_frame().unwrap();
+        assert_eq!(expected, writer.into_inner());
+
+        let mut reader = EscapeReader::new(Cursor::new(expected), escape);
+        let mut out = vec![];
+        reader.read_to_end(&mut out).unwrap();
+        assert_eq!(input, out);
+    }
+
+    #[rstest(input, kind,
+        case(&hex!("01 02"), ErrorKind::UnexpectedEof),
+        case(&hex!("01 DB 02 C0"), ErrorKind::InvalidData),
+    )]
+    fn test_escape_invalid(input: &[u8], kind: ErrorKind) {
+        let mut reader = EscapeReader::new(Cursor::new(input), Escape::SLIP);
+        let mut out = vec![];
+        assert_eq!(kind, reader.read_to_end(&mut out).unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_seek() {
+        let mut reader = EscapeReader::new(Cursor::new(hex!("01 02 03 C0")), Escape::SLIP);
+        assert_eq!(2, reader.seek(SeekFrom::Current(2)).unwrap());
+        assert!(reader.seek(SeekFrom::Start(1)).is_err());
+        let mut buf = [0u8; 1];
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!([0x03], buf);
+
+        let mut writer = CobsWriter::new(vec![]);
+        writer.write_all(&[1, 2]).unwrap();
+        assert_eq!(2, writer.stream_position().unwrap());
+        assert!(writer.seek(SeekFrom::Start(0)).is_err());
+    }
+}
@@ -71,6 +71,8 @@ where
     }
 }
 
+impl<T: ?Sized + crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for Arc<T> {}
+
 #[cfg(test)]
 #[allow(clippy::too_many_arguments)]
 mod tests {
@@ -0,0 +1,120 @@
+//! Implementations of DekuRead and DekuWrite for `arrayvec::ArrayVec`
+//!
+//! It is read with the same limits as `Vec`, failing with [DekuError::Parse] instead of
+//! growing past its capacity. [DekuSize](crate::DekuSize) is its size at full capacity.
+
+use arrayvec::ArrayVec;
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::ctx::Limit;
+use crate::impls::capacity::read_limited;
+use crate::reader::Reader;
+#[cfg(feature = "alloc")]
+use crate::vectored::IoSliceWriter;
+use crate::writer::Writer;
+use crate::{DekuError, DekuReader, DekuWriter};
+
+impl<'a, T, Ctx, Predicate, const CAP: usize> DekuReader<'a, (Limit<T, Predicate>, Ctx)>
+    for ArrayVec<T, CAP>
+where
+    T: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+    Predicate: FnMut(&T) -> bool,
+{
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (limit, inner_ctx): (Limit<T, Predicate>, Ctx),
+    ) -> Result<Self, DekuError> {
+        let mut res = ArrayVec::new();
+        read_limited(reader, limit, inner_ctx, CAP, |v| {
+            res.try_push(v).map_err(|e| e.element())
+        })?;
+        Ok(res)
+    }
+}
+
+impl<'a, T, Predicate, const CAP: usize> DekuReader<'a, Limit<T, Predicate>> for ArrayVec<T, CAP>
+where
+    T: DekuReader<'a>,
+    Predicate: FnMut(&T) -> bool,
+{
+    /// Read `T`s until the given limit from input for types which don't require context.
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        limit: Limit<T, Predicate>,
+    ) -> Result<Self, DekuError> {
+        ArrayVec::from_reader_with_ctx(reader, (limit, ()))
+    }
+}
+
+impl<T, Ctx, const CAP: usize> DekuWriter<Ctx> for ArrayVec<T, CAP>
+where
+    T: DekuWriter<Ctx>,
+    Ctx: Copy,
+{
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.as_slice().to_writer(writer, ctx)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_writer_vectored<'a>(
+        &'a self,
+        writer: &mut Writer<IoSliceWriter<'a>>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.as_slice().to_writer_vectored(writer, ctx)
+    }
+}
+
+impl<T: crate::DekuWriterNoSeek, const CAP: usize> crate::DekuWriterNoSeek for ArrayVec<T, CAP> {}
+
+impl<T: crate::DekuSize, const CAP: usize> crate::DekuSize for ArrayVec<T, CAP> {
+    const SIZE_BITS: usize = T::SIZE_BITS * CAP;
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::ctx::{BitSize, Endian};
+    use crate::DekuSize;
+
+    #[rstest(input, limit, expected,
+        case::count(&[0xaa, 0xbb, 0xcc], 2.into(), &[0xaa, 0xbb]),
+        case::until(&[0xaa, 0x00, 0xcc], (|v: &u8| *v == 0).into(), &[0xaa, 0x00]),
+        case::bits(&[0xaa, 0xbb, 0xcc], BitSize(16).into(), &[0xaa, 0xbb]),
+        case::end(&[0xaa, 0xbb, 0xcc, 0xdd], Limit::end(), &[0xaa, 0xbb, 0xcc, 0xdd]),
+        #[should_panic(expected = "Container capacity exceeded")]
+        case::count_too_large(&[0xaa; 8], 5.into(), &[]),
+        #[should_panic(expected = "Container capacity exceeded")]
+        case::bits_too_large(&[0xaa; 8], BitSize(48).into(), &[]),
+    )]
+    fn test_arrayvec_read<Predicate: FnMut(&u8) -> bool>(
+        input: &[u8],
+        limit: Limit<u8, Predicate>,
+        expected: &[u8],
+    ) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res = ArrayVec::<u8, 4>::from_reader_with_ctx(&mut reader, limit).unwrap();
+        assert_eq!(expected, res.as_slice());
+    }
+
+    #[test]
+    fn test_arrayvec_write() {
+        let value: ArrayVec<u16, 4> = [0x0102, 0x0304].into_iter().collect();
+        let mut out = std::vec::Vec::new();
+        let mut writer = Writer::new(Cursor::new(&mut out));
+        value.to_writer(&mut writer, Endian::Little).unwrap();
+        assert_eq!(out, [0x02, 0x01, 0x04, 0x03]);
+
+        assert_eq!(ArrayVec::<u16, 4>::SIZE_BITS, 64);
+    }
+}
@@ -0,0 +1,137 @@
+use no_std_io::io::{Read, Seek, Write};
+
+use alloc::collections::BinaryHeap;
+use alloc::vec::Vec;
+
+use crate::ctx::Limit;
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{DekuError, DekuReader, DekuWriter};
+
+impl<'a, T, Ctx, Predicate> DekuReader<'a, (Limit<T, Predicate>, Ctx)> for BinaryHeap<T>
+where
+    T: DekuReader<'a, Ctx> + Ord,
+    Ctx: Copy,
+    Predicate: FnMut(&T) -> bool,
+{
+    /// Read `T`s until the given limit
+    /// * `limit` - the limiting factor on the amount of `T`s to read
+    /// * `inner_ctx` - The context required by `T`. It will be passed to every `T`s when constructing.
+    /// # Examples
+    /// ```rust
+    /// # use deku::ctx::*;
+    /// # use deku::DekuReader;
+    /// # use deku::no_std_io::Cursor;
+    /// # extern crate alloc;
+    /// # use alloc::collections::BinaryHeap;
+    /// let mut input = Cursor::new([0x01, 0x03, 0x02]);
+    /// let mut reader = deku::reader::Reader::new(&mut input);
+    /// let heap =
+    ///     BinaryHeap::<u8>::from_reader_with_ctx(&mut reader, (3.into(), Endian::Big)).unwrap();
+    /// assert_eq!(heap.into_sorted_vec(), [0x01, 0x02, 0x03]);
+    /// ```
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (limit, inner_ctx): (Limit<T, Predicate>, Ctx),
+    ) -> Result<Self, DekuError> {
+        // use Vec<T>'s implementation and convert to BinaryHeap<T>
+        let val = <Vec<T>>::from_reader_with_ctx(reader, (limit, inner_ctx))?;
+        Ok(BinaryHeap::from(val))
+    }
+}
+
+impl<'a, T: DekuReader<'a> + Ord, Predicate: FnMut(&T) -> bool> DekuReader<'a, Limit<T, Predicate>>
+    for BinaryHeap<T>
+{
+    /// Read `T`s until the given limit from input for types which don't require context.
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        limit: Limit<T, Predicate>,
+    ) -> Result<Self, DekuError> {
+        Self::from_reader_with_ctx(reader, (limit, ()))
+    }
+}
+
+impl<T: DekuWriter<Ctx>, Ctx: Copy> DekuWriter<Ctx> for BinaryHeap<T> {
+    /// Write all `T`s in a `BinaryHeap` to bits in the heap's internal order.
+    /// * **inner_ctx** - The context required by `T`.
+    ///
+    /// Note: the order in which the `T`s are written is the heap's internal
+    /// (arbitrary) order, as returned by [`BinaryHeap::iter`]. Reading the output
+    /// back yields an equal heap, but not necessarily the same byte order when
+    /// written again. Use a sorted container if the wire order matters.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use deku::{ctx::Endian, DekuWriter};
+    /// # use deku::writer::Writer;
+    /// # use deku::no_std_io::Cursor;
+    /// # extern crate alloc;
+    /// # use alloc::collections::BinaryHeap;
+    /// let mut out_buf = [0; 1];
+    /// let mut cursor = Cursor::new(&mut out_buf[..]);
+    /// let mut writer = Writer::new(&mut cursor);
+    /// let heap: BinaryHeap<u8> = [1].into_iter().collect();
+    /// heap.to_writer(&mut writer, Endian::Big).unwrap();
+    /// assert_eq!(out_buf, [1]);
+    /// ```
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        inner_ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        for v in self {
+            v.to_writer(writer, inner_ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for BinaryHeap<T> {}
+
+#[cfg(all(feature = "alloc", feature = "bits"))]
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use no_std_io::io::Cursor;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ctx::*;
+    use crate::reader::Reader;
+
+    #[rstest(input, endian, limit, expected_sorted, expected_rest_bytes,
+        case::count_0([0xAA].as_ref(), Endian::Little, 0.into(), vec![], &[0xaa]),
+        case::count_2([0xCC, 0xDD, 0xAA, 0xBB, 0xEE].as_ref(), Endian::Big, 2.into(), vec![0xAABB, 0xCCDD], &[0xee]),
+        case::until_le([0xAA, 0xBB, 0x00, 0x00, 0xCC].as_ref(), Endian::Little, (|v: &u16| *v == 0).into(), vec![0x0000, 0xBBAA], &[0xcc]),
+        case::bits_be([0xCC, 0xDD, 0xAA, 0xBB].as_ref(), Endian::Big, BitSize(32).into(), vec![0xAABB, 0xCCDD], &[]),
+        case::end_be([0x00, 0x01, 0x00, 0x03, 0x00, 0x02].as_ref(), Endian::Big, Limit::end(), vec![1, 2, 3], &[]),
+    )]
+    fn test_binaryheap_reader_write<Predicate: FnMut(&u16) -> bool>(
+        input: &[u8],
+        endian: Endian,
+        limit: Limit<u16, Predicate>,
+        expected_sorted: Vec<u16>,
+        expected_rest_bytes: &[u8],
+    ) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read =
+            BinaryHeap::<u16>::from_reader_with_ctx(&mut reader, (limit, endian)).unwrap();
+        assert_eq!(expected_sorted, res_read.clone().into_sorted_vec());
+        let mut buf = vec![];
+        cursor.read_to_end(&mut buf).unwrap();
+        assert_eq!(expected_rest_bytes, buf);
+
+        // Written in the heap's internal order, which reads back as the same heap
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        let written = writer.inner.into_inner();
+        assert_eq!(expected_sorted.len() * 2, written.len());
+        let mut cursor = Cursor::new(written);
+        let mut reader = Reader::new(&mut cursor);
+        let reread =
+            BinaryHeap::<u16>::from_reader_with_ctx(&mut reader, (Limit::end(), endian)).unwrap();
+        assert_eq!(expected_sorted, reread.into_sorted_vec());
+    }
+}
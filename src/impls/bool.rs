@@ -51,6 +51,8 @@ impl crate::DekuSize for bool {
     const SIZE_BITS: usize = 8;
 }
 
+impl crate::DekuWriterNoSeek for bool {}
+
 #[cfg(test)]
 mod tests {
     use hexlit::hex;
@@ -5,6 +5,7 @@ use alloc::vec::Vec;
 
 use crate::ctx::Limit;
 use crate::reader::Reader;
+use crate::vectored::IoSliceWriter;
 use crate::writer::Writer;
 use crate::{DekuError, DekuReader, DekuWriter};
 
@@ -54,6 +55,14 @@ where
         }
         Ok(())
     }
+
+    fn to_writer_vectored<'a>(
+        &'a self,
+        writer: &mut Writer<IoSliceWriter<'a>>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        <[T]>::to_writer_vectored(self, writer, ctx)
+    }
 }
 
 impl<T, Ctx> DekuWriter<Ctx> for Box<T>
@@ -70,8 +79,18 @@ where
         self.as_ref().to_writer(writer, ctx)?;
         Ok(())
     }
+
+    fn to_writer_vectored<'a>(
+        &'a self,
+        writer: &mut Writer<IoSliceWriter<'a>>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.as_ref().to_writer_vectored(writer, ctx)
+    }
 }
 
+impl<T: ?Sized + crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for Box<T> {}
+
 #[cfg(all(feature = "alloc", feature = "bits"))]
 #[cfg(test)]
 #[allow(clippy::too_many_arguments)]
@@ -0,0 +1,271 @@
+use alloc::collections::BTreeSet;
+
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::ctx::*;
+use crate::writer::Writer;
+use crate::{DekuError, DekuReader, DekuWriter};
+
+/// Read `T`s into a btreeset until a given predicate returns true
+/// * `ctx` - The context required by `T`. It will be passed to every `T` when constructing.
+/// * `predicate` - the predicate that decides when to stop reading `T`s
+///   The predicate takes two parameters: the number of bits that have been read so far,
+///   and a borrow of the latest value to have been read. It should return `true` if reading
+///   should now stop, and `false` otherwise
+fn from_reader_with_ctx_btreeset_with_predicate<'a, T, Ctx, Predicate, R: Read + Seek>(
+    reader: &mut crate::reader::Reader<R>,
+    ctx: Ctx,
+    mut predicate: Predicate,
+) -> Result<BTreeSet<T>, DekuError>
+where
+    T: DekuReader<'a, Ctx> + Ord,
+    Ctx: Copy,
+    Predicate: FnMut(usize, &T) -> bool,
+{
+    let mut res = BTreeSet::new();
+
+    let mut found_predicate = false;
+    let orig_bits_read = reader.bits_read;
+
+    while !found_predicate {
+        let val = <T>::from_reader_with_ctx(reader, ctx)?;
+        found_predicate = predicate(reader.bits_read - orig_bits_read, &val);
+        res.insert(val);
+    }
+
+    Ok(res)
+}
+
+fn from_reader_with_ctx_btreeset_to_end<'a, T, Ctx, R: Read + Seek>(
+    reader: &mut crate::reader::Reader<R>,
+    ctx: Ctx,
+) -> Result<BTreeSet<T>, DekuError>
+where
+    T: DekuReader<'a, Ctx> + Ord,
+    Ctx: Copy,
+{
+    let mut res = BTreeSet::new();
+
+    loop {
+        if reader.end() {
+            break;
+        }
+        let val = <T>::from_reader_with_ctx(reader, ctx)?;
+        res.insert(val);
+    }
+
+    Ok(res)
+}
+
+impl<'a, T, Ctx, Predicate> DekuReader<'a, (Limit<T, Predicate>, Ctx)> for BTreeSet<T>
+where
+    T: DekuReader<'a, Ctx> + Ord,
+    Ctx: Copy,
+    Predicate: FnMut(&T) -> bool,
+{
+    /// Read `T`s until the given limit
+    /// * `limit` - the limiting factor on the amount of `T`s to read
+    /// * `inner_ctx` - The context required by `T`. It will be passed to every `T`s when constructing.
+    /// # Examples
+    /// ```rust
+    /// # use deku::ctx::*;
+    /// # use deku::DekuReader;
+    /// # use deku::no_std_io::Cursor;
+    /// # extern crate alloc;
+    /// # use alloc::collections::BTreeSet;
+    /// let mut input = Cursor::new([1u8, 2, 3, 4]);
+    /// let expected: BTreeSet<u32> = [0x04030201].into_iter().collect();
+    /// let mut reader = deku::reader::Reader::new(&mut input);
+    /// let set = BTreeSet::<u32>::from_reader_with_ctx(&mut reader, (1.into(), Endian::Little)).unwrap();
+    /// assert_eq!(expected, set)
+    /// ```
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut crate::reader::Reader<R>,
+        (limit, inner_ctx): (Limit<T, Predicate>, Ctx),
+    ) -> Result<Self, DekuError>
+    where
+        Self: Sized,
+    {
+        match limit {
+            // Read a given count of elements
+            Limit::Count(mut count) => {
+                // Handle the trivial case of reading an empty btreeset
+                if count == 0 {
+                    return Ok(BTreeSet::new());
+                }
+
+                // Otherwise, read until we have read `count` elements
+                from_reader_with_ctx_btreeset_with_predicate(reader, inner_ctx, move |_, _| {
+                    count -= 1;
+                    count == 0
+                })
+            }
+
+            // Read until a given predicate returns true
+            Limit::Until(mut predicate, _) => {
+                from_reader_with_ctx_btreeset_with_predicate(reader, inner_ctx, move |_, value| {
+                    predicate(value)
+                })
+            }
+
+            // Read until a given quantity of bits have been read
+            Limit::BitSize(size) => {
+                let bit_size = size.0;
+
+                // Handle the trivial case of reading an empty btreeset
+                if bit_size == 0 {
+                    return Ok(BTreeSet::new());
+                }
+
+                from_reader_with_ctx_btreeset_with_predicate(
+                    reader,
+                    inner_ctx,
+                    move |read_bits, _| read_bits == bit_size,
+                )
+            }
+
+            // Read until a given quantity of bytes have been read
+            Limit::ByteSize(size) => {
+                let bit_size = size.0 * 8;
+
+                // Handle the trivial case of reading an empty btreeset
+                if bit_size == 0 {
+                    return Ok(BTreeSet::new());
+                }
+
+                from_reader_with_ctx_btreeset_with_predicate(
+                    reader,
+                    inner_ctx,
+                    move |read_bits, _| read_bits == bit_size,
+                )
+            }
+
+            // Read until `reader.end()` is true
+            Limit::End => from_reader_with_ctx_btreeset_to_end(reader, inner_ctx),
+        }
+    }
+}
+
+impl<'a, T: DekuReader<'a> + Ord, Predicate: FnMut(&T) -> bool> DekuReader<'a, Limit<T, Predicate>>
+    for BTreeSet<T>
+{
+    /// Read `T`s until the given limit from input for types which don't require context.
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut crate::reader::Reader<R>,
+        limit: Limit<T, Predicate>,
+    ) -> Result<Self, DekuError>
+    where
+        Self: Sized,
+    {
+        Self::from_reader_with_ctx(reader, (limit, ()))
+    }
+}
+
+impl<T: DekuWriter<Ctx>, Ctx: Copy> DekuWriter<Ctx> for BTreeSet<T> {
+    /// Write all `T`s in a `BTreeSet` to bits, in ascending order.
+    /// * **inner_ctx** - The context required by `T`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use deku::{ctx::Endian, DekuWriter};
+    /// # use deku::writer::Writer;
+    /// # use deku::no_std_io::Cursor;
+    /// # extern crate alloc;
+    /// # use alloc::collections::BTreeSet;
+    /// let mut out_buf = [0; 3];
+    /// let mut cursor = Cursor::new(&mut out_buf[..]);
+    /// let mut writer = Writer::new(&mut cursor);
+    /// let set: BTreeSet<u8> = [3, 1, 2].into_iter().collect();
+    /// set.to_writer(&mut writer, Endian::Big).unwrap();
+    /// assert_eq!(out_buf, [1, 2, 3]);
+    /// ```
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        inner_ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        for v in self {
+            v.to_writer(writer, inner_ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for BTreeSet<T> {}
+
+#[cfg(test)]
+#[allow(clippy::too_many_arguments)]
+mod tests {
+    #[cfg(all(feature = "bits", feature = "descriptive-errors"))]
+    use crate::bitvec::{bits, BitSlice, Msb0};
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use no_std_io::io::Cursor;
+    use rstest::rstest;
+
+    #[cfg(all(feature = "bits", feature = "descriptive-errors"))]
+    use crate::reader::Reader;
+
+    use super::*;
+
+    #[cfg(all(feature = "bits", feature = "descriptive-errors"))]
+    #[rstest(input, endian, bit_size, limit, expected, expected_rest_bits, expected_rest_bytes,
+        case::count_0([0xAA].as_ref(), Endian::Little, Some(8), 0.into(), BTreeSet::new(), bits![u8, Msb0;], &[0xaa]),
+        case::count_1([0xAA, 0xBB].as_ref(), Endian::Little, Some(8), 1.into(), [0xAA].into_iter().collect(), bits![u8, Msb0;], &[0xbb]),
+        case::count_2([0xAA, 0xBB, 0xCC].as_ref(), Endian::Little, Some(8), 2.into(), [0xAA, 0xBB].into_iter().collect(), bits![u8, Msb0;], &[0xcc]),
+        case::count_duplicate([0xAA, 0xAA, 0xCC].as_ref(), Endian::Little, Some(8), 2.into(), [0xAA].into_iter().collect(), bits![u8, Msb0;], &[0xcc]),
+        case::until_null([0xAA, 0, 0xBB].as_ref(), Endian::Little, None, (|v: &u8| *v == 0u8).into(), [0xAA, 0].into_iter().collect(), bits![u8, Msb0;], &[0xbb]),
+        case::until_empty_bits([0xAA, 0xBB].as_ref(), Endian::Little, None, BitSize(0).into(), BTreeSet::new(), bits![u8, Msb0;], &[0xaa, 0xbb]),
+        case::until_empty_bytes([0xAA, 0xBB].as_ref(), Endian::Little, None, ByteSize(0).into(), BTreeSet::new(), bits![u8, Msb0;], &[0xaa, 0xbb]),
+        case::until_bits([0xAA, 0xBB].as_ref(), Endian::Little, None, BitSize(8).into(), [0xAA].into_iter().collect(), bits![u8, Msb0;], &[0xbb]),
+        case::read_all([0xAA, 0xBB].as_ref(), Endian::Little, None, Limit::end(), [0xAA, 0xBB].into_iter().collect(), bits![u8, Msb0;], &[]),
+        case::until_bytes([0xAA, 0xBB].as_ref(), Endian::Little, None, ByteSize(1).into(), [0xAA].into_iter().collect(), bits![u8, Msb0;], &[0xbb]),
+        case::bits_6([0b0110_1001, 0b1110_1001].as_ref(), Endian::Little, Some(6), 2.into(), [0b00_011010, 0b00_011110].into_iter().collect(), bits![u8, Msb0; 1, 0, 0, 1], &[]),
+        #[should_panic(expected = "Parse(\"too much data: container of 8 bits cannot hold 9 bits\")")]
+        case::not_enough_data([0xAA].as_ref(), Endian::Little, Some(9), 1.into(), BTreeSet::new(), bits![u8, Msb0;], &[]),
+        #[should_panic(expected = "Incomplete(NeedSize { bits: 8 })")]
+        case::not_enough_data([0xAA].as_ref(), Endian::Little, Some(8), 2.into(), BTreeSet::new(), bits![u8, Msb0;], &[]),
+        #[should_panic(expected = "Incomplete(NeedSize { bits: 8 })")]
+        case::not_enough_data_until([0xAA].as_ref(), Endian::Little, Some(8), (|_: &u8| false).into(), BTreeSet::new(), bits![u8, Msb0;], &[]),
+        #[should_panic(expected = "Incomplete(NeedSize { bits: 8 })")]
+        case::not_enough_data_bits([0xAA].as_ref(), Endian::Little, Some(8), (BitSize(16)).into(), BTreeSet::new(), bits![u8, Msb0;], &[]),
+    )]
+    fn test_btreeset_read<Predicate: FnMut(&u8) -> bool + Copy>(
+        input: &[u8],
+        endian: Endian,
+        bit_size: Option<usize>,
+        limit: Limit<u8, Predicate>,
+        expected: BTreeSet<u8>,
+        expected_rest_bits: &BitSlice<u8, Msb0>,
+        expected_rest_bytes: &[u8],
+    ) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = match bit_size {
+            Some(bit_size) => BTreeSet::<u8>::from_reader_with_ctx(
+                &mut reader,
+                (limit, (endian, BitSize(bit_size))),
+            )
+            .unwrap(),
+            None => BTreeSet::<u8>::from_reader_with_ctx(&mut reader, (limit, (endian))).unwrap(),
+        };
+        assert_eq!(expected, res_read);
+        assert_eq!(
+            reader.rest(),
+            expected_rest_bits.iter().by_vals().collect::<Vec<bool>>()
+        );
+        let mut buf = vec![];
+        cursor.read_to_end(&mut buf).unwrap();
+        assert_eq!(expected_rest_bytes, buf);
+    }
+
+    #[rstest(input, endian, expected,
+        case::normal([0xCCDD, 0xAABB].into_iter().collect(), Endian::Little, vec![0xBB, 0xAA, 0xDD, 0xCC]),
+        case::normal_be([0xCCDD, 0xAABB].into_iter().collect(), Endian::Big, vec![0xAA, 0xBB, 0xCC, 0xDD]),
+    )]
+    fn test_btreeset_write(input: BTreeSet<u16>, endian: Endian, expected: Vec<u8>) {
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        input.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(expected, writer.inner.into_inner());
+    }
+}
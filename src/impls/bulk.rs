@@ -0,0 +1,199 @@
+//! Bulk reads and writes of primitive sequences, used by `Vec<T>` and `[T; N]`
+//!
+//! A primitive whose encoding is its bytes in memory, possibly reversed, is read
+//! with one `read_bytes` straight into the destination and byte swapped in place,
+//! and written from a slice of its memory, instead of one value at a time. Written to an
+//! [IoSliceWriter](crate::vectored::IoSliceWriter), that slice is borrowed instead of copied.
+
+use core::marker::PhantomData;
+use core::mem::{self, MaybeUninit};
+
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::ctx::{Endian, Order};
+use crate::error::NeedSize;
+use crate::reader::Reader;
+#[cfg(feature = "alloc")]
+use crate::vectored::IoSliceWriter;
+use crate::writer::Writer;
+use crate::DekuError;
+
+/// Primitives whose memory can be read and written as bytes
+///
+/// Only implemented in `impls::primitive`, `BulkLayout<T>` can't be constructed for other types.
+///
+/// # Safety
+/// Any bit pattern must be a valid value, and the type must have no padding bytes.
+pub(crate) unsafe trait BulkPrimitive: Copy {}
+
+/// Byte layout of a primitive for a given ctx, returned by
+/// [DekuReader::bulk_layout](crate::DekuReader::bulk_layout) and
+/// [DekuWriter::bulk_layout](crate::DekuWriter::bulk_layout)
+///
+/// Can only be constructed by deku for a `BulkPrimitive`, which makes the bulk reads and
+/// writes of `T` sound.
+#[doc(hidden)]
+#[derive(Debug, PartialEq, Eq)]
+pub struct BulkLayout<T> {
+    /// The encoding is the bytes in memory reversed
+    swap: bool,
+    _type: PhantomData<fn() -> T>,
+}
+
+impl<T> Clone for BulkLayout<T> {
+    #[inline(always)]
+    fn clone(&self) -> Self {
+        *self
+    }
+}
+
+impl<T> Copy for BulkLayout<T> {}
+
+impl<T> BulkLayout<T> {
+    /// Whether the bytes of each `T` have to be reversed, never for single bytes
+    #[inline(always)]
+    fn swaps(self) -> bool {
+        self.swap && mem::size_of::<T>() > 1
+    }
+
+    /// Layout of a primitive encoded with `endian`, none for word swapped endians
+    #[inline(always)]
+    pub(crate) fn of_endian(endian: Endian) -> Option<Self>
+    where
+        T: BulkPrimitive,
+    {
+        if endian.is_word_swapped() {
+            return None;
+        }
+        Some(Self {
+            swap: endian.is_le() != cfg!(target_endian = "little"),
+            _type: PhantomData,
+        })
+    }
+}
+
+/// Bytes per `read_bytes` when filling a `Vec`, so that a bogus count only
+/// allocates as much as the input actually holds
+#[cfg(feature = "alloc")]
+const VEC_CHUNK_BYTES: usize = 64 * 1024;
+
+/// Read `out.len()` values of `T` in one go, initializing all of `out` on success
+#[inline]
+pub(crate) fn read_into<T, R: Read + Seek>(
+    reader: &mut Reader<R>,
+    layout: BulkLayout<T>,
+    out: &mut [MaybeUninit<T>],
+) -> Result<(), DekuError> {
+    let len = mem::size_of_val(out);
+    // SAFETY: zeroing makes every byte initialized, and `out` is valid for `len` bytes
+    let bytes = unsafe {
+        let ptr = out.as_mut_ptr().cast::<u8>();
+        ptr.write_bytes(0, len);
+        core::slice::from_raw_parts_mut(ptr, len)
+    };
+    reader
+        .read_bytes(len, bytes, Order::default())
+        .map_err(|e| match e {
+            // report the first missing value, like reading one at a time
+            DekuError::Incomplete(_) => {
+                DekuError::Incomplete(NeedSize::new(mem::size_of::<T>() * 8))
+            }
+            e => e,
+        })?;
+    if layout.swaps() {
+        swap_each::<T>(bytes);
+    }
+    // the bytes read are valid values, as `layout` guarantees that `T` is a `BulkPrimitive`
+    Ok(())
+}
+
+/// Read `count` values of `T` into a `Vec`
+#[cfg(feature = "alloc")]
+pub(crate) fn read_vec<T, R: Read + Seek>(
+    reader: &mut Reader<R>,
+    layout: BulkLayout<T>,
+    count: usize,
+) -> Result<alloc::vec::Vec<T>, DekuError> {
+    let chunk = (VEC_CHUNK_BYTES / mem::size_of::<T>()).max(1);
+    let mut res = alloc::vec::Vec::with_capacity(count.min(chunk));
+    while res.len() < count {
+        let n = (count - res.len()).min(chunk);
+        res.reserve(n);
+        let start = res.len();
+        read_into(reader, layout, &mut res.spare_capacity_mut()[..n])?;
+        // SAFETY: `read_into` initialized the `n` values after `start` with valid values
+        unsafe { res.set_len(start + n) };
+    }
+    Ok(res)
+}
+
+/// Write `values` in one go, or through a small buffer when they need a byte swap
+#[inline]
+pub(crate) fn write<T, W: Write + Seek>(
+    writer: &mut Writer<W>,
+    layout: BulkLayout<T>,
+    values: &[T],
+) -> Result<(), DekuError> {
+    // SAFETY: `layout` guarantees that `T` is a `BulkPrimitive` without padding, so all of its
+    // bytes are initialized
+    let bytes = unsafe {
+        core::slice::from_raw_parts(values.as_ptr().cast::<u8>(), mem::size_of_val(values))
+    };
+    if !layout.swaps() {
+        return writer.write_bytes(bytes);
+    }
+
+    let size = mem::size_of::<T>();
+    let mut buf = [0u8; 256];
+    for chunk in bytes.chunks(buf.len() / size * size) {
+        let buf = &mut buf[..chunk.len()];
+        buf.copy_from_slice(chunk);
+        swap_each::<T>(buf);
+        writer.write_bytes(buf)?;
+    }
+    Ok(())
+}
+
+/// Write `values` by borrowing their memory, unless they need a byte swap
+#[cfg(feature = "alloc")]
+#[inline]
+pub(crate) fn write_vectored<'a, T>(
+    writer: &mut Writer<IoSliceWriter<'a>>,
+    layout: BulkLayout<T>,
+    values: &'a [T],
+) -> Result<(), DekuError> {
+    if layout.swaps() {
+        return write(writer, layout, values);
+    }
+    // SAFETY: `layout` guarantees that `T` is a `BulkPrimitive` without padding, so all of its
+    // bytes are initialized
+    let bytes = unsafe {
+        core::slice::from_raw_parts(values.as_ptr().cast::<u8>(), mem::size_of_val(values))
+    };
+    writer.write_bytes_borrowed(bytes)
+}
+
+/// Reverse the bytes of each `T` in `bytes`
+#[inline(always)]
+fn swap_each<T>(bytes: &mut [u8]) {
+    macro_rules! swap {
+        ($uint:ty) => {
+            for value in bytes.chunks_exact_mut(mem::size_of::<$uint>()) {
+                let swapped = <$uint>::from_ne_bytes(value.try_into().unwrap()).swap_bytes();
+                value.copy_from_slice(&swapped.to_ne_bytes());
+            }
+        };
+    }
+    match mem::size_of::<T>() {
+        1 => {}
+        2 => swap!(u16),
+        4 => swap!(u32),
+        8 => swap!(u64),
+        16 => swap!(u128),
+        size => {
+            for value in bytes.chunks_exact_mut(size) {
+                value.reverse();
+            }
+        }
+    }
+}
@@ -0,0 +1,188 @@
+//! Implementations of DekuRead and DekuWrite for `bytes::Bytes` and `bytes::BytesMut`
+//!
+//! With a [Reader](crate::reader::Reader) created by
+//! [new_shared](crate::reader::Reader::new_shared), byte aligned `Bytes` read with `count`,
+//! `bytes_read` or `read_all` are slices of the input. Otherwise they are copied like `Vec<u8>`.
+
+use alloc::vec::Vec;
+
+use bytes::{Bytes, BytesMut};
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::ctx::Limit;
+use crate::reader::Reader;
+use crate::vectored::IoSliceWriter;
+use crate::writer::Writer;
+use crate::{DekuError, DekuReader, DekuWriter};
+
+impl<'a, Ctx, Predicate> DekuReader<'a, (Limit<u8, Predicate>, Ctx)> for Bytes
+where
+    u8: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+    Predicate: FnMut(&u8) -> bool,
+{
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (limit, inner_ctx): (Limit<u8, Predicate>, Ctx),
+    ) -> Result<Self, DekuError> {
+        // whole bytes can be sliced from the shared input
+        if <u8 as DekuReader<'a, Ctx>>::bulk_layout(inner_ctx).is_some() {
+            let len = match &limit {
+                Limit::Count(count) => Some(Some(*count)),
+                Limit::ByteSize(size) => Some(Some(size.0)),
+                Limit::End => Some(None),
+                Limit::Until(..) | Limit::BitSize(_) => None,
+            };
+            if let Some(len) = len {
+                if let Some(bytes) = reader.read_shared(len)? {
+                    return Ok(bytes);
+                }
+            }
+        }
+
+        let bytes = Vec::<u8>::from_reader_with_ctx(reader, (limit, inner_ctx))?;
+        Ok(Bytes::from(bytes))
+    }
+}
+
+impl<'a, Predicate: FnMut(&u8) -> bool> DekuReader<'a, Limit<u8, Predicate>> for Bytes {
+    /// Read bytes until the given limit, for types which don't require context.
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        limit: Limit<u8, Predicate>,
+    ) -> Result<Self, DekuError> {
+        Bytes::from_reader_with_ctx(reader, (limit, ()))
+    }
+}
+
+impl<'a, Ctx, Predicate> DekuReader<'a, (Limit<u8, Predicate>, Ctx)> for BytesMut
+where
+    u8: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+    Predicate: FnMut(&u8) -> bool,
+{
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (limit, inner_ctx): (Limit<u8, Predicate>, Ctx),
+    ) -> Result<Self, DekuError> {
+        let bytes = Vec::<u8>::from_reader_with_ctx(reader, (limit, inner_ctx))?;
+        Ok(BytesMut::from(Bytes::from(bytes)))
+    }
+}
+
+impl<'a, Predicate: FnMut(&u8) -> bool> DekuReader<'a, Limit<u8, Predicate>> for BytesMut {
+    /// Read bytes until the given limit, for types which don't require context.
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        limit: Limit<u8, Predicate>,
+    ) -> Result<Self, DekuError> {
+        BytesMut::from_reader_with_ctx(reader, (limit, ()))
+    }
+}
+
+macro_rules! ImplDekuWrite {
+    ($typ:ty) => {
+        impl<Ctx> DekuWriter<Ctx> for $typ
+        where
+            u8: DekuWriter<Ctx>,
+            Ctx: Copy,
+        {
+            fn to_writer<W: Write + Seek>(
+                &self,
+                writer: &mut Writer<W>,
+                ctx: Ctx,
+            ) -> Result<(), DekuError> {
+                self.as_ref().to_writer(writer, ctx)
+            }
+
+            fn to_writer_vectored<'a>(
+                &'a self,
+                writer: &mut Writer<IoSliceWriter<'a>>,
+                ctx: Ctx,
+            ) -> Result<(), DekuError> {
+                self.as_ref().to_writer_vectored(writer, ctx)
+            }
+        }
+
+        impl crate::DekuWriterNoSeek for $typ {}
+    };
+}
+
+ImplDekuWrite!(Bytes);
+ImplDekuWrite!(BytesMut);
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::ctx::{ByteSize, Endian};
+
+    #[rstest(limit, expected, shared,
+        case::count(2.into(), &[0xaa, 0xbb], true),
+        case::bytes(ByteSize(3).into(), &[0xaa, 0xbb, 0xcc], true),
+        case::end(Limit::end(), &[0xaa, 0xbb, 0xcc, 0x00], true),
+        case::until((|v: &u8| *v == 0xbb).into(), &[0xaa, 0xbb], false),
+        #[should_panic(expected = "Incomplete(NeedSize { bits: 40 })")]
+        case::count_too_large(5.into(), &[], true),
+    )]
+    fn test_bytes_read<Predicate: FnMut(&u8) -> bool>(
+        limit: Limit<u8, Predicate>,
+        expected: &[u8],
+        shared: bool,
+    ) {
+        let input = Bytes::from_static(&[0xaa, 0xbb, 0xcc, 0x00]);
+        let mut reader = Reader::new_shared(input.clone());
+        let res = Bytes::from_reader_with_ctx(&mut reader, (limit, Endian::Big)).unwrap();
+        assert_eq!(expected, res);
+        assert_eq!(shared, res.as_ptr() == input.as_ptr());
+        assert_eq!(expected.len() * 8, reader.bits_read);
+    }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn test_bytes_read_unaligned() {
+        let input = Bytes::from_static(&[0xab, 0xcd, 0xe0]);
+        let mut reader = Reader::new_shared(input);
+        let _ = reader.read_bits(4, crate::ctx::Order::Msb0).unwrap();
+        let res = Bytes::from_reader_with_ctx(&mut reader, Limit::new_count(2)).unwrap();
+        assert_eq!(res, [0xbc, 0xde][..]);
+    }
+
+    #[test]
+    fn test_bytes_read_copied() {
+        let input = [0xab, 0xcd, 0xe0];
+        let mut reader = Reader::new(Cursor::new(&input));
+        let res = BytesMut::from_reader_with_ctx(&mut reader, Limit::new_count(2)).unwrap();
+        assert_eq!(res, [0xab, 0xcd][..]);
+    }
+
+    #[test]
+    fn test_bytes_read_after_peek() {
+        let input = Bytes::from_static(&[0x01, 0x02, 0x03, 0x04]);
+        let mut reader = Reader::new_shared(input.clone());
+        let mut peeked = [0; 2];
+        reader.peek_bytes(&mut peeked).unwrap();
+        let _ = u8::from_reader_with_ctx(&mut reader, ()).unwrap();
+        let res = Bytes::from_reader_with_ctx(&mut reader, Limit::new_count(2)).unwrap();
+        assert_eq!(res, input.slice(1..3));
+        assert_eq!(res.as_ptr(), input[1..].as_ptr());
+        let last = u8::from_reader_with_ctx(&mut reader, ()).unwrap();
+        assert_eq!(last, 0x04);
+    }
+
+    #[test]
+    fn test_bytes_write() {
+        let mut out = Vec::new();
+        let mut writer = Writer::new(Cursor::new(&mut out));
+        Bytes::from_static(&[1, 2])
+            .to_writer(&mut writer, ())
+            .unwrap();
+        BytesMut::from(&[3, 4][..])
+            .to_writer(&mut writer, Endian::Little)
+            .unwrap();
+        assert_eq!(out, [1, 2, 3, 4]);
+    }
+}
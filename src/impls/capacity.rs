@@ -0,0 +1,87 @@
+//! Reading into fixed-capacity containers, shared by the `heapless` and `arrayvec` integrations
+
+use core::mem;
+
+use no_std_io::io::{Read, Seek};
+
+use crate::ctx::Limit;
+use crate::reader::Reader;
+use crate::{deku_error, DekuError, DekuReader};
+
+fn capacity_exceeded(capacity: usize) -> DekuError {
+    deku_error!(
+        DekuError::Parse,
+        "Container capacity exceeded",
+        "capacity is {}",
+        capacity
+    )
+}
+
+/// Read `T`s until `limit` is reached, giving each to `push`, which returns the value back
+/// when the container is full
+///
+/// A `Limit::Count` larger than `capacity` fails before anything is read, and a `Limit::BitSize`
+/// or `Limit::ByteSize` fails if the last element ends past the limit.
+pub(crate) fn read_limited<'a, T, Ctx, Predicate, R>(
+    reader: &mut Reader<R>,
+    limit: Limit<T, Predicate>,
+    ctx: Ctx,
+    capacity: usize,
+    mut push: impl FnMut(T) -> Result<(), T>,
+) -> Result<(), DekuError>
+where
+    T: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+    Predicate: FnMut(&T) -> bool,
+    R: Read + Seek,
+{
+    // ZST detected, nothing to read
+    if mem::size_of::<T>() == 0 {
+        return Ok(());
+    }
+
+    let mut push = |value| push(value).map_err(|_| capacity_exceeded(capacity));
+    let start_read = reader.bits_read;
+    let bit_size = match limit {
+        Limit::Count(count) => {
+            if count > capacity {
+                return Err(capacity_exceeded(capacity));
+            }
+            for _ in 0..count {
+                push(T::from_reader_with_ctx(reader, ctx)?)?;
+            }
+            return Ok(());
+        }
+        Limit::Until(mut predicate, _) => loop {
+            let value = T::from_reader_with_ctx(reader, ctx)?;
+            let done = predicate(&value);
+            push(value)?;
+            if done {
+                return Ok(());
+            }
+        },
+        Limit::End => {
+            while !reader.end() {
+                push(T::from_reader_with_ctx(reader, ctx)?)?;
+            }
+            return Ok(());
+        }
+        Limit::BitSize(size) => size.0,
+        Limit::ByteSize(size) => size.0 * 8,
+    };
+
+    while reader.bits_read - start_read < bit_size {
+        push(T::from_reader_with_ctx(reader, ctx)?)?;
+    }
+    let read = reader.bits_read - start_read;
+    if read != bit_size {
+        return Err(deku_error!(
+            DekuError::Parse,
+            "Elements read past the size limit",
+            "read {} bits, limit is {} bits",
+            read,
+            bit_size
+        ));
+    }
+    Ok(())
+}
@@ -0,0 +1,144 @@
+use core::cell::{Cell, RefCell};
+
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{deku_error, DekuError, DekuReader, DekuWriter};
+
+impl<'a, T, Ctx> DekuReader<'a, Ctx> for Cell<T>
+where
+    T: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+{
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        inner_ctx: Ctx,
+    ) -> Result<Self, DekuError> {
+        let val = <T>::from_reader_with_ctx(reader, inner_ctx)?;
+        Ok(Cell::new(val))
+    }
+}
+
+impl<T, Ctx> DekuWriter<Ctx> for Cell<T>
+where
+    T: DekuWriter<Ctx> + Copy,
+    Ctx: Copy,
+{
+    /// Write the `T` currently held by the `Cell`
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        inner_ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.get().to_writer(writer, inner_ctx)
+    }
+}
+
+impl<T: crate::DekuSize> crate::DekuSize for Cell<T> {
+    const SIZE_BITS: usize = T::SIZE_BITS;
+}
+
+impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for Cell<T> {}
+
+impl<'a, T, Ctx> DekuReader<'a, Ctx> for RefCell<T>
+where
+    T: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+{
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        inner_ctx: Ctx,
+    ) -> Result<Self, DekuError> {
+        let val = <T>::from_reader_with_ctx(reader, inner_ctx)?;
+        Ok(RefCell::new(val))
+    }
+}
+
+impl<T, Ctx> DekuWriter<Ctx> for RefCell<T>
+where
+    T: DekuWriter<Ctx>,
+    Ctx: Copy,
+{
+    /// Write the `T` held by the `RefCell`
+    ///
+    /// Returns [`DekuError::InvalidParam`] if the value is currently mutably borrowed
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        inner_ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        let val = self.try_borrow().map_err(|_| {
+            deku_error!(
+                DekuError::InvalidParam,
+                "cannot write RefCell while it is mutably borrowed"
+            )
+        })?;
+        val.to_writer(writer, inner_ctx)
+    }
+}
+
+impl<T: crate::DekuSize> crate::DekuSize for RefCell<T> {
+    const SIZE_BITS: usize = T::SIZE_BITS;
+}
+
+impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for RefCell<T> {}
+
+#[cfg(test)]
+mod tests {
+    use no_std_io::io::Cursor;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ctx::Endian;
+    use crate::DekuSize;
+
+    #[rstest(input, endian, expected,
+        case(&[0xEF, 0xBE], Endian::Little, 0xBEEF_u16),
+        case(&[0xBE, 0xEF], Endian::Big, 0xBEEF_u16),
+    )]
+    fn test_cell(input: &[u8], endian: Endian, expected: u16) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = <Cell<u16>>::from_reader_with_ctx(&mut reader, endian).unwrap();
+        assert_eq!(expected, res_read.get());
+
+        let mut out = [0u8; 2];
+        let mut writer = Writer::new(Cursor::new(&mut out[..]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(input, out);
+
+        assert_eq!(16, <Cell<u16>>::SIZE_BITS);
+    }
+
+    #[rstest(input, endian, expected,
+        case(&[0xEF, 0xBE], Endian::Little, 0xBEEF_u16),
+        case(&[0xBE, 0xEF], Endian::Big, 0xBEEF_u16),
+    )]
+    fn test_refcell(input: &[u8], endian: Endian, expected: u16) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = <RefCell<u16>>::from_reader_with_ctx(&mut reader, endian).unwrap();
+        assert_eq!(expected, *res_read.borrow());
+
+        let mut out = [0u8; 2];
+        let mut writer = Writer::new(Cursor::new(&mut out[..]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(input, out);
+
+        assert_eq!(16, <RefCell<u16>>::SIZE_BITS);
+    }
+
+    #[test]
+    fn test_refcell_write_borrowed() {
+        let cell = RefCell::new(0xAA_u8);
+        let _guard = cell.borrow_mut();
+
+        let mut out = [0u8; 1];
+        let mut writer = Writer::new(Cursor::new(&mut out[..]));
+        assert!(matches!(
+            cell.to_writer(&mut writer, ()),
+            Err(DekuError::InvalidParam(_))
+        ));
+    }
+}
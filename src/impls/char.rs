@@ -0,0 +1,191 @@
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::ctx::{CharEncoding, Endian};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{deku_error, DekuError, DekuReader, DekuWriter};
+
+fn char_from_reader<R: Read + Seek>(
+    reader: &mut Reader<R>,
+    endian: Endian,
+    encoding: CharEncoding,
+) -> Result<char, DekuError> {
+    match encoding {
+        CharEncoding::Utf32 => {
+            let value = u32::from_reader_with_ctx(reader, endian)?;
+            char::from_u32(value).ok_or_else(|| {
+                deku_error!(
+                    DekuError::Parse,
+                    "invalid unicode scalar value",
+                    "{:#x}",
+                    value
+                )
+            })
+        }
+        CharEncoding::Utf8 => {
+            let mut buf = [0u8; 4];
+            buf[0] = u8::from_reader_with_ctx(reader, ())?;
+            let len = match buf[0] {
+                0x00..=0x7f => 1,
+                0xc0..=0xdf => 2,
+                0xe0..=0xef => 3,
+                0xf0..=0xf7 => 4,
+                b => {
+                    return Err(deku_error!(
+                        DekuError::Parse,
+                        "invalid utf-8 leading byte",
+                        "{:#x}",
+                        b
+                    ))
+                }
+            };
+            for b in buf.iter_mut().take(len).skip(1) {
+                *b = u8::from_reader_with_ctx(reader, ())?;
+            }
+
+            core::str::from_utf8(&buf[..len])
+                .ok()
+                .and_then(|s| s.chars().next())
+                .ok_or_else(|| {
+                    deku_error!(
+                        DekuError::Parse,
+                        "invalid utf-8 sequence",
+                        "{:02x?}",
+                        &buf[..len]
+                    )
+                })
+        }
+    }
+}
+
+fn char_to_writer<W: Write + Seek>(
+    value: char,
+    writer: &mut Writer<W>,
+    endian: Endian,
+    encoding: CharEncoding,
+) -> Result<(), DekuError> {
+    match encoding {
+        CharEncoding::Utf32 => u32::from(value).to_writer(writer, endian),
+        CharEncoding::Utf8 => {
+            let mut buf = [0u8; 4];
+            for b in value.encode_utf8(&mut buf).as_bytes() {
+                b.to_writer(writer, ())?;
+            }
+            Ok(())
+        }
+    }
+}
+
+impl DekuReader<'_, (Endian, CharEncoding)> for char {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (endian, encoding): (Endian, CharEncoding),
+    ) -> Result<Self, DekuError> {
+        char_from_reader(reader, endian, encoding)
+    }
+}
+
+impl DekuReader<'_, CharEncoding> for char {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        encoding: CharEncoding,
+    ) -> Result<Self, DekuError> {
+        char_from_reader(reader, Endian::default(), encoding)
+    }
+}
+
+impl DekuReader<'_, Endian> for char {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        endian: Endian,
+    ) -> Result<Self, DekuError> {
+        char_from_reader(reader, endian, CharEncoding::default())
+    }
+}
+
+impl DekuReader<'_> for char {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        _: (),
+    ) -> Result<Self, DekuError> {
+        char_from_reader(reader, Endian::default(), CharEncoding::default())
+    }
+}
+
+impl DekuWriter<(Endian, CharEncoding)> for char {
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        (endian, encoding): (Endian, CharEncoding),
+    ) -> Result<(), DekuError> {
+        char_to_writer(*self, writer, endian, encoding)
+    }
+}
+
+impl DekuWriter<CharEncoding> for char {
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        encoding: CharEncoding,
+    ) -> Result<(), DekuError> {
+        char_to_writer(*self, writer, Endian::default(), encoding)
+    }
+}
+
+impl DekuWriter<Endian> for char {
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        endian: Endian,
+    ) -> Result<(), DekuError> {
+        char_to_writer(*self, writer, endian, CharEncoding::default())
+    }
+}
+
+impl DekuWriter for char {
+    fn to_writer<W: Write + Seek>(&self, writer: &mut Writer<W>, _: ()) -> Result<(), DekuError> {
+        char_to_writer(*self, writer, Endian::default(), CharEncoding::default())
+    }
+}
+
+impl crate::DekuWriterNoSeek for char {}
+
+#[cfg(feature = "alloc")]
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use no_std_io::io::Cursor;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(input, endian, encoding, expected,
+        case::utf32_le(&[0x41, 0x00, 0x00, 0x00], Endian::Little, CharEncoding::Utf32, 'A'),
+        case::utf32_be(&[0x00, 0x01, 0xF6, 0x00], Endian::Big, CharEncoding::Utf32, '😀'),
+        case::utf8_1(&[0x41], Endian::Big, CharEncoding::Utf8, 'A'),
+        case::utf8_2(&[0xC3, 0xA9], Endian::Little, CharEncoding::Utf8, 'é'),
+        case::utf8_3(&[0xE2, 0x82, 0xAC], Endian::Big, CharEncoding::Utf8, '€'),
+        case::utf8_4(&[0xF0, 0x9F, 0x98, 0x80], Endian::Little, CharEncoding::Utf8, '😀'),
+
+        #[should_panic(expected = "Parse(\"invalid unicode scalar value")]
+        case::utf32_surrogate(&[0x00, 0xD8, 0x00, 0x00], Endian::Little, CharEncoding::Utf32, 'A'),
+        #[should_panic(expected = "Parse(\"invalid unicode scalar value")]
+        case::utf32_out_of_range(&[0x00, 0x11, 0x00, 0x00], Endian::Big, CharEncoding::Utf32, 'A'),
+        #[should_panic(expected = "Parse(\"invalid utf-8 leading byte")]
+        case::utf8_continuation(&[0x80], Endian::Big, CharEncoding::Utf8, 'A'),
+        #[should_panic(expected = "Parse(\"invalid utf-8 sequence")]
+        case::utf8_truncated(&[0xC3, 0x41], Endian::Big, CharEncoding::Utf8, 'A'),
+        #[should_panic(expected = "Incomplete(NeedSize { bits: 8 })")]
+        case::utf8_eof(&[0xE2, 0x82], Endian::Big, CharEncoding::Utf8, 'A'),
+    )]
+    fn test_char(input: &[u8], endian: Endian, encoding: CharEncoding, expected: char) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = char::from_reader_with_ctx(&mut reader, (endian, encoding)).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, (endian, encoding)).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+}
@@ -35,6 +35,8 @@ where
     }
 }
 
+impl<T: crate::DekuWriterNoSeek + Clone> crate::DekuWriterNoSeek for Cow<'_, T> {}
+
 #[cfg(test)]
 mod tests {
     #[cfg(feature = "alloc")]
@@ -22,6 +22,8 @@ where
     }
 }
 
+impl crate::DekuWriterNoSeek for CString {}
+
 impl DekuReader<'_> for CString {
     fn from_reader_with_ctx<R: Read + Seek>(
         reader: &mut Reader<R>,
@@ -241,6 +241,11 @@ impl<K: DekuWriter<Ctx>, V: DekuWriter<Ctx>, S, Ctx: Copy> DekuWriter<Ctx> for H
     }
 }
 
+impl<K: crate::DekuWriterNoSeek, V: crate::DekuWriterNoSeek, S> crate::DekuWriterNoSeek
+    for HashMap<K, V, S>
+{
+}
+
 #[cfg(all(feature = "bits", feature = "descriptive-errors"))]
 #[cfg(test)]
 mod tests {
@@ -211,6 +211,8 @@ impl<T: DekuWriter<Ctx>, S, Ctx: Copy> DekuWriter<Ctx> for HashSet<T, S> {
     }
 }
 
+impl<T: crate::DekuWriterNoSeek, S> crate::DekuWriterNoSeek for HashSet<T, S> {}
+
 #[cfg(test)]
 #[allow(clippy::too_many_arguments)]
 mod tests {
@@ -0,0 +1,219 @@
+//! Implementations of DekuRead and DekuWrite for `heapless::Vec` and `heapless::String`
+//!
+//! Both are read with the same limits as `Vec`, failing with [DekuError::Parse] instead of
+//! growing past their capacity. [DekuSize](crate::DekuSize) is their size at full capacity.
+
+use heapless::{LenType, String, Vec};
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::ctx::Limit;
+use crate::impls::capacity::read_limited;
+use crate::reader::Reader;
+#[cfg(feature = "alloc")]
+use crate::vectored::IoSliceWriter;
+use crate::writer::Writer;
+use crate::{deku_error, DekuError, DekuReader, DekuWriter};
+
+impl<'a, T, Ctx, Predicate, const N: usize, LenT> DekuReader<'a, (Limit<T, Predicate>, Ctx)>
+    for Vec<T, N, LenT>
+where
+    T: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+    Predicate: FnMut(&T) -> bool,
+    LenT: LenType,
+{
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (limit, inner_ctx): (Limit<T, Predicate>, Ctx),
+    ) -> Result<Self, DekuError> {
+        let mut res = Vec::new();
+        read_limited(reader, limit, inner_ctx, N, |v| res.push(v))?;
+        Ok(res)
+    }
+}
+
+impl<'a, T, Predicate, const N: usize, LenT> DekuReader<'a, Limit<T, Predicate>> for Vec<T, N, LenT>
+where
+    T: DekuReader<'a>,
+    Predicate: FnMut(&T) -> bool,
+    LenT: LenType,
+{
+    /// Read `T`s until the given limit from input for types which don't require context.
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        limit: Limit<T, Predicate>,
+    ) -> Result<Self, DekuError> {
+        Vec::from_reader_with_ctx(reader, (limit, ()))
+    }
+}
+
+impl<T, Ctx, const N: usize, LenT> DekuWriter<Ctx> for Vec<T, N, LenT>
+where
+    T: DekuWriter<Ctx>,
+    Ctx: Copy,
+    LenT: LenType,
+{
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.as_slice().to_writer(writer, ctx)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_writer_vectored<'a>(
+        &'a self,
+        writer: &mut Writer<IoSliceWriter<'a>>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.as_slice().to_writer_vectored(writer, ctx)
+    }
+}
+
+impl<T: crate::DekuWriterNoSeek, const N: usize, LenT: LenType> crate::DekuWriterNoSeek
+    for Vec<T, N, LenT>
+{
+}
+
+impl<T: crate::DekuSize, const N: usize, LenT: LenType> crate::DekuSize for Vec<T, N, LenT> {
+    const SIZE_BITS: usize = T::SIZE_BITS * N;
+}
+
+impl<'a, Ctx, Predicate, const N: usize, LenT> DekuReader<'a, (Limit<u8, Predicate>, Ctx)>
+    for String<N, LenT>
+where
+    u8: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+    Predicate: FnMut(&u8) -> bool,
+    LenT: LenType,
+{
+    /// Read UTF-8 bytes until the given limit
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (limit, inner_ctx): (Limit<u8, Predicate>, Ctx),
+    ) -> Result<Self, DekuError> {
+        let bytes = Vec::<u8, N, LenT>::from_reader_with_ctx(reader, (limit, inner_ctx))?;
+        String::from_utf8(bytes).map_err(|e| {
+            deku_error!(
+                DekuError::Parse,
+                "Failed to convert Vec to String",
+                "{:?}",
+                e
+            )
+        })
+    }
+}
+
+impl<'a, Predicate, const N: usize, LenT> DekuReader<'a, Limit<u8, Predicate>> for String<N, LenT>
+where
+    Predicate: FnMut(&u8) -> bool,
+    LenT: LenType,
+{
+    /// Read UTF-8 bytes until the given limit, for types which don't require context.
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        limit: Limit<u8, Predicate>,
+    ) -> Result<Self, DekuError> {
+        String::from_reader_with_ctx(reader, (limit, ()))
+    }
+}
+
+impl<Ctx, const N: usize, LenT> DekuWriter<Ctx> for String<N, LenT>
+where
+    u8: DekuWriter<Ctx>,
+    Ctx: Copy,
+    LenT: LenType,
+{
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.as_bytes().to_writer(writer, ctx)
+    }
+
+    #[cfg(feature = "alloc")]
+    fn to_writer_vectored<'a>(
+        &'a self,
+        writer: &mut Writer<IoSliceWriter<'a>>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.as_bytes().to_writer_vectored(writer, ctx)
+    }
+}
+
+impl<const N: usize, LenT: LenType> crate::DekuWriterNoSeek for String<N, LenT> {}
+
+impl<const N: usize, LenT: LenType> crate::DekuSize for String<N, LenT> {
+    const SIZE_BITS: usize = 8 * N;
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use rstest::rstest;
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::ctx::{ByteSize, Endian};
+    use crate::DekuSize;
+
+    #[rstest(input, limit, expected,
+        case::count(&[0xaa, 0xbb, 0xcc], 2.into(), &[0xaa, 0xbb]),
+        case::until(&[0xaa, 0x00, 0xcc], (|v: &u8| *v == 0).into(), &[0xaa, 0x00]),
+        case::bytes(&[0xaa, 0xbb, 0xcc], ByteSize(3).into(), &[0xaa, 0xbb, 0xcc]),
+        case::end(&[0xaa, 0xbb], Limit::end(), &[0xaa, 0xbb]),
+        #[should_panic(expected = "Container capacity exceeded")]
+        case::count_too_large(&[0xaa; 8], 5.into(), &[]),
+        #[should_panic(expected = "Container capacity exceeded")]
+        case::until_too_large(&[0xaa; 8], (|v: &u8| *v == 0).into(), &[]),
+        #[should_panic(expected = "Container capacity exceeded")]
+        case::end_too_large(&[0xaa; 8], Limit::end(), &[]),
+    )]
+    fn test_vec_read<Predicate: FnMut(&u8) -> bool>(
+        input: &[u8],
+        limit: Limit<u8, Predicate>,
+        expected: &[u8],
+    ) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res = Vec::<u8, 4>::from_reader_with_ctx(&mut reader, limit).unwrap();
+        assert_eq!(expected, res.as_slice());
+    }
+
+    #[test]
+    fn test_vec_write() {
+        let value: Vec<u16, 4> = Vec::from_slice(&[0x0102, 0x0304]).unwrap();
+        let mut out = std::vec::Vec::new();
+        let mut writer = Writer::new(Cursor::new(&mut out));
+        value.to_writer(&mut writer, Endian::Big).unwrap();
+        assert_eq!(out, [0x01, 0x02, 0x03, 0x04]);
+
+        assert_eq!(Vec::<u16, 4>::SIZE_BITS, 64);
+    }
+
+    #[rstest(input, limit, expected,
+        case::count(b"abcd", 3.into(), "abc"),
+        case::until(b"ab\0c", (|v: &u8| *v == 0).into(), "ab\0"),
+        #[should_panic(expected = "Failed to convert Vec to String")]
+        case::invalid_utf8(&[0xff, 0xfe], 2.into(), ""),
+        #[should_panic(expected = "Container capacity exceeded")]
+        case::too_large(b"abcdefgh", 8.into(), ""),
+    )]
+    fn test_string_read<Predicate: FnMut(&u8) -> bool>(
+        input: &[u8],
+        limit: Limit<u8, Predicate>,
+        expected: &str,
+    ) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res = String::<4>::from_reader_with_ctx(&mut reader, limit).unwrap();
+        assert_eq!(expected, res.as_str());
+
+        let mut out = std::vec::Vec::new();
+        let mut writer = Writer::new(Cursor::new(&mut out));
+        res.to_writer(&mut writer, ()).unwrap();
+        assert_eq!(expected.as_bytes(), out);
+    }
+}
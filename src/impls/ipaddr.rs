@@ -1,4 +1,4 @@
-use core::net::{IpAddr, Ipv4Addr, Ipv6Addr};
+use core::net::{IpAddr, Ipv4Addr, Ipv6Addr, SocketAddr, SocketAddrV4, SocketAddrV6};
 
 use no_std_io::io::{Read, Seek, Write};
 
@@ -33,6 +33,8 @@ where
     }
 }
 
+impl crate::DekuWriterNoSeek for Ipv4Addr {}
+
 impl<'a, Ctx> DekuReader<'a, Ctx> for Ipv6Addr
 where
     u128: DekuReader<'a, Ctx>,
@@ -60,6 +62,8 @@ where
     }
 }
 
+impl crate::DekuWriterNoSeek for Ipv6Addr {}
+
 impl<Ctx> DekuWriter<Ctx> for IpAddr
 where
     Ipv6Addr: DekuWriter<Ctx>,
@@ -77,6 +81,103 @@ where
     }
 }
 
+impl crate::DekuWriterNoSeek for IpAddr {}
+
+/// Read a `SocketAddrV4` stored as the address followed by the port
+impl<'a, Ctx> DekuReader<'a, Ctx> for SocketAddrV4
+where
+    Ipv4Addr: DekuReader<'a, Ctx>,
+    u16: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+{
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        inner_ctx: Ctx,
+    ) -> Result<Self, DekuError> {
+        let ip = Ipv4Addr::from_reader_with_ctx(reader, inner_ctx)?;
+        let port = u16::from_reader_with_ctx(reader, inner_ctx)?;
+        Ok(SocketAddrV4::new(ip, port))
+    }
+}
+
+impl<Ctx> DekuWriter<Ctx> for SocketAddrV4
+where
+    Ipv4Addr: DekuWriter<Ctx>,
+    u16: DekuWriter<Ctx>,
+    Ctx: Copy,
+{
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.ip().to_writer(writer, ctx)?;
+        self.port().to_writer(writer, ctx)
+    }
+}
+
+impl crate::DekuWriterNoSeek for SocketAddrV4 {}
+
+/// Read a `SocketAddrV6` stored as the address, port, flow info and scope id
+impl<'a, Ctx> DekuReader<'a, Ctx> for SocketAddrV6
+where
+    Ipv6Addr: DekuReader<'a, Ctx>,
+    u16: DekuReader<'a, Ctx>,
+    u32: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+{
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        inner_ctx: Ctx,
+    ) -> Result<Self, DekuError> {
+        let ip = Ipv6Addr::from_reader_with_ctx(reader, inner_ctx)?;
+        let port = u16::from_reader_with_ctx(reader, inner_ctx)?;
+        let flowinfo = u32::from_reader_with_ctx(reader, inner_ctx)?;
+        let scope_id = u32::from_reader_with_ctx(reader, inner_ctx)?;
+        Ok(SocketAddrV6::new(ip, port, flowinfo, scope_id))
+    }
+}
+
+impl<Ctx> DekuWriter<Ctx> for SocketAddrV6
+where
+    Ipv6Addr: DekuWriter<Ctx>,
+    u16: DekuWriter<Ctx>,
+    u32: DekuWriter<Ctx>,
+    Ctx: Copy,
+{
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.ip().to_writer(writer, ctx)?;
+        self.port().to_writer(writer, ctx)?;
+        self.flowinfo().to_writer(writer, ctx)?;
+        self.scope_id().to_writer(writer, ctx)
+    }
+}
+
+impl crate::DekuWriterNoSeek for SocketAddrV6 {}
+
+impl<Ctx> DekuWriter<Ctx> for SocketAddr
+where
+    SocketAddrV6: DekuWriter<Ctx>,
+    SocketAddrV4: DekuWriter<Ctx>,
+{
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        match self {
+            SocketAddr::V4(addr) => addr.to_writer(writer, ctx),
+            SocketAddr::V6(addr) => addr.to_writer(writer, ctx),
+        }
+    }
+}
+
+impl crate::DekuWriterNoSeek for SocketAddr {}
+
 #[cfg(feature = "alloc")]
 #[cfg(test)]
 mod tests {
@@ -136,4 +237,46 @@ mod tests {
             writer.inner.into_inner()
         );
     }
+
+    #[rstest(input, endian, expected,
+        case::normal_le([237, 160, 254, 145, 0x50, 0x00].as_ref(), Endian::Little, SocketAddrV4::new(Ipv4Addr::new(145, 254, 160, 237), 80)),
+        case::normal_be([145, 254, 160, 237, 0x00, 0x50].as_ref(), Endian::Big, SocketAddrV4::new(Ipv4Addr::new(145, 254, 160, 237), 80)),
+    )]
+    fn test_socket_addr_v4(input: &[u8], endian: Endian, expected: SocketAddrV4) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = SocketAddrV4::from_reader_with_ctx(&mut reader, endian).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[rstest(input, endian, expected,
+        case::normal_le([0xFF, 0x02, 0x0A, 0xC0, 0xFF, 0xFF, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xBB, 0x01, 0x01, 0x00, 0x00, 0x00, 0x02, 0x00, 0x00, 0x00].as_ref(), Endian::Little, SocketAddrV6::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0xc00a, 0x02ff), 443, 1, 2)),
+        case::normal_be([0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0x00, 0xFF, 0xFF, 0xC0, 0x0A, 0x02, 0xFF, 0x01, 0xBB, 0x00, 0x00, 0x00, 0x01, 0x00, 0x00, 0x00, 0x02].as_ref(), Endian::Big, SocketAddrV6::new(Ipv6Addr::new(0, 0, 0, 0, 0, 0xffff, 0xc00a, 0x02ff), 443, 1, 2)),
+    )]
+    fn test_socket_addr_v6(input: &[u8], endian: Endian, expected: SocketAddrV6) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = SocketAddrV6::from_reader_with_ctx(&mut reader, endian).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[test]
+    fn test_socket_addr_write() {
+        let addr = SocketAddr::V4(SocketAddrV4::new(Ipv4Addr::new(145, 254, 160, 237), 80));
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        addr.to_writer(&mut writer, Endian::Big).unwrap();
+        assert_eq!(
+            vec![145, 254, 160, 237, 0x00, 0x50],
+            writer.inner.into_inner()
+        );
+    }
 }
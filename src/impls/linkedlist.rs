@@ -0,0 +1,131 @@
+use no_std_io::io::{Read, Seek, Write};
+
+use alloc::collections::LinkedList;
+use alloc::vec::Vec;
+
+use crate::ctx::Limit;
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{DekuError, DekuReader, DekuWriter};
+
+impl<'a, T, Ctx, Predicate> DekuReader<'a, (Limit<T, Predicate>, Ctx)> for LinkedList<T>
+where
+    T: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+    Predicate: FnMut(&T) -> bool,
+{
+    /// Read `T`s until the given limit
+    /// * `limit` - the limiting factor on the amount of `T`s to read
+    /// * `inner_ctx` - The context required by `T`. It will be passed to every `T`s when constructing.
+    /// # Examples
+    /// ```rust
+    /// # use deku::ctx::*;
+    /// # use deku::DekuReader;
+    /// # use deku::no_std_io::Cursor;
+    /// # extern crate alloc;
+    /// # use alloc::collections::LinkedList;
+    /// let mut input = Cursor::new([0xAA, 0xBB, 0x00, 0xCC]);
+    /// let mut reader = deku::reader::Reader::new(&mut input);
+    /// let list = LinkedList::<u8>::from_reader_with_ctx(
+    ///     &mut reader,
+    ///     (Limit::new_until(|v: &u8| *v == 0), Endian::Big),
+    /// )
+    /// .unwrap();
+    /// assert_eq!(list.into_iter().collect::<Vec<_>>(), [0xAA, 0xBB, 0x00]);
+    /// ```
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (limit, inner_ctx): (Limit<T, Predicate>, Ctx),
+    ) -> Result<Self, DekuError> {
+        // use Vec<T>'s implementation and convert to LinkedList<T>
+        let val = <Vec<T>>::from_reader_with_ctx(reader, (limit, inner_ctx))?;
+        Ok(val.into_iter().collect())
+    }
+}
+
+impl<'a, T: DekuReader<'a>, Predicate: FnMut(&T) -> bool> DekuReader<'a, Limit<T, Predicate>>
+    for LinkedList<T>
+{
+    /// Read `T`s until the given limit from input for types which don't require context.
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        limit: Limit<T, Predicate>,
+    ) -> Result<Self, DekuError> {
+        Self::from_reader_with_ctx(reader, (limit, ()))
+    }
+}
+
+impl<T: DekuWriter<Ctx>, Ctx: Copy> DekuWriter<Ctx> for LinkedList<T> {
+    /// Write all `T`s in a `LinkedList` to bits.
+    /// * **inner_ctx** - The context required by `T`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use deku::{ctx::Endian, DekuWriter};
+    /// # use deku::writer::Writer;
+    /// # use deku::no_std_io::Cursor;
+    /// # extern crate alloc;
+    /// # use alloc::collections::LinkedList;
+    /// let mut out_buf = [0; 2];
+    /// let mut cursor = Cursor::new(&mut out_buf[..]);
+    /// let mut writer = Writer::new(&mut cursor);
+    /// let list: LinkedList<u8> = [1, 2].into_iter().collect();
+    /// list.to_writer(&mut writer, Endian::Big).unwrap();
+    /// assert_eq!(out_buf, [1, 2]);
+    /// ```
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        inner_ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        for v in self {
+            v.to_writer(writer, inner_ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for LinkedList<T> {}
+
+#[cfg(all(feature = "alloc", feature = "bits"))]
+#[cfg(test)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use no_std_io::io::Cursor;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ctx::*;
+    use crate::reader::Reader;
+
+    #[rstest(input, endian, limit, expected, expected_rest_bytes,
+        case::count_0([0xAA].as_ref(), Endian::Little, 0.into(), vec![], &[0xaa]),
+        case::count_2([0xAA, 0xBB, 0xCC, 0xDD, 0xEE].as_ref(), Endian::Little, 2.into(), vec![0xBBAA, 0xDDCC], &[0xee]),
+        case::until_be([0xAA, 0xBB, 0xCC, 0xDD].as_ref(), Endian::Big, (|v: &u16| *v == 0xAABB).into(), vec![0xAABB], &[0xcc, 0xdd]),
+        case::bytes_be([0xAA, 0xBB, 0xCC, 0xDD].as_ref(), Endian::Big, ByteSize(4).into(), vec![0xAABB, 0xCCDD], &[]),
+        case::end_le([0xAA, 0xBB, 0xCC, 0xDD].as_ref(), Endian::Little, Limit::end(), vec![0xBBAA, 0xDDCC], &[]),
+    )]
+    fn test_linkedlist_reader_write<Predicate: FnMut(&u16) -> bool>(
+        input: &[u8],
+        endian: Endian,
+        limit: Limit<u16, Predicate>,
+        expected: Vec<u16>,
+        expected_rest_bytes: &[u8],
+    ) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read =
+            LinkedList::<u16>::from_reader_with_ctx(&mut reader, (limit, endian)).unwrap();
+        assert_eq!(expected, res_read.iter().copied().collect::<Vec<_>>());
+        let mut buf = vec![];
+        cursor.read_to_end(&mut buf).unwrap();
+        assert_eq!(expected_rest_bytes, buf);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(
+            input[..input.len() - expected_rest_bytes.len()].to_vec(),
+            writer.inner.into_inner()
+        );
+    }
+}
@@ -1,11 +1,17 @@
 mod bool;
+pub(crate) mod bulk;
+mod cell;
+mod char;
 mod ipaddr;
 mod nonzero;
 mod option;
+mod phantomdata;
 mod primitive;
 mod slice;
+mod time;
 mod tuple;
 mod unit;
+mod wrapper;
 
 #[cfg(feature = "alloc")]
 mod vec;
@@ -13,6 +19,9 @@ mod vec;
 #[cfg(all(feature = "alloc", target_has_atomic = "ptr"))]
 mod arc;
 
+#[cfg(feature = "alloc")]
+mod rc;
+
 #[cfg(feature = "alloc")]
 mod cow;
 
@@ -27,3 +36,30 @@ mod hashset;
 
 #[cfg(feature = "alloc")]
 mod boxed;
+
+#[cfg(feature = "alloc")]
+mod btreemap;
+
+#[cfg(feature = "alloc")]
+mod btreeset;
+
+#[cfg(feature = "alloc")]
+mod vecdeque;
+
+#[cfg(feature = "alloc")]
+mod binaryheap;
+
+#[cfg(feature = "alloc")]
+mod linkedlist;
+
+#[cfg(any(feature = "heapless", feature = "arrayvec"))]
+mod capacity;
+
+#[cfg(feature = "heapless")]
+mod heapless;
+
+#[cfg(feature = "arrayvec")]
+mod arrayvec;
+
+#[cfg(feature = "bytes")]
+mod bytes;
@@ -56,6 +56,8 @@ macro_rules! ImplDekuTraits {
             (Endian, ByteSize, Order)
         );
         ImplDekuTraitsCtx!($typ, $readtype, endian, Endian);
+
+        impl crate::DekuWriterNoSeek for $typ {}
     };
 }
 
@@ -21,8 +21,20 @@ impl<T: DekuWriter<Ctx>, Ctx: Copy> DekuWriter<Ctx> for Option<T> {
         self.as_ref()
             .map_or(Ok(()), |v| v.to_writer(writer, inner_ctx))
     }
+
+    #[cfg(feature = "alloc")]
+    fn to_writer_vectored<'a>(
+        &'a self,
+        writer: &mut Writer<crate::vectored::IoSliceWriter<'a>>,
+        inner_ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.as_ref()
+            .map_or(Ok(()), |v| v.to_writer_vectored(writer, inner_ctx))
+    }
 }
 
+impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for Option<T> {}
+
 #[cfg(test)]
 mod tests {
     use super::*;
@@ -0,0 +1,60 @@
+use core::marker::PhantomData;
+
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::{reader::Reader, writer::Writer, DekuError, DekuReader, DekuWriter};
+
+impl<T: ?Sized, Ctx: Copy> DekuReader<'_, Ctx> for PhantomData<T> {
+    /// NOP on read
+    fn from_reader_with_ctx<R: Read + Seek>(
+        _reader: &mut Reader<R>,
+        _inner_ctx: Ctx,
+    ) -> Result<Self, DekuError> {
+        Ok(PhantomData)
+    }
+}
+
+impl<T: ?Sized, Ctx: Copy> DekuWriter<Ctx> for PhantomData<T> {
+    /// NOP on write
+    fn to_writer<W: Write + Seek>(
+        &self,
+        _writer: &mut Writer<W>,
+        _inner_ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        Ok(())
+    }
+}
+
+impl<T: ?Sized> crate::DekuSize for PhantomData<T> {
+    const SIZE_BITS: usize = 0;
+}
+
+impl<T: ?Sized> crate::DekuWriterNoSeek for PhantomData<T> {}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use crate::reader::Reader;
+    use crate::DekuSize;
+    use std::io::Cursor;
+
+    use super::*;
+
+    #[test]
+    fn test_phantomdata() {
+        let input = &[0xff];
+
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = <PhantomData<u32>>::from_reader_with_ctx(&mut reader, ()).unwrap();
+        assert_eq!(PhantomData, res_read);
+        assert_eq!(0, reader.bits_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, ()).unwrap();
+        assert!(writer.inner.into_inner().is_empty());
+
+        assert_eq!(0, <PhantomData<u32>>::SIZE_BITS);
+        assert_eq!(Some(0), <PhantomData<u32>>::SIZE_BYTES);
+    }
+}
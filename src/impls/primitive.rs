@@ -1,49 +1,220 @@
-use core::convert::TryInto;
-
 #[cfg(feature = "bits")]
 use bitvec::prelude::*;
 use no_std_io::io::{Read, Seek, Write};
 
 use crate::ctx::*;
-use crate::reader::{Reader, ReaderRet};
+#[cfg(feature = "bits")]
+use crate::error::NeedSize;
+use crate::impls::bulk::BulkPrimitive;
+use crate::reader::Reader;
 use crate::writer::Writer;
-use crate::{deku_error, DekuError, DekuReader, DekuWriter};
+use crate::{deku_error, BulkLayout, DekuError, DekuReader, DekuWriter};
+
+/// Two's complement conversion between a signed primitive and the low `bit_size` bits of its
+/// unsigned counterpart, so that signed reads and writes share the unsigned code paths
+trait SignedBits: Sized {
+    type Unsigned;
+
+    /// Sign extend the low `bit_size` bits of `value`
+    fn from_unsigned_bits(value: Self::Unsigned, bit_size: usize) -> Self;
 
-/// "Read" trait: read bits and construct type
+    /// Errors if `self` cannot be represented in `bit_size` bits
+    fn to_unsigned_bits(self, bit_size: usize) -> Result<Self::Unsigned, DekuError>;
+}
+
+/// Big endian `Lsb0` fields wider than a byte keep their trailing partial byte as the least
+/// significant byte of the value, leaving unused bits above it. Signed fields close that gap, so
+/// that the sign bit is bit `bit_size - 1` of the assembled value, the top bit of the first byte.
 #[cfg(feature = "bits")]
-trait DekuRead<'a, Ctx = ()> {
-    /// Read bits and construct type
-    /// * **input** - Input as bits
-    /// * **ctx** - A context required by context-sensitive reading. A unit type `()` means no context
-    ///   needed.
-    ///
-    /// Returns the amount of bits read after parsing in addition to Self.
-    ///
-    /// NOTE: since this is only used internally by primitive types, we don't need to verify the
-    /// size of BitSize or ByteSize to check if they fit in the requested container size
-    /// (size_of::<type>()).
-    fn read(
-        input: &'a crate::bitvec::BitSlice<u8, crate::bitvec::Msb0>,
-        ctx: Ctx,
-    ) -> Result<(usize, Self), DekuError>
-    where
-        Self: Sized;
+#[inline]
+fn is_gapped(endian: Endian, bit_size: usize, order: Order) -> bool {
+    order == Order::Lsb0
+        && !endian.without_word_swap().is_le()
+        && bit_size > 8
+        && !bit_size.is_multiple_of(8)
 }
 
-// specialize u8 for ByteSize
+/// Remove the unused bits above the trailing partial byte of a gapped value
 #[cfg(feature = "bits")]
-impl DekuRead<'_, (Endian, ByteSize)> for u8 {
-    #[inline]
-    fn read(
-        input: &BitSlice<u8, Msb0>,
-        (_, _): (Endian, ByteSize),
-    ) -> Result<(usize, Self), DekuError> {
-        const MAX_TYPE_BITS: usize = BitSize::of::<u8>().0;
+#[inline]
+fn close_gap(value: u128, bit_size: usize) -> u128 {
+    let rem = bit_size % 8;
+    ((value >> 8) << rem) | (value & ((1 << rem) - 1))
+}
+
+/// Move the low `bit_size % 8` bits of `value` to their own byte, the inverse of `close_gap`
+#[cfg(feature = "bits")]
+#[inline]
+fn open_gap(value: u128, bit_size: usize) -> u128 {
+    let rem = bit_size % 8;
+    ((value >> rem) << 8) | (value & ((1 << rem) - 1))
+}
+
+/// Write the low `bit_size.div_ceil(8)` bytes of the big endian `input` in `Lsb0` order, the
+/// trailing partial byte being the low `bit_size % 8` bits of the last byte
+#[cfg(feature = "bits")]
+fn write_be_lsb0<W: Write + Seek>(
+    writer: &mut Writer<W>,
+    input: &[u8],
+    bit_size: usize,
+) -> Result<(), DekuError> {
+    let input_bits = input.view_bits::<Msb0>();
+
+    // Skip the unused leading bytes of the container, the reader
+    // places the value in the last `bit_size.div_ceil(8)` bytes
+    let start = input_bits.len() - bit_size.div_ceil(8) * 8;
+    let mut remaining_bits = bit_size;
+    for chunk in input_bits[start..].chunks(8) {
+        if chunk.len() > remaining_bits {
+            writer.write_bits_order(&chunk[chunk.len() - remaining_bits..], Order::Lsb0)?;
+            break;
+        } else {
+            writer.write_bits_order(chunk, Order::Lsb0)?;
+        }
+        remaining_bits -= chunk.len();
+    }
+    Ok(())
+}
+
+/// Reverse the order of the 16-bit words in the low `bit_size` bits, for the word swapped
+/// [`Endian`] variants. Values of up to 16 bits are left as is.
+#[cfg(feature = "bits")]
+trait WordSwap: Sized {
+    fn swap_words(self, bit_size: usize) -> Result<Self, DekuError>;
+}
+
+fn word_swap_error(bits: usize) -> DekuError {
+    deku_error!(
+        DekuError::InvalidParam,
+        "word swapped endian requires a multiple of 16 bits",
+        "{} bits",
+        bits
+    )
+}
+
+/// Reverse the low `bytes` bytes of `value`
+#[cfg(feature = "bits")]
+#[inline]
+fn swap_low_bytes(value: u128, bytes: usize) -> u128 {
+    if bytes == 0 {
+        0
+    } else {
+        value.swap_bytes() >> (128 - 8 * bytes)
+    }
+}
+
+/// Read a `bit_size` bit primitive with an integer accumulator, returning the bit pattern of the
+/// value right-aligned in a `u128`.
+///
+/// For every combination of [`Endian`] (without word swap) and [`Order`]:
+/// - `Msb0` collects the bits most-significant first. Big-endian is that value, little-endian
+///   takes the whole bytes in stream order with the trailing partial byte as the most
+///   significant.
+/// - `Lsb0` collects the bits least-significant first. Little-endian is that value, big-endian
+///   reverses its bytes.
+#[cfg(feature = "bits")]
+#[inline(always)]
+fn read_bits_uint<R: Read + Seek>(
+    reader: &mut Reader<R>,
+    endian: Endian,
+    bit_size: usize,
+    order: Order,
+) -> Result<u128, DekuError> {
+    let read = |reader: &mut Reader<R>, amt: usize| reader.read_bits_uint(amt, order);
+    let value = if bit_size <= 64 {
+        u128::from(read(reader, bit_size)?)
+    } else {
+        // Wider than the accumulator, read in two halves. Report the full field on EOF.
+        let mut halves = || -> Result<(u64, u64), DekuError> {
+            Ok(match order {
+                Order::Msb0 => {
+                    let hi = read(reader, bit_size - 64)?;
+                    (hi, read(reader, 64)?)
+                }
+                Order::Lsb0 => {
+                    let lo = read(reader, 64)?;
+                    (read(reader, bit_size - 64)?, lo)
+                }
+            })
+        };
+        let (hi, lo) = halves().map_err(|e| match e {
+            DekuError::Incomplete(_) => DekuError::Incomplete(NeedSize::new(bit_size)),
+            e => e,
+        })?;
+        (u128::from(hi) << 64) | u128::from(lo)
+    };
+
+    Ok(match (endian.is_le(), order) {
+        (false, Order::Msb0) | (true, Order::Lsb0) => value,
+        (true, Order::Msb0) => {
+            let rem = bit_size % 8;
+            let full = bit_size / 8;
+            let bytes = swap_low_bytes(value >> rem, full);
+            if rem == 0 {
+                bytes
+            } else {
+                bytes | ((value & ((1 << rem) - 1)) << (8 * full))
+            }
+        }
+        (false, Order::Lsb0) => swap_low_bytes(value, bit_size.div_ceil(8)),
+    })
+}
 
-        // PANIC: We already check that input.len() < bit_size above, so no panic will happen
-        let value = input[..MAX_TYPE_BITS].load::<u8>();
-        Ok((MAX_TYPE_BITS, value))
+/// Reverse the order of the 16-bit words in `bytes`
+fn swap_word_bytes(bytes: &mut [u8]) -> Result<(), DekuError> {
+    if bytes.len() <= 2 {
+        return Ok(());
+    }
+    if !bytes.len().is_multiple_of(2) {
+        return Err(word_swap_error(bytes.len() * 8));
+    }
+    let words = bytes.len() / 2;
+    for i in 0..words / 2 {
+        let j = words - 1 - i;
+        bytes.swap(2 * i, 2 * j);
+        bytes.swap(2 * i + 1, 2 * j + 1);
     }
+    Ok(())
+}
+
+#[cfg(feature = "bits")]
+macro_rules! ImplWordSwap {
+    (u8) => {
+        impl WordSwap for u8 {
+            #[inline]
+            fn swap_words(self, _: usize) -> Result<Self, DekuError> {
+                Ok(self)
+            }
+        }
+    };
+    ($typ:ty) => {
+        impl WordSwap for $typ {
+            #[inline]
+            fn swap_words(self, bit_size: usize) -> Result<Self, DekuError> {
+                if bit_size <= 16 {
+                    return Ok(self);
+                }
+                if !bit_size.is_multiple_of(16) {
+                    return Err(word_swap_error(bit_size));
+                }
+                let words = bit_size / 16;
+                let mut value: $typ = 0;
+                for i in 0..words {
+                    let word = (self >> (16 * i)) & 0xFFFF;
+                    value |= word << (16 * (words - 1 - i));
+                }
+                Ok(value)
+            }
+        }
+    };
+    ($typ:ty, $inner:ty) => {
+        impl WordSwap for $typ {
+            #[inline]
+            fn swap_words(self, bit_size: usize) -> Result<Self, DekuError> {
+                self.to_bits().swap_words(bit_size).map(<$typ>::from_bits)
+            }
+        }
+    };
 }
 
 impl DekuReader<'_, (Endian, ByteSize, Order)> for u8 {
@@ -164,222 +335,6 @@ impl DekuWriter<(Endian, ByteSize)> for u8 {
 
 macro_rules! ImplDekuReadBits {
     ($typ:ty, $inner:ty) => {
-        #[cfg(feature = "bits")]
-        impl DekuRead<'_, (Endian, BitSize, Order)> for $typ {
-            #[inline]
-            fn read(
-                input: &BitSlice<u8, Msb0>,
-                (endian, size, order): (Endian, BitSize, Order),
-            ) -> Result<(usize, Self), DekuError> {
-                const MAX_TYPE_BITS: usize = BitSize::of::<$typ>().0;
-                let bit_size: usize = size.0;
-
-                let input_is_le = endian.is_le();
-
-                // PANIC: We already check that input.len() < bit_size above, so no panic will happen
-                let bit_slice = &input;
-
-                let pad = 8 * bit_slice.len().div_ceil(8) - bit_slice.len();
-                debug_assert!(MAX_TYPE_BITS >= bit_slice.len() + pad);
-
-                // if everything is aligned, just read the value
-                if pad == 0 && bit_slice.len() == MAX_TYPE_BITS && order != Order::Lsb0 {
-                    let bytes = bit_slice.domain().region().unwrap().1;
-
-                    if bytes.len() * 8 == MAX_TYPE_BITS {
-                        // Read value
-                        let value = if input_is_le {
-                            <$typ>::from_le_bytes(bytes.try_into()?)
-                        } else {
-                            <$typ>::from_be_bytes(bytes.try_into()?)
-                        };
-                        return Ok((bit_size, value));
-                    }
-                }
-
-                // Fast path: big-endian, Msb0 bit order.
-                //
-                // The generic paths below zero-extend the value to the container
-                // width by calling `BoundedBitVec::insert(0, false)` once per
-                // padding bit, and each `insert` shifts the entire backing array
-                // right by one bit. That is O(MAX_TYPE_BITS) bit operations per
-                // padding bit, i.e. O(MAX_TYPE_BITS * (MAX_TYPE_BITS - bit_size))
-                // per field -- for a 1-bit field in a u64 that is 63 whole-array
-                // shifts. `load_be` performs the identical zero-extension in one
-                // step. Only Msb0 + big-endian is handled here; the Lsb0 and
-                // little-endian paths keep their existing behaviour.
-                if !input_is_le && order == Order::Msb0 && bit_size > 0 {
-                    let value = bit_slice.load_be::<$inner>();
-                    return Ok((bit_size, <$typ>::from_be_bytes(value.to_be_bytes())));
-                }
-
-                // if read from Lsb order and it's especially cursed since its not just within one byte...
-                // read_bits returned: [0, 0, 0, 1, 0, 0, 1, 0, 0, 0, 1, 1]
-                //                     | second     |    first            |
-                // we want to read from right to left when lsb (without using BitVec BitFields)
-                //
-                // Turning this into [0x23, 0x01] (then appending till type size)
-                debug_assert_eq!(bit_size, bit_slice.len());
-                const MAX_TYPE_BYTES: usize = core::mem::size_of::<$typ>();
-                if order == Order::Lsb0 {
-                    let mut bits = crate::BoundedBitVec::<[u8; MAX_TYPE_BYTES], Msb0>::new();
-
-                    bits.extend_from_bitslice(&bit_slice);
-
-                    for _ in 0..pad {
-                        bits.insert(0, false);
-                    }
-
-                    let mut buf = [0u8; MAX_TYPE_BYTES];
-                    let skip = if input_is_le {
-                        0
-                    } else {
-                        MAX_TYPE_BYTES - bit_slice.len().div_ceil(8)
-                    };
-
-                    for (slice, slot) in bits
-                        .as_bitslice()
-                        .rchunks_exact(8)
-                        .zip(buf.iter_mut().skip(skip))
-                    {
-                        *slot = slice.load_be();
-                    }
-
-                    // Read value
-                    let value = if input_is_le {
-                        <$typ>::from_le_bytes(buf.try_into().unwrap())
-                    } else {
-                        <$typ>::from_be_bytes(buf.try_into().unwrap())
-                    };
-
-                    Ok((bit_size, value))
-                } else {
-                    // Create a new BoundedBitVec from the slice and pad un-aligned chunks
-                    // i.e. [10010110, 1110] -> [10010110, 00001110]
-                    let bits: crate::BoundedBitVec<[u8; MAX_TYPE_BYTES], Msb0> = {
-                        let mut bits = crate::BoundedBitVec::new();
-
-                        // Copy bits to new BoundedBitVec
-                        bits.extend_from_bitslice(&bit_slice);
-
-                        // Some padding to next byte
-                        let index = if input_is_le {
-                            bits.len() - (8 - pad)
-                        } else {
-                            0
-                        };
-                        for _ in 0..pad {
-                            bits.insert(index, false);
-                        }
-
-                        // Pad up-to size of type
-                        for _ in 0..(MAX_TYPE_BITS - bits.len()) {
-                            if input_is_le {
-                                bits.push(false);
-                            } else {
-                                bits.insert(0, false);
-                            }
-                        }
-
-                        bits
-                    };
-                    let bytes = bits.as_raw_slice();
-
-                    // Read value
-                    let value = if input_is_le {
-                        <$typ>::from_le_bytes(bytes.try_into()?)
-                    } else {
-                        <$typ>::from_be_bytes(bytes.try_into()?)
-                    };
-
-                    Ok((bit_size, value))
-                }
-            }
-        }
-
-        #[cfg(feature = "bits")]
-        impl DekuRead<'_, (Endian, BitSize)> for $typ {
-            #[inline(never)]
-            fn read(
-                input: &BitSlice<u8, Msb0>,
-                (endian, size): (Endian, BitSize),
-            ) -> Result<(usize, Self), DekuError> {
-                const MAX_TYPE_BITS: usize = BitSize::of::<$typ>().0;
-                let bit_size: usize = size.0;
-
-                let input_is_le = endian.is_le();
-
-                let bit_slice = &input[..bit_size];
-
-                let pad = 8 * bit_slice.len().div_ceil(8) - bit_slice.len();
-
-                // if everything is aligned, just read the value
-                if pad == 0 && bit_slice.len() == MAX_TYPE_BITS {
-                    let bytes = bit_slice.domain().region().unwrap().1;
-
-                    if bytes.len() * 8 == MAX_TYPE_BITS {
-                        // Read value
-                        let value = if input_is_le {
-                            <$typ>::from_le_bytes(bytes.try_into()?)
-                        } else {
-                            <$typ>::from_be_bytes(bytes.try_into()?)
-                        };
-                        return Ok((bit_size, value));
-                    }
-                }
-
-                // Fast path: big-endian. This impl has no `Order` parameter and
-                // so is always Msb0. See the comment on the same fast path in
-                // the `(Endian, BitSize, Order)` impl above for why the generic
-                // path below is quadratic in the container width.
-                if !input_is_le && bit_size > 0 {
-                    let value = bit_slice.load_be::<$inner>();
-                    return Ok((bit_size, <$typ>::from_be_bytes(value.to_be_bytes())));
-                }
-
-                // Create a new BoundedBitVec from the slice and pad un-aligned chunks
-                // i.e. [10010110, 1110] -> [10010110, 00001110]
-                const MAX_TYPE_BYTES: usize = core::mem::size_of::<$typ>();
-                let bits: crate::BoundedBitVec<[u8; MAX_TYPE_BYTES], Msb0> = {
-                    let mut bits = crate::BoundedBitVec::new();
-
-                    // Copy bits to new BoundedBitVec
-                    bits.extend_from_bitslice(&bit_slice);
-
-                    // Some padding to next byte
-                    let index = if input_is_le {
-                        bits.len() - (8 - pad)
-                    } else {
-                        0
-                    };
-                    for _ in 0..pad {
-                        bits.insert(index, false);
-                    }
-
-                    // Pad up-to size of type
-                    for _ in 0..(MAX_TYPE_BITS - bits.len()) {
-                        if input_is_le {
-                            bits.push(false);
-                        } else {
-                            bits.insert(0, false);
-                        }
-                    }
-
-                    bits
-                };
-
-                let bytes = bits.as_raw_slice();
-
-                // Read value
-                let value = if input_is_le {
-                    <$typ>::from_le_bytes(bytes.try_into()?)
-                } else {
-                    <$typ>::from_be_bytes(bytes.try_into()?)
-                };
-                Ok((bit_size, value))
-            }
-        }
-
         #[cfg(feature = "bits")]
         impl DekuReader<'_, (Endian, BitSize)> for $typ {
             #[inline(always)]
@@ -397,24 +352,20 @@ macro_rules! ImplDekuReadBits {
                         size.0
                     ));
                 }
-                // Fast path: big-endian, and `Order::default()` is Msb0. Reads the
-                // field straight into an integer, with no `BitSlice` in between.
-                if !endian.is_le() && size.0 > 0 && size.0 <= 64 {
-                    let value = reader.read_bits_uint_msb0(size.0)? as $inner;
-                    return Ok(<$typ>::from_be_bytes(value.to_be_bytes()));
+                let value =
+                    read_bits_uint(reader, endian.without_word_swap(), size.0, Order::default())?
+                        as $inner;
+                let value = <$typ>::from_be_bytes(value.to_be_bytes());
+                if endian.is_word_swapped() {
+                    return value.swap_words(size.0);
                 }
-                let mut bits = ::bitvec::array::BitArray::<[u8; { MAX_TYPE_BITS / 8 }], Msb0>::new(
-                    [0; { MAX_TYPE_BITS / 8 }],
-                );
-                reader.read_bits_into(&mut bits[..size.0], Order::default())?;
-                let a = <$typ>::read(&bits[..size.0], (endian, size))?;
-                Ok(a.1)
+                Ok(value)
             }
         }
 
         #[cfg(feature = "bits")]
         impl DekuReader<'_, (Endian, BitSize, Order)> for $typ {
-            #[inline]
+            #[inline(always)]
             fn from_reader_with_ctx<R: Read + Seek>(
                 reader: &mut Reader<R>,
                 (endian, size, order): (Endian, BitSize, Order),
@@ -429,17 +380,13 @@ macro_rules! ImplDekuReadBits {
                         size.0
                     ));
                 }
-                // Fast path: big-endian, Msb0. See the `(Endian, BitSize)` impl above.
-                if !endian.is_le() && order == Order::Msb0 && size.0 > 0 && size.0 <= 64 {
-                    let value = reader.read_bits_uint_msb0(size.0)? as $inner;
-                    return Ok(<$typ>::from_be_bytes(value.to_be_bytes()));
+                let value =
+                    read_bits_uint(reader, endian.without_word_swap(), size.0, order)? as $inner;
+                let value = <$typ>::from_be_bytes(value.to_be_bytes());
+                if endian.is_word_swapped() {
+                    return value.swap_words(size.0);
                 }
-                let mut bits = ::bitvec::array::BitArray::<[u8; { MAX_TYPE_BITS / 8 }], Msb0>::new(
-                    [0; { MAX_TYPE_BITS / 8 }],
-                );
-                reader.read_bits_into(&mut bits[..size.0], order)?;
-                let a = <$typ>::read(&bits[..size.0], (endian, size, order))?;
-                Ok(a.1)
+                Ok(value)
             }
         }
     };
@@ -447,34 +394,6 @@ macro_rules! ImplDekuReadBits {
 
 macro_rules! ImplDekuReadBytes {
     ($typ:ty, $inner:ty) => {
-        /// Ignore order
-        #[cfg(feature = "bits")]
-        impl DekuRead<'_, (Endian, ByteSize, Order)> for $typ {
-            #[inline]
-            fn read(
-                input: &BitSlice<u8, Msb0>,
-                (endian, size, order): (Endian, ByteSize, Order),
-            ) -> Result<(usize, Self), DekuError> {
-                <$typ as DekuRead<'_, (Endian, BitSize, Order)>>::read(
-                    input,
-                    (endian, BitSize(size.0 * 8), order),
-                )
-            }
-        }
-
-        #[cfg(feature = "bits")]
-        impl DekuRead<'_, (Endian, ByteSize)> for $typ {
-            #[inline(never)]
-            fn read(
-                input: &BitSlice<u8, Msb0>,
-                (endian, size): (Endian, ByteSize),
-            ) -> Result<(usize, Self), DekuError> {
-                let bit_size = BitSize(size.0 * 8);
-
-                <$typ>::read(input, (endian, bit_size))
-            }
-        }
-
         impl DekuReader<'_, (Endian, ByteSize, Order)> for $typ {
             #[inline(always)]
             fn from_reader_with_ctx<R: Read + Seek>(
@@ -492,31 +411,20 @@ macro_rules! ImplDekuReadBytes {
                     ));
                 }
                 let mut buf = [0; MAX_TYPE_BYTES];
-                let ret = reader.read_bytes(size.0, &mut buf, order)?;
-                let a = match ret {
-                    ReaderRet::Bytes => {
-                        if endian.is_le() {
-                            <$typ>::from_le_bytes(buf.try_into().unwrap())
-                        } else {
-                            if size.0 != core::mem::size_of::<$typ>() {
-                                let padding = core::mem::size_of::<$typ>() - size.0;
-                                buf.copy_within(0..size.0, padding);
-                                buf[..padding].fill(0x00);
-                            }
-                            <$typ>::from_be_bytes(buf.try_into().unwrap())
-                        }
-                    }
-                    #[cfg(all(feature = "bits", feature = "alloc"))]
-                    ReaderRet::Bits(Some(bits)) => {
-                        let a = <$typ>::read(&bits, (endian, size, order))?;
-                        a.1
-                    }
-                    #[cfg(all(feature = "bits", feature = "alloc"))]
-                    ReaderRet::Bits(None) => {
-                        return Err(deku_error!(DekuError::Parse, "no bits read from reader"));
+                reader.read_bytes(size.0, &mut buf, order)?;
+                if endian.is_word_swapped() {
+                    swap_word_bytes(&mut buf[..size.0])?;
+                }
+                if endian.without_word_swap().is_le() {
+                    Ok(<$typ>::from_le_bytes(buf))
+                } else {
+                    if size.0 != MAX_TYPE_BYTES {
+                        let padding = MAX_TYPE_BYTES - size.0;
+                        buf.copy_within(0..size.0, padding);
+                        buf[..padding].fill(0x00);
                     }
-                };
-                Ok(a)
+                    Ok(<$typ>::from_be_bytes(buf))
+                }
             }
         }
     };
@@ -524,77 +432,6 @@ macro_rules! ImplDekuReadBytes {
 
 macro_rules! ImplDekuReadSignExtend {
     ($typ:ty, $inner:ty) => {
-        // Ignore Order, send back
-        #[cfg(feature = "bits")]
-        impl DekuRead<'_, (Endian, ByteSize, Order)> for $typ {
-            #[inline]
-            fn read(
-                input: &BitSlice<u8, Msb0>,
-                (endian, size, order): (Endian, ByteSize, Order),
-            ) -> Result<(usize, Self), DekuError> {
-                <$typ as DekuRead<'_, (Endian, BitSize, Order)>>::read(
-                    input,
-                    (endian, BitSize(size.0 * 8), order),
-                )
-            }
-        }
-
-        #[cfg(feature = "bits")]
-        impl DekuRead<'_, (Endian, BitSize, Order)> for $typ {
-            #[inline]
-            fn read(
-                input: &BitSlice<u8, Msb0>,
-                (endian, size, order): (Endian, BitSize, Order),
-            ) -> Result<(usize, Self), DekuError> {
-                let (amt_read, value) = <$inner as DekuRead<'_, (Endian, BitSize, Order)>>::read(
-                    input,
-                    (endian, size, order),
-                )?;
-
-                const MAX_TYPE_BITS: usize = BitSize::of::<$typ>().0;
-                let bit_size = size.0;
-                let shift = MAX_TYPE_BITS - bit_size;
-                let value = (value as $typ) << shift >> shift;
-                Ok((amt_read, value))
-            }
-        }
-
-        #[cfg(feature = "bits")]
-        impl DekuRead<'_, (Endian, ByteSize)> for $typ {
-            #[inline(never)]
-            fn read(
-                input: &BitSlice<u8, Msb0>,
-                (endian, size): (Endian, ByteSize),
-            ) -> Result<(usize, Self), DekuError> {
-                let (amt_read, value) =
-                    <$inner as DekuRead<'_, (Endian, ByteSize)>>::read(input, (endian, size))?;
-
-                const MAX_TYPE_BITS: usize = BitSize::of::<$typ>().0;
-                let bit_size = size.0 * 8;
-                let shift = MAX_TYPE_BITS - bit_size;
-                let value = (value as $typ) << shift >> shift;
-                Ok((amt_read, value))
-            }
-        }
-
-        #[cfg(feature = "bits")]
-        impl DekuRead<'_, (Endian, BitSize)> for $typ {
-            #[inline(never)]
-            fn read(
-                input: &BitSlice<u8, Msb0>,
-                (endian, size): (Endian, BitSize),
-            ) -> Result<(usize, Self), DekuError> {
-                let (amt_read, value) =
-                    <$inner as DekuRead<'_, (Endian, BitSize)>>::read(input, (endian, size))?;
-
-                const MAX_TYPE_BITS: usize = BitSize::of::<$typ>().0;
-                let bit_size = size.0;
-                let shift = MAX_TYPE_BITS - bit_size;
-                let value = (value as $typ) << shift >> shift;
-                Ok((amt_read, value))
-            }
-        }
-
         #[cfg(feature = "bits")]
         impl DekuReader<'_, (Endian, BitSize)> for $typ {
             #[inline(always)]
@@ -623,13 +460,11 @@ macro_rules! ImplDekuReadSignExtend {
                         size.0
                     ));
                 }
-                let mut bits = ::bitvec::array::BitArray::<[u8; { MAX_TYPE_BITS / 8 }], Msb0>::new(
-                    [0; { MAX_TYPE_BITS / 8 }],
-                );
-
-                reader.read_bits_into(&mut bits[..size.0], order)?;
-                let a = <$typ>::read(&bits[..size.0], (endian, size, order))?;
-                Ok(a.1)
+                let mut value = <$inner>::from_reader_with_ctx(reader, (endian, size, order))?;
+                if is_gapped(endian, size.0, order) {
+                    value = close_gap(value as u128, size.0) as $inner;
+                }
+                Ok(<$typ>::from_unsigned_bits(value, size.0))
             }
         }
 
@@ -650,31 +485,22 @@ macro_rules! ImplDekuReadSignExtend {
                     ));
                 }
                 let mut buf = [0; MAX_TYPE_BYTES];
-                let ret = reader.read_bytes(size.0, &mut buf, order)?;
-                let a = match ret {
-                    ReaderRet::Bytes => {
-                        if endian.is_le() {
-                            <$typ>::from_le_bytes(buf.try_into().unwrap())
-                        } else {
-                            if size.0 != core::mem::size_of::<$typ>() {
-                                let padding = core::mem::size_of::<$typ>() - size.0;
-                                buf.copy_within(0..size.0, padding);
-                                buf[..padding].fill(0x00);
-                            }
-                            <$typ>::from_be_bytes(buf.try_into().unwrap())
-                        }
-                    }
-                    #[cfg(all(feature = "bits", feature = "alloc"))]
-                    ReaderRet::Bits(Some(bits)) => {
-                        let a = <$typ>::read(&bits, (endian, size, order))?;
-                        a.1
-                    }
-                    #[cfg(all(feature = "bits", feature = "alloc"))]
-                    ReaderRet::Bits(None) => {
-                        return Err(deku_error!(DekuError::Parse, "no bits read from reader"));
+                reader.read_bytes(size.0, &mut buf, order)?;
+                if endian.is_word_swapped() {
+                    swap_word_bytes(&mut buf[..size.0])?;
+                }
+                let value = if endian.without_word_swap().is_le() {
+                    <$typ>::from_le_bytes(buf)
+                } else {
+                    if size.0 != MAX_TYPE_BYTES {
+                        let padding = MAX_TYPE_BYTES - size.0;
+                        buf.copy_within(0..size.0, padding);
+                        buf[..padding].fill(0x00);
                     }
+                    <$typ>::from_be_bytes(buf)
                 };
-                Ok(a)
+                // the bytes above `size` were zero filled, sign extend into them
+                Ok(<$typ>::from_unsigned_bits(value as $inner, size.0 * 8))
             }
         }
     };
@@ -682,15 +508,24 @@ macro_rules! ImplDekuReadSignExtend {
 
 macro_rules! ForwardDekuRead {
     ($typ:ty) => {
+        // The whole type, specialize and use read_bytes_const
         impl DekuReader<'_, (Endian, Order)> for $typ {
-            #[inline]
+            #[inline(always)]
             fn from_reader_with_ctx<R: Read + Seek>(
                 reader: &mut Reader<R>,
                 (endian, order): (Endian, Order),
             ) -> Result<$typ, DekuError> {
-                let byte_size = core::mem::size_of::<$typ>();
-
-                <$typ>::from_reader_with_ctx(reader, (endian, ByteSize(byte_size), order))
+                const MAX_TYPE_BYTES: usize = core::mem::size_of::<$typ>();
+                let mut buf = [0; MAX_TYPE_BYTES];
+                reader.read_bytes_const_into::<MAX_TYPE_BYTES>(&mut buf, order)?;
+                if endian.is_word_swapped() {
+                    swap_word_bytes(&mut buf)?;
+                }
+                if endian.without_word_swap().is_le() {
+                    Ok(<$typ>::from_le_bytes(buf))
+                } else {
+                    Ok(<$typ>::from_be_bytes(buf))
+                }
             }
         }
 
@@ -704,21 +539,19 @@ macro_rules! ForwardDekuRead {
             }
         }
 
-        // Only have `endian`, specialize and use read_bytes_const
+        // Only have `endian`, set `order` to `Order::default`.
         impl DekuReader<'_, Endian> for $typ {
             #[inline(always)]
             fn from_reader_with_ctx<R: Read + Seek>(
                 reader: &mut Reader<R>,
                 endian: Endian,
             ) -> Result<$typ, DekuError> {
-                const MAX_TYPE_BYTES: usize = core::mem::size_of::<$typ>();
-                let mut buf = [0; MAX_TYPE_BYTES];
-                reader.read_bytes_const_into::<MAX_TYPE_BYTES>(&mut buf, Order::default())?;
-                if endian.is_le() {
-                    Ok(<$typ>::from_le_bytes(buf))
-                } else {
-                    Ok(<$typ>::from_be_bytes(buf))
-                }
+                <$typ>::from_reader_with_ctx(reader, (endian, Order::default()))
+            }
+
+            #[inline(always)]
+            fn bulk_layout(endian: Endian) -> Option<BulkLayout<Self>> {
+                BulkLayout::of_endian(endian)
             }
         }
 
@@ -790,6 +623,11 @@ macro_rules! ForwardDekuRead {
             ) -> Result<$typ, DekuError> {
                 <$typ>::from_reader_with_ctx(reader, Endian::default())
             }
+
+            #[inline(always)]
+            fn bulk_layout(_: ()) -> Option<BulkLayout<Self>> {
+                BulkLayout::of_endian(Endian::default())
+            }
         }
     };
 }
@@ -804,9 +642,15 @@ macro_rules! ImplDekuWrite {
                 writer: &mut Writer<W>,
                 (endian, size, order): (Endian, BitSize, Order),
             ) -> Result<(), DekuError> {
-                let input = match endian {
-                    Endian::Little => self.to_le_bytes(),
-                    Endian::Big => self.to_be_bytes(),
+                if endian.is_word_swapped() {
+                    let value = self.swap_words(size.0)?;
+                    return value.to_writer(writer, (endian.without_word_swap(), size, order));
+                }
+
+                let input = if endian.is_le() {
+                    self.to_le_bytes()
+                } else {
+                    self.to_be_bytes()
                 };
 
                 let bit_size: usize = size.0;
@@ -823,8 +667,9 @@ macro_rules! ImplDekuWrite {
                     ));
                 }
 
+                // word swapped endians were handled above
                 match (endian, order) {
-                    (Endian::Little, Order::Lsb0) | (Endian::Little, Order::Msb0) => {
+                    (Endian::Little, _) => {
                         let input_bits_lsb = input.view_bits::<Lsb0>();
                         if let Some(last) = input_bits_lsb.last_one() {
                             let last = last + 1;
@@ -854,7 +699,7 @@ macro_rules! ImplDekuWrite {
                             remaining_bits -= chunk.len();
                         }
                     }
-                    (Endian::Big, Order::Lsb0) => {
+                    (_, Order::Lsb0) => {
                         const MAX_TYPE_BITS: usize = BitSize::of::<$typ>().0;
                         if let Some(first) = input_bits.first_one() {
                             let max = MAX_TYPE_BITS - bit_size;
@@ -875,22 +720,24 @@ macro_rules! ImplDekuWrite {
                                 order,
                             )?;
                         } else {
-                            let mut remaining_bits = bit_size;
-                            for chunk in input_bits.chunks(8) {
-                                if chunk.len() > remaining_bits {
-                                    writer.write_bits_order(
-                                        &chunk[chunk.len() - remaining_bits..],
-                                        order,
-                                    )?;
-                                    break;
-                                } else {
-                                    writer.write_bits_order(&chunk, order)?;
-                                }
-                                remaining_bits -= chunk.len();
+                            // Only the low `bit_size % 8` bits of the last byte are written,
+                            // anything above them would be silently dropped
+                            let partial = bit_size % 8;
+                            let last = input[input.len() - 1];
+                            if partial != 0 && (last >> partial) != 0 {
+                                return Err(deku_error!(
+                                    DekuError::InvalidParam,
+                                    "big endian lsb input does not fit in requested size",
+                                    "last byte {:#04x} exceeds {} bits",
+                                    last,
+                                    partial
+                                ));
                             }
+
+                            write_be_lsb0(writer, &input, bit_size)?;
                         }
                     }
-                    (Endian::Big, Order::Msb0) => {
+                    (_, Order::Msb0) => {
                         const MAX_TYPE_BITS: usize = BitSize::of::<$typ>().0;
 
                         // Fast path, ahead of the bit-slice scan. Significant-bit
@@ -953,7 +800,12 @@ macro_rules! ImplDekuWrite {
         }
 
         ImplDekuWriteDetails!($typ, $signed_type);
+        ImplDekuWriteBytes!($typ);
+    };
+}
 
+macro_rules! ImplDekuWriteBytes {
+    ($typ:ty) => {
         impl DekuWriter<(Endian, ByteSize)> for $typ {
             #[inline(always)]
             fn to_writer<W: Write + Seek>(
@@ -961,9 +813,10 @@ macro_rules! ImplDekuWrite {
                 writer: &mut Writer<W>,
                 (endian, size): (Endian, ByteSize),
             ) -> Result<(), DekuError> {
-                let input = match endian {
-                    Endian::Little => self.to_le_bytes(),
-                    Endian::Big => self.to_be_bytes(),
+                let mut input = if endian.without_word_swap().is_le() {
+                    self.to_le_bytes()
+                } else {
+                    self.to_be_bytes()
                 };
 
                 const TYPE_SIZE: usize = core::mem::size_of::<$typ>();
@@ -977,13 +830,16 @@ macro_rules! ImplDekuWrite {
                     ));
                 }
 
-                let input = if matches!(endian, Endian::Big) {
-                    &input[TYPE_SIZE - size.0 as usize..]
+                let input = if endian.without_word_swap().is_be() {
+                    &mut input[TYPE_SIZE - size.0 as usize..]
                 } else {
-                    &input[..size.0 as usize]
+                    &mut input[..size.0 as usize]
                 };
+                if endian.is_word_swapped() {
+                    swap_word_bytes(input)?;
+                }
 
-                writer.write_bytes(&input)?;
+                writer.write_bytes(input)?;
                 Ok(())
             }
         }
@@ -1012,9 +868,15 @@ macro_rules! ImplDekuWriteDetails {
                 writer: &mut Writer<W>,
                 (endian, size): (Endian, BitSize),
             ) -> Result<(), DekuError> {
-                let input = match endian {
-                    Endian::Little => self.to_le_bytes(),
-                    Endian::Big => self.to_be_bytes(),
+                if endian.is_word_swapped() {
+                    let value = self.swap_words(size.0)?;
+                    return value.to_writer(writer, (endian.without_word_swap(), size));
+                }
+
+                let input = if endian.is_le() {
+                    self.to_le_bytes()
+                } else {
+                    self.to_be_bytes()
                 };
 
                 let bit_size: usize = size.0;
@@ -1084,116 +946,121 @@ macro_rules! ImplDekuWriteDetails {
             }
         }
     };
-    ($typ:ty, Signed) => {
-        #[cfg(feature = "bits")]
-        impl DekuWriter<(Endian, BitSize)> for $typ {
-            #[inline(always)]
-            fn to_writer<W: Write + Seek>(
-                &self,
-                writer: &mut Writer<W>,
-                (endian, size): (Endian, BitSize),
-            ) -> Result<(), DekuError> {
-                let input = match endian {
-                    Endian::Little => self.to_le_bytes(),
-                    Endian::Big => self.to_be_bytes(),
-                };
+}
 
-                let bit_size: usize = size.0;
+macro_rules! ImplSignedBits {
+    ($typ:ty, $inner:ty) => {
+        impl SignedBits for $typ {
+            type Unsigned = $inner;
 
-                let input_bits = input.view_bits::<Msb0>();
+            #[inline]
+            fn from_unsigned_bits(value: $inner, bit_size: usize) -> Self {
+                const MAX_TYPE_BITS: usize = BitSize::of::<$typ>().0;
+                if bit_size == 0 {
+                    return 0;
+                }
+                let shift = MAX_TYPE_BITS - bit_size;
+                (value as $typ) << shift >> shift
+            }
 
-                if bit_size > input_bits.len() {
+            #[inline]
+            fn to_unsigned_bits(self, bit_size: usize) -> Result<$inner, DekuError> {
+                const MAX_TYPE_BITS: usize = BitSize::of::<$typ>().0;
+                if bit_size > MAX_TYPE_BITS {
                     return Err(deku_error!(
                         DekuError::InvalidParam,
                         "bit size is larger than input",
                         "{} exceeds {}",
                         bit_size,
-                        input_bits.len()
+                        MAX_TYPE_BITS
                     ));
                 }
 
-                if matches!(endian, Endian::Little) {
-                    // Check if this is a value that will fit inside the required bits, if
-                    // not, throw an error
-                    if *self >= 0 {
-                        let input_bits_lsb = input.view_bits::<Lsb0>();
-                        if let Some(last) = input_bits_lsb.last_one() {
-                            let last = last + 2;
-                            let max = bit_size;
-                            if last > max {
-                                return Err(deku_error!(
-                                    DekuError::InvalidParam,
-                                    "bit size of input is larger than bit requested size",
-                                    "{} exceeds {}",
-                                    last,
-                                    bit_size
-                                ));
-                            }
-                        }
-                    } else {
-                        let input_bits_lsb = input.view_bits::<Lsb0>();
-                        if let Some(last) = input_bits_lsb.last_zero() {
-                            let last = last + 2;
-                            let max = bit_size;
-                            if last > max {
-                                return Err(deku_error!(
-                                    DekuError::InvalidParam,
-                                    "bit size of input is larger than requested bit size",
-                                    "{} exceeds {}",
-                                    last,
-                                    bit_size
-                                ));
-                            }
-                        }
-                    }
-
-                    // Example read 10 bits u32 [0xAB, 0b11_000000]
-                    // => [10101011, 00000011, 00000000, 00000000]
-                    let mut remaining_bits = bit_size;
-                    for chunk in input_bits.chunks(8) {
-                        if chunk.len() > remaining_bits {
-                            writer.write_bits(&chunk[chunk.len() - remaining_bits..])?;
-                            break;
-                        } else {
-                            writer.write_bits(&chunk)?;
-                        }
-                        remaining_bits -= chunk.len();
-                    }
+                // bits needed for `self` in two's complement, including the sign bit
+                let significant = if self == 0 {
+                    0
                 } else {
-                    const MAX_TYPE_BITS: usize = BitSize::of::<$typ>().0;
-                    // Check for extra bits before sending into writer
-                    if *self >= 0 {
-                        if let Some(first) = input_bits.first_one() {
-                            let max = (MAX_TYPE_BITS - bit_size);
-                            if max + 1 > first {
-                                return Err(deku_error!(
-                                    DekuError::InvalidParam,
-                                    "bit size of input is larger than bit requested size",
-                                    "{} exceeds {}",
-                                    MAX_TYPE_BITS - first,
-                                    bit_size
-                                ));
-                            }
-                        }
+                    let magnitude = self ^ (self >> (MAX_TYPE_BITS - 1));
+                    MAX_TYPE_BITS - (magnitude.leading_zeros() as usize) + 1
+                };
+                if significant > bit_size {
+                    return Err(deku_error!(
+                        DekuError::InvalidParam,
+                        "bit size of input is larger than requested size",
+                        "{} exceeds {}",
+                        significant,
+                        bit_size
+                    ));
+                }
+
+                if bit_size == 0 {
+                    return Ok(0);
+                }
+                Ok((self as $inner) & (<$inner>::MAX >> (MAX_TYPE_BITS - bit_size)))
+            }
+        }
+    };
+}
+
+macro_rules! ImplDekuWriteSignExtend {
+    ($typ:ty, $inner:ty) => {
+        #[cfg(feature = "bits")]
+        impl DekuWriter<(Endian, BitSize, Order)> for $typ {
+            #[inline]
+            fn to_writer<W: Write + Seek>(
+                &self,
+                writer: &mut Writer<W>,
+                (endian, size, order): (Endian, BitSize, Order),
+            ) -> Result<(), DekuError> {
+                let value = self.to_unsigned_bits(size.0)?;
+                if is_gapped(endian, size.0, order) {
+                    let value = if endian.is_word_swapped() {
+                        // leaves up to 16 bits as is, and fails on wider gapped fields
+                        value.swap_words(size.0)?
                     } else {
-                        if let Some(first) = input_bits.first_zero() {
-                            let max = (MAX_TYPE_BITS - bit_size);
-                            if max + 1 > first {
-                                return Err(deku_error!(
-                                    DekuError::InvalidParam,
-                                    "bit size of input is larger than bit requested size",
-                                    "{} exceeds {}",
-                                    MAX_TYPE_BITS - first,
-                                    bit_size
-                                ));
-                            }
-                        }
-                    }
-                    // Example read 10 bits u32 [0xAB, 0b11_000000]
-                    // => [00000000, 00000000, 00000010, 10101111]
-                    writer.write_bits(&input_bits[input_bits.len() - bit_size..])?;
+                        value
+                    };
+                    let value = open_gap(value as u128, size.0) as $inner;
+                    return write_be_lsb0(writer, &value.to_be_bytes(), size.0);
                 }
-                Ok(())
+                value.to_writer(writer, (endian, size, order))
+            }
+        }
+
+        #[cfg(feature = "bits")]
+        impl DekuWriter<(Endian, BitSize)> for $typ {
+            #[inline(always)]
+            fn to_writer<W: Write + Seek>(
+                &self,
+                writer: &mut Writer<W>,
+                (endian, size): (Endian, BitSize),
+            ) -> Result<(), DekuError> {
+                let value = self.to_unsigned_bits(size.0)?;
+                value.to_writer(writer, (endian, size))
+            }
+        }
+
+        impl DekuWriter<(Endian, ByteSize)> for $typ {
+            #[inline(always)]
+            fn to_writer<W: Write + Seek>(
+                &self,
+                writer: &mut Writer<W>,
+                (endian, size): (Endian, ByteSize),
+            ) -> Result<(), DekuError> {
+                let value = self.to_unsigned_bits(size.0.saturating_mul(8))?;
+                value.to_writer(writer, (endian, size))
+            }
+        }
+
+        /// When using Endian and ByteSize, Order is not used
+        impl DekuWriter<(Endian, ByteSize, Order)> for $typ {
+            #[inline]
+            fn to_writer<W: Write + Seek>(
+                &self,
+                writer: &mut Writer<W>,
+                (endian, size, _order): (Endian, ByteSize, Order),
+            ) -> Result<(), DekuError> {
+                <$typ>::to_writer(self, writer, (endian, size))
             }
         }
     };
@@ -1208,13 +1075,22 @@ macro_rules! ImplDekuWriteOnlyEndian {
                 writer: &mut Writer<W>,
                 endian: Endian,
             ) -> Result<(), DekuError> {
-                let input = match endian {
-                    Endian::Little => self.to_le_bytes(),
-                    Endian::Big => self.to_be_bytes(),
+                let mut input = if endian.without_word_swap().is_le() {
+                    self.to_le_bytes()
+                } else {
+                    self.to_be_bytes()
                 };
+                if endian.is_word_swapped() {
+                    swap_word_bytes(&mut input)?;
+                }
                 writer.write_bytes(&input)?;
                 Ok(())
             }
+
+            #[inline(always)]
+            fn bulk_layout(endian: Endian) -> Option<BulkLayout<Self>> {
+                BulkLayout::of_endian(endian)
+            }
         }
     };
 }
@@ -1288,6 +1164,11 @@ macro_rules! ForwardDekuWrite {
             ) -> Result<(), DekuError> {
                 <$typ>::to_writer(self, writer, Endian::default())
             }
+
+            #[inline(always)]
+            fn bulk_layout(_: ()) -> Option<BulkLayout<Self>> {
+                BulkLayout::of_endian(Endian::default())
+            }
         }
     };
 }
@@ -1321,15 +1202,49 @@ macro_rules! ImplDekuTraitsUnsigned {
 
 macro_rules! ImplDekuTraitsSigned {
     ($typ:ty, $inner:ty) => {
+        ImplSignedBits!($typ, $inner);
         ImplDekuReadSignExtend!($typ, $inner);
         ForwardDekuRead!($typ);
 
-        ImplDekuWrite!($typ, Signed);
+        ImplDekuWriteSignExtend!($typ, $inner);
         ImplDekuWriteOnlyEndian!($typ);
         ForwardDekuWrite!($typ);
     };
 }
 
+#[cfg(feature = "bits")]
+ImplWordSwap!(u8);
+#[cfg(feature = "bits")]
+ImplWordSwap!(u16);
+#[cfg(feature = "bits")]
+ImplWordSwap!(u32);
+#[cfg(feature = "bits")]
+ImplWordSwap!(u64);
+#[cfg(feature = "bits")]
+ImplWordSwap!(u128);
+#[cfg(feature = "bits")]
+ImplWordSwap!(usize);
+#[cfg(feature = "bits")]
+ImplWordSwap!(f32, u32);
+#[cfg(feature = "bits")]
+ImplWordSwap!(f64, u64);
+
+// SAFETY: integers and floats are valid for any bit pattern and have no padding
+unsafe impl BulkPrimitive for u8 {}
+unsafe impl BulkPrimitive for u16 {}
+unsafe impl BulkPrimitive for u32 {}
+unsafe impl BulkPrimitive for u64 {}
+unsafe impl BulkPrimitive for u128 {}
+unsafe impl BulkPrimitive for usize {}
+unsafe impl BulkPrimitive for i8 {}
+unsafe impl BulkPrimitive for i16 {}
+unsafe impl BulkPrimitive for i32 {}
+unsafe impl BulkPrimitive for i64 {}
+unsafe impl BulkPrimitive for i128 {}
+unsafe impl BulkPrimitive for isize {}
+unsafe impl BulkPrimitive for f32 {}
+unsafe impl BulkPrimitive for f64 {}
+
 ImplDekuTraitsUnsigned!(u8);
 ImplDekuTraitsUnsigned!(u16);
 ImplDekuTraitsBytesUnsigned!(u16);
@@ -1381,6 +1296,16 @@ ImplDekuSize!(isize);
 ImplDekuSize!(f32);
 ImplDekuSize!(f64);
 
+macro_rules! ImplDekuWriterNoSeek {
+    ($($typ:ty),*) => {
+        $(impl crate::DekuWriterNoSeek for $typ {})*
+    };
+}
+
+ImplDekuWriterNoSeek!(u8, u16, u32, u64, u128, usize);
+ImplDekuWriterNoSeek!(i8, i16, i32, i64, i128, isize);
+ImplDekuWriterNoSeek!(f32, f64);
+
 #[cfg(feature = "std")]
 #[cfg(test)]
 mod tests {
@@ -1712,4 +1637,481 @@ mod tests {
     TestSignExtendingPanic!(test_sign_extend_i64_panic, i64, 64);
     #[cfg(feature = "bits")]
     TestSignExtendingPanic!(test_sign_extend_i128_panic, i128, 128);
+
+    /// Write `value` as `bit_size` bits after `offset` bits of padding, then read it back
+    #[cfg(feature = "bits")]
+    fn signed_round_trip<T>(
+        value: T,
+        endian: Endian,
+        bit_size: usize,
+        order: Order,
+        offset: usize,
+    ) -> Result<T, DekuError>
+    where
+        T: DekuWriter<(Endian, BitSize, Order)>
+            + DekuWriter<(Endian, BitSize)>
+            + DekuWriter<(Endian, ByteSize, Order)>
+            + for<'a> DekuReader<'a, (Endian, BitSize, Order)>
+            + for<'a> DekuReader<'a, (Endian, ByteSize)>
+            + PartialEq
+            + core::fmt::Debug
+            + Copy,
+    {
+        let padding = bitvec![u8, Msb0; 1; offset];
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        writer.write_bits_order(&padding, order)?;
+        value.to_writer(&mut writer, (endian, BitSize(bit_size), order))?;
+        writer.finalize()?;
+        let out = writer.inner.into_inner();
+
+        // the derive uses `(Endian, BitSize)` when no bit_order is given
+        if order == Order::Msb0 {
+            let mut writer = Writer::new(Cursor::new(vec![]));
+            writer.write_bits(&padding)?;
+            value.to_writer(&mut writer, (endian, BitSize(bit_size)))?;
+            writer.finalize()?;
+            assert_eq!(out, writer.inner.into_inner());
+        }
+
+        // byte sized fields are read and written without going through the bit reader
+        if order == Order::Msb0 && offset == 0 && bit_size.is_multiple_of(8) {
+            let mut writer = Writer::new(Cursor::new(vec![]));
+            value.to_writer(&mut writer, (endian, ByteSize(bit_size / 8), order))?;
+            assert_eq!(out, writer.inner.into_inner());
+
+            let mut cursor = Cursor::new(&out);
+            let mut reader = Reader::new(&mut cursor);
+            let res = T::from_reader_with_ctx(&mut reader, (endian, ByteSize(bit_size / 8)))?;
+            assert_eq!(value, res);
+        }
+
+        let mut cursor = Cursor::new(&out);
+        let mut reader = Reader::new(&mut cursor);
+        reader.skip_bits(offset, order)?;
+        T::from_reader_with_ctx(&mut reader, (endian, BitSize(bit_size), order))
+    }
+
+    /// Round trip signed values in every bit size, all of them up to `$exhaustive_bits` bits
+    #[cfg(feature = "bits")]
+    macro_rules! TestSignedMatrix {
+        ($test_name:ident, $typ:ty, $exhaustive_bits:expr) => {
+            #[rstest(
+                endian,
+                order,
+                offset,
+                case::le_msb0(Endian::Little, Order::Msb0, 0),
+                case::le_msb0_unaligned(Endian::Little, Order::Msb0, 3),
+                case::le_lsb0(Endian::Little, Order::Lsb0, 0),
+                case::le_lsb0_unaligned(Endian::Little, Order::Lsb0, 3),
+                case::be_msb0(Endian::Big, Order::Msb0, 0),
+                case::be_msb0_unaligned(Endian::Big, Order::Msb0, 3),
+                case::be_lsb0(Endian::Big, Order::Lsb0, 0),
+                case::be_lsb0_unaligned(Endian::Big, Order::Lsb0, 3)
+            )]
+            fn $test_name(endian: Endian, order: Order, offset: usize) {
+                const MAX_TYPE_BITS: usize = BitSize::of::<$typ>().0;
+                for bit_size in 1..=MAX_TYPE_BITS {
+                    let shift = MAX_TYPE_BITS - bit_size;
+                    let min = <$typ>::MIN >> shift;
+                    let max = <$typ>::MAX >> shift;
+
+                    let alternating = <$typ>::from_unsigned_bits(
+                        <$typ as SignedBits>::Unsigned::MAX / 3,
+                        bit_size,
+                    );
+                    let values: Vec<$typ> = if bit_size <= $exhaustive_bits {
+                        (min..=max).collect()
+                    } else {
+                        let samples = [
+                            min,
+                            min / 2,
+                            -1,
+                            0,
+                            1,
+                            max / 2,
+                            max,
+                            alternating,
+                            !alternating,
+                        ];
+                        samples
+                            .into_iter()
+                            .filter(|v| (min..=max).contains(v))
+                            .collect()
+                    };
+
+                    for value in values {
+                        let res = signed_round_trip(value, endian, bit_size, order, offset);
+                        assert_eq!(Ok(value), res, "{} bits", bit_size);
+                    }
+
+                    // out of range values must fail instead of being truncated
+                    if bit_size < MAX_TYPE_BITS {
+                        for value in [min - 1, max + 1, <$typ>::MIN, <$typ>::MAX] {
+                            let res = signed_round_trip(value, endian, bit_size, order, offset);
+                            assert!(
+                                matches!(res, Err(DekuError::InvalidParam(_))),
+                                "{} in {} bits: {:?}",
+                                value,
+                                bit_size,
+                                res
+                            );
+
+                            if bit_size.is_multiple_of(8) {
+                                let mut writer = Writer::new(Cursor::new(vec![]));
+                                let res = value.to_writer(
+                                    &mut writer,
+                                    (endian, ByteSize(bit_size / 8), order),
+                                );
+                                assert!(
+                                    matches!(res, Err(DekuError::InvalidParam(_))),
+                                    "{} in {} bytes: {:?}",
+                                    value,
+                                    bit_size / 8,
+                                    res
+                                );
+                            }
+                        }
+                    }
+                }
+            }
+        };
+    }
+
+    #[cfg(feature = "bits")]
+    TestSignedMatrix!(test_signed_matrix_i8, i8, 8);
+    #[cfg(feature = "bits")]
+    TestSignedMatrix!(test_signed_matrix_i16, i16, 12);
+    #[cfg(feature = "bits")]
+    TestSignedMatrix!(test_signed_matrix_i32, i32, 0);
+    #[cfg(feature = "bits")]
+    TestSignedMatrix!(test_signed_matrix_i64, i64, 0);
+    #[cfg(feature = "bits")]
+    TestSignedMatrix!(test_signed_matrix_i128, i128, 0);
+    #[cfg(feature = "bits")]
+    TestSignedMatrix!(test_signed_matrix_isize, isize, 0);
+
+    #[cfg(feature = "bits")]
+    #[rstest(input, endian, order, expected,
+        case::msb0_le([0b11101_000].as_ref(), Endian::Little, Order::Msb0, -3),
+        case::msb0_be([0b11101_000].as_ref(), Endian::Big, Order::Msb0, -3),
+        case::lsb0_le([0b000_11101].as_ref(), Endian::Little, Order::Lsb0, -3),
+        case::lsb0_be([0b000_11101].as_ref(), Endian::Big, Order::Lsb0, -3),
+        case::positive([0b01101_000].as_ref(), Endian::Big, Order::Msb0, 13),
+    )]
+    fn test_sign_extend_five_bits(input: &[u8], endian: Endian, order: Order, expected: i8) {
+        let mut cursor = std::io::Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = i8::from_reader_with_ctx(&mut reader, (endian, BitSize(5), order)).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read
+            .to_writer(&mut writer, (endian, BitSize(5), order))
+            .unwrap();
+        writer.finalize().unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    /// Big endian lsb fields wider than a byte: the first byte holds the sign bit and the high
+    /// bits, the trailing partial byte the low bits
+    #[cfg(feature = "bits")]
+    #[rstest(input, expected,
+        case::min([0x80, 0x00].as_ref(), -0x800),
+        case::negative([0x80, 0x0F].as_ref(), -0x7F1),
+        case::minus_one([0xFF, 0x0F].as_ref(), -1),
+        case::positive([0x7F, 0x0A].as_ref(), 0x7FA),
+    )]
+    fn test_sign_extend_be_lsb0_twelve_bits(input: &[u8], expected: i16) {
+        let ctx = (Endian::Big, BitSize(12), Order::Lsb0);
+        let mut cursor = std::io::Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = i16::from_reader_with_ctx(&mut reader, ctx).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, ctx).unwrap();
+        writer.finalize().unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[rstest(input, endian, expected,
+        case::le(&[0xFE, 0xFF, 0xFF], Endian::Little, -2),
+        case::be(&[0xFF, 0xFF, 0xFE], Endian::Big, -2),
+        case::le_positive(&[0xFF, 0xFF, 0x7F], Endian::Little, 0x7F_FFFF),
+        case::be_min(&[0x80, 0x00, 0x00], Endian::Big, -0x80_0000),
+    )]
+    fn test_sign_extend_bytes(input: &[u8], endian: Endian, expected: i32) {
+        let mut cursor = std::io::Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = i32::from_reader_with_ctx(&mut reader, (endian, ByteSize(3))).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read
+            .to_writer(&mut writer, (endian, ByteSize(3)))
+            .unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[rstest(value, size,
+        case::above(70000, 2),
+        case::max(0x8000, 2),
+        case::min(-0x8001, 2),
+        case::one_byte(128, 1),
+        case::wider_than_type(0, 5),
+    )]
+    fn test_signed_bytes_overflow(value: i32, size: usize) {
+        for endian in [Endian::Big, Endian::Little] {
+            let mut writer = Writer::new(Cursor::new(vec![]));
+            let res = value.to_writer(&mut writer, (endian, ByteSize(size)));
+            assert!(matches!(res, Err(DekuError::InvalidParam(_))), "{res:?}");
+            assert!(writer.inner.into_inner().is_empty());
+        }
+    }
+
+    #[rstest(endian, expected,
+        case::big(Endian::Big, [0xAA, 0xBB, 0xCC, 0xDD, 0x11, 0x22, 0x33, 0x44]),
+        case::little(Endian::Little, [0x44, 0x33, 0x22, 0x11, 0xDD, 0xCC, 0xBB, 0xAA]),
+        case::big_word_swapped(Endian::BigWordSwapped, [0x33, 0x44, 0x11, 0x22, 0xCC, 0xDD, 0xAA, 0xBB]),
+        case::little_word_swapped(Endian::LittleWordSwapped, [0xBB, 0xAA, 0xDD, 0xCC, 0x22, 0x11, 0x44, 0x33]),
+    )]
+    fn test_word_swapped_u64(endian: Endian, expected: [u8; 8]) {
+        let value: u64 = 0xAABB_CCDD_1122_3344;
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        value.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(expected.to_vec(), writer.inner.into_inner());
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        value.to_writer(&mut writer, (endian, ByteSize(8))).unwrap();
+        assert_eq!(expected.to_vec(), writer.inner.into_inner());
+
+        let mut cursor = Cursor::new(&expected);
+        let mut reader = Reader::new(&mut cursor);
+        assert_eq!(
+            value,
+            u64::from_reader_with_ctx(&mut reader, endian).unwrap()
+        );
+
+        let mut cursor = Cursor::new(&expected);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = u64::from_reader_with_ctx(&mut reader, (endian, ByteSize(8))).unwrap();
+        assert_eq!(value, res_read);
+    }
+
+    #[rstest(endian, expected,
+        case::big_word_swapped(Endian::BigWordSwapped, [0xCC, 0xDD, 0xAA, 0xBB]),
+        case::little_word_swapped(Endian::LittleWordSwapped, [0xBB, 0xAA, 0xDD, 0xCC]),
+    )]
+    fn test_word_swapped_bytes(endian: Endian, expected: [u8; 4]) {
+        // a 4 byte field in a wider container
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        0xAABB_CCDDu64
+            .to_writer(&mut writer, (endian, ByteSize(4)))
+            .unwrap();
+        assert_eq!(expected.to_vec(), writer.inner.into_inner());
+
+        let mut cursor = Cursor::new(&expected);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = u64::from_reader_with_ctx(&mut reader, (endian, ByteSize(4))).unwrap();
+        assert_eq!(0xAABB_CCDD, res_read);
+
+        // signed values are sign extended after the words are put back in order
+        let mut cursor = Cursor::new(&expected);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = i64::from_reader_with_ctx(&mut reader, (endian, ByteSize(4))).unwrap();
+        assert_eq!(0xAABB_CCDDu32 as i32 as i64, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read
+            .to_writer(&mut writer, (endian, ByteSize(4)))
+            .unwrap();
+        assert_eq!(expected.to_vec(), writer.inner.into_inner());
+
+        // 16-bit values have a single word
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        0xAABBu16.to_writer(&mut writer, endian).unwrap();
+        let mut base = Writer::new(Cursor::new(vec![]));
+        0xAABBu16
+            .to_writer(&mut base, endian.without_word_swap())
+            .unwrap();
+        assert_eq!(base.inner.into_inner(), writer.inner.into_inner());
+    }
+
+    #[test]
+    fn test_word_swapped_f32() {
+        // 123.456 as 0x42F6E979, sent by a modbus device as CDAB
+        let input = [0xE9, 0x79, 0x42, 0xF6];
+        let mut cursor = Cursor::new(&input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = f32::from_reader_with_ctx(&mut reader, Endian::BigWordSwapped).unwrap();
+        assert_eq!(123.456, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read
+            .to_writer(&mut writer, Endian::BigWordSwapped)
+            .unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[rstest(size, case::odd_bytes(3), case::odd_words(5))]
+    fn test_word_swapped_invalid_size(size: usize) {
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        let res = 0u64.to_writer(&mut writer, (Endian::BigWordSwapped, ByteSize(size)));
+        assert!(matches!(res, Err(DekuError::InvalidParam(_))));
+
+        let input = [0u8; 8];
+        let mut cursor = Cursor::new(&input);
+        let mut reader = Reader::new(&mut cursor);
+        let res = u64::from_reader_with_ctx(&mut reader, (Endian::BigWordSwapped, ByteSize(size)));
+        assert!(matches!(res, Err(DekuError::InvalidParam(_))));
+    }
+
+    #[cfg(feature = "bits")]
+    #[rstest(
+        endian,
+        order,
+        case::big_msb0(Endian::BigWordSwapped, Order::Msb0),
+        case::big_lsb0(Endian::BigWordSwapped, Order::Lsb0),
+        case::little_msb0(Endian::LittleWordSwapped, Order::Msb0),
+        case::little_lsb0(Endian::LittleWordSwapped, Order::Lsb0)
+    )]
+    fn test_word_swapped_bits(endian: Endian, order: Order) {
+        let base = endian.without_word_swap();
+        for value in [0xAABB_CCDDu32, 0x8000_0001, 0x1234_5678] {
+            // an unaligned 32-bit field is written as two 16-bit fields, in the
+            // opposite word order of the unswapped endian
+            let mut writer = Writer::new(Cursor::new(vec![]));
+            writer
+                .write_bits_order(bits![u8, Msb0; 1, 0, 1], order)
+                .unwrap();
+            value
+                .to_writer(&mut writer, (endian, BitSize(32), order))
+                .unwrap();
+            writer.finalize().unwrap();
+            let out = writer.inner.into_inner();
+
+            let (first, second) = if base.is_be() {
+                (value as u16, (value >> 16) as u16)
+            } else {
+                ((value >> 16) as u16, value as u16)
+            };
+            let mut writer = Writer::new(Cursor::new(vec![]));
+            writer
+                .write_bits_order(bits![u8, Msb0; 1, 0, 1], order)
+                .unwrap();
+            first
+                .to_writer(&mut writer, (base, BitSize(16), order))
+                .unwrap();
+            second
+                .to_writer(&mut writer, (base, BitSize(16), order))
+                .unwrap();
+            writer.finalize().unwrap();
+            assert_eq!(writer.inner.into_inner(), out);
+
+            let mut cursor = Cursor::new(&out);
+            let mut reader = Reader::new(&mut cursor);
+            reader.skip_bits(3, order).unwrap();
+            let res_read =
+                u32::from_reader_with_ctx(&mut reader, (endian, BitSize(32), order)).unwrap();
+            assert_eq!(value, res_read);
+
+            let mut cursor = Cursor::new(&out);
+            let mut reader = Reader::new(&mut cursor);
+            reader.skip_bits(3, order).unwrap();
+            let res_read =
+                i32::from_reader_with_ctx(&mut reader, (endian, BitSize(32), order)).unwrap();
+            assert_eq!(value as i32, res_read);
+
+            let mut cursor = Cursor::new(&out);
+            let mut reader = Reader::new(&mut cursor);
+            reader.skip_bits(3, order).unwrap();
+            let res_read =
+                u32::from_reader_with_ctx(&mut reader, (endian, ByteSize(4), order)).unwrap();
+            assert_eq!(value, res_read);
+        }
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        let res = 0u32.to_writer(&mut writer, (endian, BitSize(24), order));
+        assert!(matches!(res, Err(DekuError::InvalidParam(_))));
+    }
+
+    /// Reference for the integer bit reader: assemble `size` bits read one at a time, following
+    /// the layouts documented on `read_bits_uint`
+    #[cfg(feature = "bits")]
+    fn read_bits_one_by_one(
+        reader: &mut Reader<&mut Cursor<&Vec<u8>>>,
+        endian: Endian,
+        size: usize,
+        order: Order,
+    ) -> u128 {
+        let mut value: u128 = 0;
+        for i in 0..size {
+            let bit = u128::from(reader.read_bits_uint(1, order).unwrap());
+            match order {
+                Order::Msb0 => value = (value << 1) | bit,
+                Order::Lsb0 => value |= bit << i,
+            }
+        }
+        match (endian.is_le(), order) {
+            (false, Order::Msb0) | (true, Order::Lsb0) => value,
+            (true, Order::Msb0) => {
+                // whole bytes in stream order, then the trailing partial byte
+                let (full, rem) = (size / 8, size % 8);
+                let mut le = 0;
+                for byte in 0..full {
+                    le |= ((value >> (size - 8 * (byte + 1))) & 0xFF) << (8 * byte);
+                }
+                if rem == 0 {
+                    le
+                } else {
+                    le | ((value & ((1 << rem) - 1)) << (8 * full))
+                }
+            }
+            (false, Order::Lsb0) => {
+                let mut be = 0;
+                for byte in 0..size.div_ceil(8) {
+                    be = (be << 8) | ((value >> (8 * byte)) & 0xFF);
+                }
+                be
+            }
+        }
+    }
+
+    /// The integer bit reader matches reading bit by bit, including the leftover it hands to the
+    /// next field
+    #[cfg(feature = "bits")]
+    #[rstest(
+        endian,
+        order,
+        case(Endian::Big, Order::Msb0),
+        case(Endian::Big, Order::Lsb0),
+        case(Endian::Little, Order::Msb0),
+        case(Endian::Little, Order::Lsb0)
+    )]
+    fn test_read_bits_uint_matches_bit_by_bit(endian: Endian, order: Order) {
+        let input: Vec<u8> = (0..40u32).map(|i| (i * 0x9d + 0x5b) as u8).collect();
+        for lead in 0..8 {
+            for size in 1..=128 {
+                let mut cursor = Cursor::new(&input);
+                let mut reader = Reader::new(&mut cursor);
+                reader.skip_bits(lead, order).unwrap();
+                let value = u128::from_reader_with_ctx(&mut reader, (endian, BitSize(size), order))
+                    .unwrap();
+                let next =
+                    u8::from_reader_with_ctx(&mut reader, (endian, BitSize(5), order)).unwrap();
+
+                let mut cursor = Cursor::new(&input);
+                let mut reader = Reader::new(&mut cursor);
+                reader.skip_bits(lead, order).unwrap();
+                let expected = read_bits_one_by_one(&mut reader, endian, size, order);
+                let expected_next = read_bits_one_by_one(&mut reader, endian, 5, order) as u8;
+
+                assert_eq!(expected, value, "lead {lead}, size {size}");
+                assert_eq!(expected_next, next, "lead {lead}, size {size}");
+                assert_eq!(lead + size + 5, reader.bits_read);
+            }
+        }
+    }
 }
@@ -1,6 +1,9 @@
 //! Implementations of DekuRead and DekuWrite for [T; N] where 0 < N <= 32
 
+use crate::impls::bulk;
 use crate::reader::Reader;
+#[cfg(feature = "alloc")]
+use crate::vectored::IoSliceWriter;
 use crate::writer::Writer;
 use crate::{DekuError, DekuReader, DekuWriter};
 use core::mem::MaybeUninit;
@@ -18,6 +21,11 @@ where
         Self: Sized,
     {
         let mut array: [MaybeUninit<T>; N] = [const { MaybeUninit::uninit() }; N];
+        if let Some(layout) = T::bulk_layout(ctx) {
+            bulk::read_into(reader, layout, &mut array)?;
+            // SAFETY: `read_into` initialized every item
+            return Ok(unsafe { (&raw const array).cast::<[T; N]>().read() });
+        }
         for (n, item) in array.iter_mut().enumerate() {
             match T::from_reader_with_ctx(reader, ctx) {
                 Ok(value) => {
@@ -55,11 +63,23 @@ where
         writer: &mut Writer<W>,
         ctx: Ctx,
     ) -> Result<(), DekuError> {
+        if let Some(layout) = T::bulk_layout(ctx) {
+            return bulk::write(writer, layout, self);
+        }
         for v in self {
             v.to_writer(writer, ctx)?;
         }
         Ok(())
     }
+
+    #[cfg(feature = "alloc")]
+    fn to_writer_vectored<'b>(
+        &'b self,
+        writer: &mut Writer<IoSliceWriter<'b>>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        <[T]>::to_writer_vectored(self, writer, ctx)
+    }
 }
 
 impl<Ctx: Copy, T> DekuWriter<Ctx> for &[T]
@@ -71,11 +91,23 @@ where
         writer: &mut Writer<W>,
         ctx: Ctx,
     ) -> Result<(), DekuError> {
+        if let Some(layout) = T::bulk_layout(ctx) {
+            return bulk::write(writer, layout, self);
+        }
         for v in *self {
             v.to_writer(writer, ctx)?;
         }
         Ok(())
     }
+
+    #[cfg(feature = "alloc")]
+    fn to_writer_vectored<'b>(
+        &'b self,
+        writer: &mut Writer<IoSliceWriter<'b>>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        <[T]>::to_writer_vectored(*self, writer, ctx)
+    }
 }
 
 impl<Ctx: Copy, T> DekuWriter<Ctx> for [T]
@@ -87,17 +119,39 @@ where
         writer: &mut Writer<W>,
         ctx: Ctx,
     ) -> Result<(), DekuError> {
+        if let Some(layout) = T::bulk_layout(ctx) {
+            return bulk::write(writer, layout, self);
+        }
         for v in self {
             v.to_writer(writer, ctx)?;
         }
         Ok(())
     }
+
+    #[cfg(feature = "alloc")]
+    fn to_writer_vectored<'b>(
+        &'b self,
+        writer: &mut Writer<IoSliceWriter<'b>>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        if let Some(layout) = T::bulk_layout(ctx) {
+            return bulk::write_vectored(writer, layout, self);
+        }
+        for v in self {
+            v.to_writer_vectored(writer, ctx)?;
+        }
+        Ok(())
+    }
 }
 
 impl<T: crate::DekuSize, const N: usize> crate::DekuSize for [T; N] {
     const SIZE_BITS: usize = T::SIZE_BITS * N;
 }
 
+impl<T: crate::DekuWriterNoSeek, const N: usize> crate::DekuWriterNoSeek for [T; N] {}
+
+impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for [T] {}
+
 #[cfg(feature = "std")]
 #[cfg(test)]
 mod tests {
@@ -161,4 +215,49 @@ mod tests {
         input.to_writer(&mut writer, endian).unwrap();
         assert_eq!(expected, writer.inner.into_inner());
     }
+
+    /// Primitives with an `Endian` ctx are read and written in bulk, they must match the
+    /// `(Endian, ByteSize)` ctx which reads one value at a time
+    #[cfg(feature = "bits")]
+    #[rstest(
+        endian,
+        lead_bits,
+        case::le(Endian::Little, 0),
+        case::be(Endian::Big, 0),
+        case::unaligned_le(Endian::Little, 5),
+        case::unaligned_be(Endian::Big, 1)
+    )]
+    fn test_bulk(endian: Endian, lead_bits: usize) {
+        let input: Vec<u8> = (0..32u32).map(|i| (i * 11 + 3) as u8).collect();
+
+        let mut cursor = Cursor::new(&input);
+        let mut reader = Reader::new(&mut cursor);
+        reader
+            .skip_bits(lead_bits, crate::ctx::Order::Msb0)
+            .unwrap();
+        let res_read = <[i64; 3]>::from_reader_with_ctx(&mut reader, endian).unwrap();
+        let bits_read = reader.bits_read;
+
+        let mut cursor = Cursor::new(&input);
+        let mut reader = Reader::new(&mut cursor);
+        reader
+            .skip_bits(lead_bits, crate::ctx::Order::Msb0)
+            .unwrap();
+        let expected =
+            <[i64; 3]>::from_reader_with_ctx(&mut reader, (endian, crate::ctx::ByteSize(8)))
+                .unwrap();
+        assert_eq!(expected, res_read);
+        assert_eq!(reader.bits_read, bits_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        let mut expected_writer = Writer::new(Cursor::new(vec![]));
+        expected
+            .to_writer(&mut expected_writer, (endian, crate::ctx::ByteSize(8)))
+            .unwrap();
+        assert_eq!(
+            expected_writer.inner.into_inner(),
+            writer.inner.into_inner()
+        );
+    }
 }
@@ -0,0 +1,428 @@
+use core::time::Duration;
+#[cfg(feature = "std")]
+use std::time::{SystemTime, UNIX_EPOCH};
+
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::ctx::{Endian, TimeUnit};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{deku_error, DekuError, DekuReader, DekuWriter};
+
+const NANOS_PER_SEC: u32 = 1_000_000_000;
+
+fn duration_from_count(count: u64, unit: TimeUnit) -> Duration {
+    match unit {
+        TimeUnit::Seconds => Duration::from_secs(count),
+        TimeUnit::Millis => Duration::from_millis(count),
+        TimeUnit::Micros => Duration::from_micros(count),
+        TimeUnit::Nanos => Duration::from_nanos(count),
+    }
+}
+
+/// Whole number of `unit`s in `duration`, any remainder is truncated
+fn duration_to_count(duration: Duration, unit: TimeUnit) -> Result<u64, DekuError> {
+    let count = duration.as_nanos() / u128::from(unit.as_nanos());
+    u64::try_from(count).map_err(|_| {
+        deku_error!(
+            DekuError::InvalidParam,
+            "duration does not fit in a u64",
+            "{:?} as {:?}",
+            duration,
+            unit
+        )
+    })
+}
+
+/// Read a `Duration` stored as `u64` seconds followed by `u32` nanoseconds
+impl DekuReader<'_, Endian> for Duration {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        endian: Endian,
+    ) -> Result<Self, DekuError> {
+        let secs = u64::from_reader_with_ctx(reader, endian)?;
+        let nanos = u32::from_reader_with_ctx(reader, endian)?;
+        if nanos >= NANOS_PER_SEC {
+            return Err(deku_error!(
+                DekuError::Parse,
+                "duration nanoseconds out of range",
+                "{}",
+                nanos
+            ));
+        }
+
+        Ok(Duration::new(secs, nanos))
+    }
+}
+
+impl DekuReader<'_> for Duration {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        _: (),
+    ) -> Result<Self, DekuError> {
+        Duration::from_reader_with_ctx(reader, Endian::default())
+    }
+}
+
+/// Read a `Duration` stored as a single `u64` count of `unit`s
+impl DekuReader<'_, (Endian, TimeUnit)> for Duration {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (endian, unit): (Endian, TimeUnit),
+    ) -> Result<Self, DekuError> {
+        let count = u64::from_reader_with_ctx(reader, endian)?;
+        Ok(duration_from_count(count, unit))
+    }
+}
+
+impl DekuReader<'_, TimeUnit> for Duration {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        unit: TimeUnit,
+    ) -> Result<Self, DekuError> {
+        Duration::from_reader_with_ctx(reader, (Endian::default(), unit))
+    }
+}
+
+/// Read a `Duration` stored as a single count of `unit`s, with the size of the
+/// integer given by `Ctx` (e.g. [`ByteSize`](crate::ctx::ByteSize))
+impl<'a, Ctx> DekuReader<'a, (Endian, TimeUnit, Ctx)> for Duration
+where
+    u64: DekuReader<'a, (Endian, Ctx)>,
+{
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (endian, unit, ctx): (Endian, TimeUnit, Ctx),
+    ) -> Result<Self, DekuError> {
+        let count = u64::from_reader_with_ctx(reader, (endian, ctx))?;
+        Ok(duration_from_count(count, unit))
+    }
+}
+
+/// Write a `Duration` as `u64` seconds followed by `u32` nanoseconds
+impl DekuWriter<Endian> for Duration {
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        endian: Endian,
+    ) -> Result<(), DekuError> {
+        self.as_secs().to_writer(writer, endian)?;
+        self.subsec_nanos().to_writer(writer, endian)
+    }
+}
+
+impl DekuWriter for Duration {
+    fn to_writer<W: Write + Seek>(&self, writer: &mut Writer<W>, _: ()) -> Result<(), DekuError> {
+        self.to_writer(writer, Endian::default())
+    }
+}
+
+impl crate::DekuWriterNoSeek for Duration {}
+
+/// Write a `Duration` as a single `u64` count of `unit`s, truncating any remainder
+impl DekuWriter<(Endian, TimeUnit)> for Duration {
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        (endian, unit): (Endian, TimeUnit),
+    ) -> Result<(), DekuError> {
+        duration_to_count(*self, unit)?.to_writer(writer, endian)
+    }
+}
+
+impl DekuWriter<TimeUnit> for Duration {
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        unit: TimeUnit,
+    ) -> Result<(), DekuError> {
+        self.to_writer(writer, (Endian::default(), unit))
+    }
+}
+
+/// Write a `Duration` as a single count of `unit`s, truncating any remainder, with the size of
+/// the integer given by `Ctx` (e.g. [`ByteSize`](crate::ctx::ByteSize))
+impl<Ctx> DekuWriter<(Endian, TimeUnit, Ctx)> for Duration
+where
+    u64: DekuWriter<(Endian, Ctx)>,
+{
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        (endian, unit, ctx): (Endian, TimeUnit, Ctx),
+    ) -> Result<(), DekuError> {
+        duration_to_count(*self, unit)?.to_writer(writer, (endian, ctx))
+    }
+}
+
+#[cfg(feature = "std")]
+fn system_time_from_count(count: i64, unit: TimeUnit) -> Result<SystemTime, DekuError> {
+    let offset = duration_from_count(count.unsigned_abs(), unit);
+    let time = if count < 0 {
+        UNIX_EPOCH.checked_sub(offset)
+    } else {
+        UNIX_EPOCH.checked_add(offset)
+    };
+
+    time.ok_or_else(|| {
+        deku_error!(
+            DekuError::Parse,
+            "system time out of range",
+            "{} {:?}",
+            count,
+            unit
+        )
+    })
+}
+
+/// Signed whole number of `unit`s since the Unix epoch, truncated towards the epoch
+#[cfg(feature = "std")]
+fn system_time_to_count(time: SystemTime, unit: TimeUnit) -> Result<i64, DekuError> {
+    let out_of_range = || {
+        deku_error!(
+            DekuError::InvalidParam,
+            "system time does not fit in an i64",
+            "{:?} as {:?}",
+            time,
+            unit
+        )
+    };
+
+    match time.duration_since(UNIX_EPOCH) {
+        Ok(after) => i64::try_from(duration_to_count(after, unit)?).map_err(|_| out_of_range()),
+        Err(e) => {
+            let before = duration_to_count(e.duration(), unit)?;
+            0i64.checked_sub_unsigned(before).ok_or_else(out_of_range)
+        }
+    }
+}
+
+/// Read a `SystemTime` stored as an `i64` count of `unit`s since the Unix epoch
+#[cfg(feature = "std")]
+impl DekuReader<'_, (Endian, TimeUnit)> for SystemTime {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (endian, unit): (Endian, TimeUnit),
+    ) -> Result<Self, DekuError> {
+        let count = i64::from_reader_with_ctx(reader, endian)?;
+        system_time_from_count(count, unit)
+    }
+}
+
+/// Read a `SystemTime` stored as an `i64` count of seconds since the Unix epoch
+#[cfg(feature = "std")]
+impl DekuReader<'_, Endian> for SystemTime {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        endian: Endian,
+    ) -> Result<Self, DekuError> {
+        SystemTime::from_reader_with_ctx(reader, (endian, TimeUnit::Seconds))
+    }
+}
+
+#[cfg(feature = "std")]
+impl DekuReader<'_, TimeUnit> for SystemTime {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        unit: TimeUnit,
+    ) -> Result<Self, DekuError> {
+        SystemTime::from_reader_with_ctx(reader, (Endian::default(), unit))
+    }
+}
+
+#[cfg(feature = "std")]
+impl DekuReader<'_> for SystemTime {
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        _: (),
+    ) -> Result<Self, DekuError> {
+        SystemTime::from_reader_with_ctx(reader, (Endian::default(), TimeUnit::Seconds))
+    }
+}
+
+/// Read a `SystemTime` stored as a signed count of `unit`s since the Unix
+/// epoch, with the size of the integer given by `Ctx` (e.g. [`ByteSize`](crate::ctx::ByteSize))
+#[cfg(feature = "std")]
+impl<'a, Ctx> DekuReader<'a, (Endian, TimeUnit, Ctx)> for SystemTime
+where
+    i64: DekuReader<'a, (Endian, Ctx)>,
+{
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (endian, unit, ctx): (Endian, TimeUnit, Ctx),
+    ) -> Result<Self, DekuError> {
+        let count = i64::from_reader_with_ctx(reader, (endian, ctx))?;
+        system_time_from_count(count, unit)
+    }
+}
+
+/// Write a `SystemTime` as an `i64` count of `unit`s since the Unix epoch, truncated towards
+/// the epoch
+#[cfg(feature = "std")]
+impl DekuWriter<(Endian, TimeUnit)> for SystemTime {
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        (endian, unit): (Endian, TimeUnit),
+    ) -> Result<(), DekuError> {
+        system_time_to_count(*self, unit)?.to_writer(writer, endian)
+    }
+}
+
+/// Write a `SystemTime` as an `i64` count of seconds since the Unix epoch, truncated towards
+/// the epoch
+#[cfg(feature = "std")]
+impl DekuWriter<Endian> for SystemTime {
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        endian: Endian,
+    ) -> Result<(), DekuError> {
+        self.to_writer(writer, (endian, TimeUnit::Seconds))
+    }
+}
+
+#[cfg(feature = "std")]
+impl DekuWriter<TimeUnit> for SystemTime {
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        unit: TimeUnit,
+    ) -> Result<(), DekuError> {
+        self.to_writer(writer, (Endian::default(), unit))
+    }
+}
+
+#[cfg(feature = "std")]
+impl DekuWriter for SystemTime {
+    fn to_writer<W: Write + Seek>(&self, writer: &mut Writer<W>, _: ()) -> Result<(), DekuError> {
+        self.to_writer(writer, (Endian::default(), TimeUnit::Seconds))
+    }
+}
+
+#[cfg(feature = "std")]
+impl crate::DekuWriterNoSeek for SystemTime {}
+
+/// Write a `SystemTime` as a signed count of `unit`s since the Unix epoch, truncated towards
+/// the epoch, with the size of the integer given by `Ctx` (e.g. [`ByteSize`](crate::ctx::ByteSize))
+#[cfg(feature = "std")]
+impl<Ctx> DekuWriter<(Endian, TimeUnit, Ctx)> for SystemTime
+where
+    i64: DekuWriter<(Endian, Ctx)>,
+{
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        (endian, unit, ctx): (Endian, TimeUnit, Ctx),
+    ) -> Result<(), DekuError> {
+        system_time_to_count(*self, unit)?.to_writer(writer, (endian, ctx))
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use hexlit::hex;
+    use rstest::rstest;
+    use std::io::Cursor;
+
+    use super::*;
+    use crate::ctx::ByteSize;
+
+    #[rstest(input, endian, expected,
+        case::le(&hex!("0200000000000000 40420f00"), Endian::Little, Duration::new(2, 1_000_000)),
+        case::be(&hex!("0000000000000002 000f4240"), Endian::Big, Duration::new(2, 1_000_000)),
+
+        #[should_panic(expected = "Parse(\"duration nanoseconds out of range")]
+        case::nanos_overflow(&hex!("0000000000000000 3b9aca00"), Endian::Big, Duration::ZERO),
+    )]
+    fn test_duration_secs_nanos(input: &[u8], endian: Endian, expected: Duration) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = Duration::from_reader_with_ctx(&mut reader, endian).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[rstest(input, endian, unit, expected,
+        case::secs_le(&hex!("2a00000000000000"), Endian::Little, TimeUnit::Seconds, Duration::from_secs(42)),
+        case::millis_be(&hex!("00000000000003e9"), Endian::Big, TimeUnit::Millis, Duration::from_millis(1001)),
+        case::micros_le(&hex!("e903000000000000"), Endian::Little, TimeUnit::Micros, Duration::from_micros(1001)),
+        case::nanos_be(&hex!("00000000000003e9"), Endian::Big, TimeUnit::Nanos, Duration::from_nanos(1001)),
+    )]
+    fn test_duration_unit(input: &[u8], endian: Endian, unit: TimeUnit, expected: Duration) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = Duration::from_reader_with_ctx(&mut reader, (endian, unit)).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, (endian, unit)).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[test]
+    fn test_duration_unit_size() {
+        let input = hex!("0003e8");
+        let ctx = (Endian::Big, TimeUnit::Millis, ByteSize(3));
+
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = Duration::from_reader_with_ctx(&mut reader, ctx).unwrap();
+        assert_eq!(Duration::from_secs(1), res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, ctx).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[test]
+    fn test_duration_unit_truncate() {
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        Duration::new(1, 999_999_999)
+            .to_writer(&mut writer, (Endian::Big, TimeUnit::Seconds))
+            .unwrap();
+        assert_eq!(hex!("0000000000000001").to_vec(), writer.inner.into_inner());
+    }
+
+    #[test]
+    fn test_duration_unit_overflow() {
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        let res = Duration::MAX.to_writer(&mut writer, (Endian::Big, TimeUnit::Nanos));
+        assert!(matches!(res, Err(DekuError::InvalidParam(_))));
+    }
+
+    #[rstest(input, endian, unit, expected,
+        case::secs_le(&hex!("00e1f50500000000"), Endian::Little, TimeUnit::Seconds, UNIX_EPOCH + Duration::from_secs(100_000_000)),
+        case::millis_be(&hex!("00000000000003e8"), Endian::Big, TimeUnit::Millis, UNIX_EPOCH + Duration::from_secs(1)),
+        case::before_epoch(&hex!("fffffffffffffc18"), Endian::Big, TimeUnit::Micros, UNIX_EPOCH - Duration::from_millis(1)),
+    )]
+    fn test_system_time(input: &[u8], endian: Endian, unit: TimeUnit, expected: SystemTime) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = SystemTime::from_reader_with_ctx(&mut reader, (endian, unit)).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, (endian, unit)).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[test]
+    fn test_system_time_size() {
+        let input = hex!("5f5e1000");
+        let ctx = (Endian::Big, TimeUnit::Seconds, ByteSize(4));
+
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = SystemTime::from_reader_with_ctx(&mut reader, ctx).unwrap();
+        assert_eq!(UNIX_EPOCH + Duration::from_secs(1_600_000_000), res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, ctx).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+}
@@ -66,6 +66,8 @@ macro_rules! ImplDekuTupleTraits {
                 Ok(())
             }
         }
+
+        impl<$($T: crate::DekuWriterNoSeek),+> crate::DekuWriterNoSeek for ($($T,)+) {}
     };
 }
 
@@ -22,6 +22,8 @@ impl<Ctx: Copy> DekuWriter<Ctx> for () {
     }
 }
 
+impl crate::DekuWriterNoSeek for () {}
+
 #[cfg(feature = "std")]
 #[cfg(test)]
 mod tests {
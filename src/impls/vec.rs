@@ -5,7 +5,9 @@ use no_std_io::io::{Read, Seek, SeekFrom, Write};
 use alloc::vec::Vec;
 
 use crate::error::NeedSize;
+use crate::impls::bulk;
 use crate::reader::Reader;
+use crate::vectored::IoSliceWriter;
 use crate::writer::Writer;
 use crate::{ctx::*, DekuReader};
 use crate::{DekuError, DekuWriter};
@@ -32,7 +34,8 @@ impl DekuReader<'_, ReadExact> for Vec<u8> {
             .seek(SeekFrom::Start(pos))
             .map_err(|e| DekuError::Io(e.kind()))?;
 
-        if (end - pos) < exact.0 as u64 {
+        // peeked bytes were already read from the inner reader
+        if (end - pos) + (reader.peeked_len() as u64) < exact.0 as u64 {
             return Err(DekuError::Incomplete(NeedSize::new(exact.0 * 8)));
         }
 
@@ -130,6 +133,11 @@ where
                     return Ok(Vec::new());
                 }
 
+                // Primitives are read all at once
+                if let Some(layout) = T::bulk_layout(inner_ctx) {
+                    return bulk::read_vec(reader, layout, count);
+                }
+
                 // Otherwise, read until we have read `count` elements
                 reader_vec_with_predicate(reader, Some(count), inner_ctx, move |_, _| {
                     count -= 1;
@@ -165,6 +173,14 @@ where
                     return Ok(Vec::new());
                 }
 
+                // Primitives are read all at once, when the size is a whole number of them
+                if let Some(layout) = T::bulk_layout(inner_ctx) {
+                    if size.0 % mem::size_of::<T>() == 0 {
+                        let count = size.0 / mem::size_of::<T>();
+                        return bulk::read_vec(reader, layout, count);
+                    }
+                }
+
                 reader_vec_with_predicate(reader, None, inner_ctx, move |read_bits, _| {
                     read_bits == bit_size
                 })
@@ -220,13 +236,32 @@ impl<T: DekuWriter<Ctx>, Ctx: Copy> DekuWriter<Ctx> for Vec<T> {
         writer: &mut Writer<W>,
         inner_ctx: Ctx,
     ) -> Result<(), DekuError> {
+        if let Some(layout) = T::bulk_layout(inner_ctx) {
+            return bulk::write(writer, layout, self);
+        }
         for v in self {
             v.to_writer(writer, inner_ctx)?;
         }
         Ok(())
     }
+
+    fn to_writer_vectored<'a>(
+        &'a self,
+        writer: &mut Writer<IoSliceWriter<'a>>,
+        inner_ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        if let Some(layout) = T::bulk_layout(inner_ctx) {
+            return bulk::write_vectored(writer, layout, self);
+        }
+        for v in self {
+            v.to_writer_vectored(writer, inner_ctx)?;
+        }
+        Ok(())
+    }
 }
 
+impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for Vec<T> {}
+
 #[cfg(feature = "std")]
 #[cfg(test)]
 #[allow(clippy::too_many_arguments)]
@@ -330,6 +365,69 @@ mod tests {
         assert_eq!(expected, writer.inner.into_inner());
     }
 
+    /// Primitives with an `Endian` ctx are read and written in bulk, they must match the
+    /// `(Endian, ByteSize)` ctx which reads one value at a time
+    #[rstest(endian, count, lead_bits,
+        case::count_le(Endian::Little, Ok(5), 0),
+        case::count_be(Endian::Big, Ok(5), 0),
+        case::bytes_le(Endian::Little, Err(ByteSize(12)), 0),
+        case::bytes_be(Endian::Big, Err(ByteSize(12)), 0),
+        case::word_swapped(Endian::BigWordSwapped, Ok(5), 0),
+        #[cfg(feature = "bits")]
+        case::unaligned_le(Endian::Little, Ok(5), 4),
+        #[cfg(feature = "bits")]
+        case::unaligned_be(Endian::Big, Err(ByteSize(12)), 3),
+    )]
+    fn test_vec_bulk(endian: Endian, count: Result<usize, ByteSize>, lead_bits: usize) {
+        let input: Vec<u8> = (0..32).map(|i| i * 7 + 1).collect();
+        let limit = || -> Limit<u32, _> {
+            match count {
+                Ok(count) => Limit::new_count(count),
+                Err(size) => Limit::new_byte_size(size),
+            }
+        };
+
+        let mut cursor = Cursor::new(&input);
+        let mut reader = Reader::new(&mut cursor);
+        reader.skip_bits(lead_bits, Order::Msb0).unwrap();
+        let res_read = Vec::<u32>::from_reader_with_ctx(&mut reader, (limit(), endian)).unwrap();
+        let bits_read = reader.bits_read;
+
+        let mut cursor = Cursor::new(&input);
+        let mut reader = Reader::new(&mut cursor);
+        reader.skip_bits(lead_bits, Order::Msb0).unwrap();
+        let expected =
+            Vec::<u32>::from_reader_with_ctx(&mut reader, (limit(), (endian, ByteSize(4))))
+                .unwrap();
+        assert_eq!(expected, res_read);
+        assert_eq!(reader.bits_read, bits_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        let mut expected_writer = Writer::new(Cursor::new(vec![]));
+        expected
+            .to_writer(&mut expected_writer, (endian, ByteSize(4)))
+            .unwrap();
+        assert_eq!(
+            expected_writer.inner.into_inner(),
+            writer.inner.into_inner()
+        );
+    }
+
+    #[rstest(input, limit,
+        #[should_panic(expected = "Incomplete(NeedSize { bits: 16 })")]
+        case::count([0xAA, 0xBB, 0xCC].as_ref(), Limit::new_count(2)),
+        #[should_panic(expected = "Incomplete(NeedSize { bits: 16 })")]
+        case::bytes([0xAA, 0xBB].as_ref(), Limit::new_byte_size(ByteSize(4))),
+        #[should_panic(expected = "Incomplete(NeedSize { bits: 16 })")]
+        case::bytes_partial([0xAA, 0xBB, 0xCC, 0xDD].as_ref(), Limit::new_byte_size(ByteSize(3))),
+    )]
+    fn test_vec_bulk_incomplete(input: &[u8], limit: Limit<u16, fn(&u16) -> bool>) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        Vec::<u16>::from_reader_with_ctx(&mut reader, (limit, Endian::Big)).unwrap();
+    }
+
     // Note: These tests also exist in boxed.rs
     #[cfg(feature = "bits")]
     #[rstest(input, endian, bit_size, limit, expected, expected_rest_bits, expected_rest_bytes, expected_write,
@@ -0,0 +1,164 @@
+use no_std_io::io::{Read, Seek, Write};
+
+use alloc::collections::VecDeque;
+use alloc::vec::Vec;
+
+use crate::ctx::Limit;
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{DekuError, DekuReader, DekuWriter};
+
+impl<'a, T, Ctx, Predicate> DekuReader<'a, (Limit<T, Predicate>, Ctx)> for VecDeque<T>
+where
+    T: DekuReader<'a, Ctx>,
+    Ctx: Copy,
+    Predicate: FnMut(&T) -> bool,
+{
+    /// Read `T`s until the given limit
+    /// * `limit` - the limiting factor on the amount of `T`s to read
+    /// * `inner_ctx` - The context required by `T`. It will be passed to every `T`s when constructing.
+    /// # Examples
+    /// ```rust
+    /// # use deku::ctx::*;
+    /// # use deku::DekuReader;
+    /// # use deku::no_std_io::Cursor;
+    /// # extern crate alloc;
+    /// # use alloc::collections::VecDeque;
+    /// let mut input = Cursor::new([0xAA, 0xBB, 0xCC, 0xDD]);
+    /// let mut reader = deku::reader::Reader::new(&mut input);
+    /// let deque =
+    ///     VecDeque::<u16>::from_reader_with_ctx(&mut reader, (2.into(), Endian::Big)).unwrap();
+    /// assert_eq!(deque, [0xAABB, 0xCCDD]);
+    /// ```
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        (limit, inner_ctx): (Limit<T, Predicate>, Ctx),
+    ) -> Result<Self, DekuError> {
+        // use Vec<T>'s implementation and convert to VecDeque<T>
+        let val = <Vec<T>>::from_reader_with_ctx(reader, (limit, inner_ctx))?;
+        Ok(VecDeque::from(val))
+    }
+}
+
+impl<'a, T: DekuReader<'a>, Predicate: FnMut(&T) -> bool> DekuReader<'a, Limit<T, Predicate>>
+    for VecDeque<T>
+{
+    /// Read `T`s until the given limit from input for types which don't require context.
+    fn from_reader_with_ctx<R: Read + Seek>(
+        reader: &mut Reader<R>,
+        limit: Limit<T, Predicate>,
+    ) -> Result<Self, DekuError> {
+        Self::from_reader_with_ctx(reader, (limit, ()))
+    }
+}
+
+impl<T: DekuWriter<Ctx>, Ctx: Copy> DekuWriter<Ctx> for VecDeque<T> {
+    /// Write all `T`s in a `VecDeque` to bits.
+    /// * **inner_ctx** - The context required by `T`.
+    ///
+    /// # Examples
+    /// ```rust
+    /// # use deku::{ctx::Endian, DekuWriter};
+    /// # use deku::writer::Writer;
+    /// # use deku::no_std_io::Cursor;
+    /// # extern crate alloc;
+    /// # use alloc::collections::VecDeque;
+    /// let mut out_buf = [0; 4];
+    /// let mut cursor = Cursor::new(&mut out_buf[..]);
+    /// let mut writer = Writer::new(&mut cursor);
+    /// let mut deque = VecDeque::<u16>::new();
+    /// deque.push_back(0xCCDD);
+    /// deque.push_front(0xAABB);
+    /// deque.to_writer(&mut writer, Endian::Big).unwrap();
+    /// assert_eq!(out_buf, [0xAA, 0xBB, 0xCC, 0xDD]);
+    /// ```
+    fn to_writer<W: Write + Seek>(
+        &self,
+        writer: &mut Writer<W>,
+        inner_ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        for v in self {
+            v.to_writer(writer, inner_ctx)?;
+        }
+        Ok(())
+    }
+}
+
+impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for VecDeque<T> {}
+
+#[cfg(all(feature = "alloc", feature = "bits"))]
+#[cfg(test)]
+#[allow(clippy::too_many_arguments)]
+mod tests {
+    use alloc::{vec, vec::Vec};
+    use no_std_io::io::Cursor;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ctx::*;
+    use crate::reader::Reader;
+    use bitvec::prelude::*;
+
+    // Note: Copied tests from vec.rs impl
+    #[rstest(input, endian, bit_size, limit, expected, expected_rest_bits, expected_rest_bytes, expected_write,
+        case::normal_le([0xAA, 0xBB, 0xCC, 0xDD].as_ref(), Endian::Little, Some(16), 2.into(), VecDeque::from(vec![0xBBAA, 0xDDCC]), bits![u8, Msb0;], &[], vec![0xAA, 0xBB, 0xCC, 0xDD]),
+        case::normal_be([0xAA, 0xBB, 0xCC, 0xDD].as_ref(), Endian::Big, Some(16), 2.into(), VecDeque::from(vec![0xAABB, 0xCCDD]), bits![u8, Msb0;], &[], vec![0xAA, 0xBB, 0xCC, 0xDD]),
+        case::predicate_le([0xAA, 0xBB, 0xCC, 0xDD].as_ref(), Endian::Little, Some(16), (|v: &u16| *v == 0xBBAA).into(), VecDeque::from(vec![0xBBAA]), bits![u8, Msb0;], &[0xcc, 0xdd], vec![0xAA, 0xBB]),
+        case::predicate_be([0xAA, 0xBB, 0xCC, 0xDD].as_ref(), Endian::Big, Some(16), (|v: &u16| *v == 0xAABB).into(), VecDeque::from(vec![0xAABB]), bits![u8, Msb0;], &[0xcc, 0xdd], vec![0xAA, 0xBB]),
+        case::bytes_le([0xAA, 0xBB, 0xCC, 0xDD].as_ref(), Endian::Little, Some(16), BitSize(16).into(), VecDeque::from(vec![0xBBAA]), bits![u8, Msb0;], &[0xcc, 0xdd], vec![0xAA, 0xBB]),
+        case::bytes_be([0xAA, 0xBB, 0xCC, 0xDD].as_ref(), Endian::Big, Some(16), ByteSize(2).into(), VecDeque::from(vec![0xAABB]), bits![u8, Msb0;], &[0xcc, 0xdd], vec![0xAA, 0xBB]),
+        case::end_be([0xAA, 0xBB, 0xCC, 0xDD].as_ref(), Endian::Big, Some(16), Limit::end(), VecDeque::from(vec![0xAABB, 0xCCDD]), bits![u8, Msb0;], &[], vec![0xAA, 0xBB, 0xCC, 0xDD]),
+    )]
+    fn test_vecdeque_reader_write<Predicate: FnMut(&u16) -> bool>(
+        input: &[u8],
+        endian: Endian,
+        bit_size: Option<usize>,
+        limit: Limit<u16, Predicate>,
+        expected: VecDeque<u16>,
+        expected_rest_bits: &BitSlice<u8, Msb0>,
+        expected_rest_bytes: &[u8],
+        expected_write: Vec<u8>,
+    ) {
+        // Unwrap here because all test cases are `Some`.
+        let bit_size = bit_size.unwrap();
+
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = VecDeque::<u16>::from_reader_with_ctx(
+            &mut reader,
+            (limit, (endian, BitSize(bit_size))),
+        )
+        .unwrap();
+        assert_eq!(expected, res_read);
+        assert_eq!(
+            reader.rest(),
+            expected_rest_bits.iter().by_vals().collect::<Vec<bool>>()
+        );
+        let mut buf = vec![];
+        cursor.read_to_end(&mut buf).unwrap();
+        assert_eq!(expected_rest_bytes, buf);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read
+            .to_writer(&mut writer, (endian, BitSize(bit_size)))
+            .unwrap();
+        assert_eq!(expected_write, writer.inner.into_inner());
+
+        assert_eq!(input[..expected_write.len()].to_vec(), expected_write);
+    }
+
+    #[test]
+    fn test_vecdeque_write_wrapped() {
+        // Force the ring buffer to wrap so both of its slices are written
+        let mut input = VecDeque::with_capacity(4);
+        input.extend([0x01u8, 0x02, 0x03, 0x04]);
+        input.pop_front();
+        input.pop_front();
+        input.push_back(0x05);
+        input.push_back(0x06);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        input.to_writer(&mut writer, ()).unwrap();
+        assert_eq!(vec![0x03, 0x04, 0x05, 0x06], writer.inner.into_inner());
+    }
+}
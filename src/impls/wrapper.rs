@@ -0,0 +1,98 @@
+use core::cmp::Reverse;
+use core::num::{Saturating, Wrapping};
+
+use no_std_io::io::{Read, Seek, Write};
+
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{DekuError, DekuReader, DekuWriter};
+
+/// Implement the deku traits for a single-field tuple newtype which reads and
+/// writes exactly like the type it wraps
+macro_rules! ImplDekuTransparent {
+    ($typ:ident) => {
+        impl<'a, T, Ctx> DekuReader<'a, Ctx> for $typ<T>
+        where
+            T: DekuReader<'a, Ctx>,
+            Ctx: Copy,
+        {
+            fn from_reader_with_ctx<R: Read + Seek>(
+                reader: &mut Reader<R>,
+                inner_ctx: Ctx,
+            ) -> Result<Self, DekuError> {
+                let val = <T>::from_reader_with_ctx(reader, inner_ctx)?;
+                Ok($typ(val))
+            }
+        }
+
+        impl<T, Ctx> DekuWriter<Ctx> for $typ<T>
+        where
+            T: DekuWriter<Ctx>,
+            Ctx: Copy,
+        {
+            fn to_writer<W: Write + Seek>(
+                &self,
+                writer: &mut Writer<W>,
+                inner_ctx: Ctx,
+            ) -> Result<(), DekuError> {
+                self.0.to_writer(writer, inner_ctx)
+            }
+        }
+
+        impl<T: crate::DekuSize> crate::DekuSize for $typ<T> {
+            const SIZE_BITS: usize = T::SIZE_BITS;
+        }
+
+        impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for $typ<T> {}
+    };
+}
+
+ImplDekuTransparent!(Wrapping);
+ImplDekuTransparent!(Saturating);
+ImplDekuTransparent!(Reverse);
+
+#[cfg(test)]
+mod tests {
+    use no_std_io::io::Cursor;
+    use rstest::rstest;
+
+    use super::*;
+    use crate::ctx::Endian;
+    use crate::DekuSize;
+
+    #[rstest(input, endian, expected,
+        case(&[0xEF, 0xBE], Endian::Little, 0xBEEF_u16),
+        case(&[0xBE, 0xEF], Endian::Big, 0xBEEF_u16),
+    )]
+    fn test_wrapper(input: &[u8], endian: Endian, expected: u16) {
+        fn round_trip<T>(input: &[u8], endian: Endian) -> T
+        where
+            T: for<'a> DekuReader<'a, Endian> + DekuWriter<Endian>,
+        {
+            let mut cursor = Cursor::new(input);
+            let mut reader = Reader::new(&mut cursor);
+            let res_read = T::from_reader_with_ctx(&mut reader, endian).unwrap();
+
+            let mut out = [0u8; 2];
+            let mut writer = Writer::new(Cursor::new(&mut out[..]));
+            res_read.to_writer(&mut writer, endian).unwrap();
+            assert_eq!(input, out);
+
+            res_read
+        }
+
+        assert_eq!(
+            Wrapping(expected),
+            round_trip::<Wrapping<u16>>(input, endian)
+        );
+        assert_eq!(
+            Saturating(expected),
+            round_trip::<Saturating<u16>>(input, endian)
+        );
+        assert_eq!(Reverse(expected), round_trip::<Reverse<u16>>(input, endian));
+
+        assert_eq!(16, <Wrapping<u16>>::SIZE_BITS);
+        assert_eq!(16, <Saturating<u16>>::SIZE_BITS);
+        assert_eq!(16, <Reverse<u16>>::SIZE_BITS);
+    }
+}
@@ -0,0 +1,779 @@
+//! Integer types with a bit width that is not a power of two, and alternative
+//! integer encodings
+//!
+//! Each type is stored in the next larger primitive, but reads and writes
+//! exactly its own width. Signed types are two's complement and are sign
+//! extended when read.
+//!
+//! [`Bcd`] and [`Gray`] wrap an unsigned primitive and encode it as packed
+//! binary-coded decimal or Gray code, with the same ctx as the primitive.
+//!
+//! ```rust
+//! use deku::prelude::*;
+//! use deku::int::{I24, U24};
+//!
+//! # #[cfg(feature = "alloc")]
+//! #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+//! #[deku(endian = "big")]
+//! struct Handshake {
+//!     kind: u8,
+//!     length: U24,
+//!     sample: I24,
+//! }
+//!
+//! # #[cfg(feature = "alloc")]
+//! # fn main() {
+//! let data: &[u8] = &[0x01, 0x00, 0x01, 0x00, 0xFF, 0xFF, 0xFE];
+//! let (_rest, value) = Handshake::from_bytes((data, 0)).unwrap();
+//! assert_eq!(value.length, U24::try_from(256u32).unwrap());
+//! assert_eq!(i32::from(value.sample), -2);
+//! assert_eq!(data, value.to_bytes().unwrap());
+//! # }
+//! #
+//! # #[cfg(not(feature = "alloc"))]
+//! # fn main() {}
+//! ```
+
+use core::fmt;
+
+use no_std_io::io::{Read, Seek, Write};
+
+#[cfg(feature = "bits")]
+use crate::ctx::BitSize;
+#[cfg(not(feature = "bits"))]
+use crate::ctx::ByteSize;
+use crate::ctx::{Endian, Order};
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::{deku_error, DekuError, DekuReader, DekuSize, DekuWriter};
+
+/// Error returned when a value does not fit in the target integer type
+#[derive(Debug, Copy, Clone, PartialEq, Eq)]
+pub struct TryFromIntError(());
+
+impl fmt::Display for TryFromIntError {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.write_str("out of range integral type conversion attempted")
+    }
+}
+
+impl core::error::Error for TryFromIntError {}
+
+impl From<TryFromIntError> for DekuError {
+    fn from(e: TryFromIntError) -> DekuError {
+        deku_error!(DekuError::Parse, "error parsing int", "{}", e)
+    }
+}
+
+macro_rules! ImplInt {
+    ($name:ident, $repr:ty, $raw:ty, $bits:expr, $signed:ident, $doc:expr) => {
+        #[doc = $doc]
+        #[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+        pub struct $name($repr);
+
+        impl $name {
+            /// Size of this type in bits
+            pub const BITS: u32 = $bits;
+
+            /// Smallest value that can be represented by this type
+            pub const MIN: Self = Self(ImplInt!(@min $repr, $bits, $signed));
+
+            /// Largest value that can be represented by this type
+            pub const MAX: Self = Self(ImplInt!(@max $repr, $bits, $signed));
+
+            /// Create a new value, returning `None` if `value` is out of range
+            #[inline]
+            pub const fn new(value: $repr) -> Option<Self> {
+                if value < Self::MIN.0 || value > Self::MAX.0 {
+                    None
+                } else {
+                    Some(Self(value))
+                }
+            }
+
+            /// Return the value as the primitive it is stored in
+            #[inline]
+            pub const fn get(self) -> $repr {
+                self.0
+            }
+
+            /// Interpret the low [`Self::BITS`] bits of `raw`
+            #[inline]
+            const fn from_raw(raw: $raw) -> Self {
+                const SHIFT: u32 = <$raw>::BITS - $bits;
+                Self(((raw << SHIFT) as $repr) >> SHIFT)
+            }
+
+            /// Two's complement representation in the low [`Self::BITS`] bits
+            #[inline]
+            const fn to_raw(self) -> $raw {
+                const MASK: $raw = <$raw>::MAX >> (<$raw>::BITS - $bits);
+                (self.0 as $raw) & MASK
+            }
+        }
+
+        impl fmt::Display for $name {
+            fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                fmt::Display::fmt(&self.0, f)
+            }
+        }
+
+        impl From<$name> for $repr {
+            #[inline]
+            fn from(value: $name) -> $repr {
+                value.0
+            }
+        }
+
+        impl TryFrom<$repr> for $name {
+            type Error = TryFromIntError;
+
+            #[inline]
+            fn try_from(value: $repr) -> Result<Self, Self::Error> {
+                Self::new(value).ok_or(TryFromIntError(()))
+            }
+        }
+
+        impl DekuSize for $name {
+            const SIZE_BITS: usize = $bits;
+        }
+
+        impl crate::DekuWriterNoSeek for $name {}
+
+        impl DekuReader<'_, (Endian, Order)> for $name {
+            #[inline]
+            fn from_reader_with_ctx<R: Read + Seek>(
+                reader: &mut Reader<R>,
+                (endian, order): (Endian, Order),
+            ) -> Result<Self, DekuError> {
+                // with `bits`, use BitSize so that `order` is honoured on unaligned streams
+                #[cfg(feature = "bits")]
+                let raw = <$raw>::from_reader_with_ctx(reader, (endian, BitSize($bits), order))?;
+                #[cfg(not(feature = "bits"))]
+                let raw =
+                    <$raw>::from_reader_with_ctx(reader, (endian, ByteSize($bits / 8), order))?;
+                Ok(Self::from_raw(raw))
+            }
+        }
+
+        impl DekuReader<'_, Endian> for $name {
+            #[inline]
+            fn from_reader_with_ctx<R: Read + Seek>(
+                reader: &mut Reader<R>,
+                endian: Endian,
+            ) -> Result<Self, DekuError> {
+                Self::from_reader_with_ctx(reader, (endian, Order::default()))
+            }
+        }
+
+        impl DekuReader<'_, Order> for $name {
+            #[inline]
+            fn from_reader_with_ctx<R: Read + Seek>(
+                reader: &mut Reader<R>,
+                order: Order,
+            ) -> Result<Self, DekuError> {
+                Self::from_reader_with_ctx(reader, (Endian::default(), order))
+            }
+        }
+
+        impl DekuReader<'_> for $name {
+            #[inline]
+            fn from_reader_with_ctx<R: Read + Seek>(
+                reader: &mut Reader<R>,
+                _: (),
+            ) -> Result<Self, DekuError> {
+                Self::from_reader_with_ctx(reader, (Endian::default(), Order::default()))
+            }
+        }
+
+        impl DekuWriter<(Endian, Order)> for $name {
+            #[inline]
+            fn to_writer<W: Write + Seek>(
+                &self,
+                writer: &mut Writer<W>,
+                (endian, order): (Endian, Order),
+            ) -> Result<(), DekuError> {
+                #[cfg(feature = "bits")]
+                return self
+                    .to_raw()
+                    .to_writer(writer, (endian, BitSize($bits), order));
+                #[cfg(not(feature = "bits"))]
+                return self
+                    .to_raw()
+                    .to_writer(writer, (endian, ByteSize($bits / 8), order));
+            }
+        }
+
+        impl DekuWriter<Endian> for $name {
+            #[inline]
+            fn to_writer<W: Write + Seek>(
+                &self,
+                writer: &mut Writer<W>,
+                endian: Endian,
+            ) -> Result<(), DekuError> {
+                self.to_writer(writer, (endian, Order::default()))
+            }
+        }
+
+        impl DekuWriter<Order> for $name {
+            #[inline]
+            fn to_writer<W: Write + Seek>(
+                &self,
+                writer: &mut Writer<W>,
+                order: Order,
+            ) -> Result<(), DekuError> {
+                self.to_writer(writer, (Endian::default(), order))
+            }
+        }
+
+        impl DekuWriter for $name {
+            #[inline]
+            fn to_writer<W: Write + Seek>(
+                &self,
+                writer: &mut Writer<W>,
+                _: (),
+            ) -> Result<(), DekuError> {
+                self.to_writer(writer, (Endian::default(), Order::default()))
+            }
+        }
+    };
+    (@min $repr:ty, $bits:expr, Unsigned) => {
+        0
+    };
+    (@max $repr:ty, $bits:expr, Unsigned) => {
+        <$repr>::MAX >> (<$repr>::BITS - $bits)
+    };
+    (@min $repr:ty, $bits:expr, Signed) => {
+        <$repr>::MIN >> (<$repr>::BITS - $bits)
+    };
+    (@max $repr:ty, $bits:expr, Signed) => {
+        <$repr>::MAX >> (<$repr>::BITS - $bits)
+    };
+}
+
+/// Lossless conversions between the integer types and primitives
+macro_rules! ImplIntFrom {
+    ($name:ident; from $($from:ty),*; into $($into:ty),*) => {
+        $(
+            impl From<$from> for $name {
+                #[inline]
+                fn from(value: $from) -> Self {
+                    Self(value.into())
+                }
+            }
+        )*
+        $(
+            impl From<$name> for $into {
+                #[inline]
+                fn from(value: $name) -> $into {
+                    value.0.into()
+                }
+            }
+        )*
+    };
+}
+
+ImplInt!(U24, u32, u32, 24, Unsigned, "24-bit unsigned integer");
+ImplInt!(I24, i32, u32, 24, Signed, "24-bit signed integer");
+ImplInt!(U40, u64, u64, 40, Unsigned, "40-bit unsigned integer");
+ImplInt!(U48, u64, u64, 48, Unsigned, "48-bit unsigned integer");
+ImplInt!(I48, i64, u64, 48, Signed, "48-bit signed integer");
+ImplInt!(U56, u64, u64, 56, Unsigned, "56-bit unsigned integer");
+
+ImplIntFrom!(U24; from u8, u16; into u64, u128, i64, i128);
+ImplIntFrom!(I24; from u8, u16, i8, i16; into i64, i128);
+ImplIntFrom!(U40; from u8, u16, u32; into u128, i128);
+ImplIntFrom!(U48; from u8, u16, u32; into u128, i128);
+ImplIntFrom!(I48; from u8, u16, u32, i8, i16, i32; into i128);
+ImplIntFrom!(U56; from u8, u16, u32; into u128, i128);
+
+/// Packed binary-coded decimal, two decimal digits per byte
+///
+/// The number of digits follows the size it is read with, such as
+/// `#[deku(bytes = 3)]` for six digits. Nibbles above 9 are rejected when reading.
+///
+/// ```rust
+/// use deku::prelude::*;
+/// use deku::int::Bcd;
+///
+/// # #[cfg(feature = "alloc")]
+/// #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+/// struct Clock {
+///     hours: Bcd<u8>,
+///     minutes: Bcd<u8>,
+///     #[deku(endian = "big", bytes = 3)]
+///     meter: Bcd<u32>,
+/// }
+///
+/// # #[cfg(feature = "alloc")]
+/// # fn main() {
+/// let data: &[u8] = &[0x23, 0x59, 0x12, 0x34, 0x56];
+/// let (_rest, value) = Clock::from_bytes((data, 0)).unwrap();
+/// assert_eq!(23, value.hours.get());
+/// assert_eq!(59, value.minutes.get());
+/// assert_eq!(123456, value.meter.get());
+/// assert_eq!(data, value.to_bytes().unwrap());
+///
+/// assert!(Clock::from_bytes((&[0x2A, 0x00, 0x00, 0x00, 0x00], 0)).is_err());
+/// # }
+/// #
+/// # #[cfg(not(feature = "alloc"))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Bcd<T>(T);
+
+/// Reflected binary (Gray) code, consecutive values differ in a single bit
+///
+/// ```rust
+/// use deku::prelude::*;
+/// use deku::int::Gray;
+///
+/// # #[cfg(all(feature = "alloc", feature = "bits"))]
+/// #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+/// struct Encoder {
+///     #[deku(bits = 4)]
+///     position: Gray<u8>,
+///     #[deku(bits = 4)]
+///     index: u8,
+/// }
+///
+/// # #[cfg(all(feature = "alloc", feature = "bits"))]
+/// # fn main() {
+/// let data: &[u8] = &[0b1101_0000];
+/// let (_rest, value) = Encoder::from_bytes((data, 0)).unwrap();
+/// assert_eq!(9, value.position.get());
+/// assert_eq!(data, value.to_bytes().unwrap());
+/// # }
+/// #
+/// # #[cfg(not(all(feature = "alloc", feature = "bits")))]
+/// # fn main() {}
+/// ```
+#[derive(Debug, Copy, Clone, Default, PartialEq, Eq, PartialOrd, Ord, Hash)]
+pub struct Gray<T>(T);
+
+impl<T: DekuSize> DekuSize for Bcd<T> {
+    const SIZE_BITS: usize = T::SIZE_BITS;
+}
+
+impl<T: DekuSize> DekuSize for Gray<T> {
+    const SIZE_BITS: usize = T::SIZE_BITS;
+}
+
+impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for Bcd<T> {}
+
+impl<T: crate::DekuWriterNoSeek> crate::DekuWriterNoSeek for Gray<T> {}
+
+macro_rules! ImplBcdGray {
+    ($($typ:ty),*) => {
+        $(
+            impl Bcd<$typ> {
+                /// Largest value that fits in the digits of the underlying integer
+                pub const MAX: Self = Self(<$typ>::pow(10, <$typ>::BITS / 4) - 1);
+
+                /// Create a new value, returning `None` if `value` has too many digits
+                #[inline]
+                pub const fn new(value: $typ) -> Option<Self> {
+                    if value > Self::MAX.0 {
+                        None
+                    } else {
+                        Some(Self(value))
+                    }
+                }
+
+                /// Return the decimal value
+                #[inline]
+                pub const fn get(self) -> $typ {
+                    self.0
+                }
+
+                /// Decode packed digits, returning `None` on a nibble above 9
+                const fn from_raw(mut raw: $typ) -> Option<Self> {
+                    let mut value: $typ = 0;
+                    let mut scale: $typ = 1;
+                    while raw != 0 {
+                        let digit = raw & 0xF;
+                        if digit > 9 {
+                            return None;
+                        }
+                        value += digit * scale;
+                        scale = scale.wrapping_mul(10);
+                        raw >>= 4;
+                    }
+                    Some(Self(value))
+                }
+
+                const fn to_raw(self) -> $typ {
+                    let mut value = self.0;
+                    let mut raw: $typ = 0;
+                    let mut shift = 0;
+                    while value != 0 {
+                        raw |= (value % 10) << shift;
+                        value /= 10;
+                        shift += 4;
+                    }
+                    raw
+                }
+            }
+
+            impl fmt::Display for Bcd<$typ> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.0, f)
+                }
+            }
+
+            impl From<Bcd<$typ>> for $typ {
+                #[inline]
+                fn from(value: Bcd<$typ>) -> $typ {
+                    value.0
+                }
+            }
+
+            impl TryFrom<$typ> for Bcd<$typ> {
+                type Error = TryFromIntError;
+
+                #[inline]
+                fn try_from(value: $typ) -> Result<Self, Self::Error> {
+                    Self::new(value).ok_or(TryFromIntError(()))
+                }
+            }
+
+            impl<'a, Ctx> DekuReader<'a, Ctx> for Bcd<$typ>
+            where
+                $typ: DekuReader<'a, Ctx>,
+            {
+                #[inline]
+                fn from_reader_with_ctx<R: Read + Seek>(
+                    reader: &mut Reader<R>,
+                    ctx: Ctx,
+                ) -> Result<Self, DekuError> {
+                    let raw = <$typ>::from_reader_with_ctx(reader, ctx)?;
+                    Self::from_raw(raw).ok_or_else(|| {
+                        deku_error!(DekuError::Parse, "invalid bcd digit", "{:#x}", raw)
+                    })
+                }
+            }
+
+            impl<Ctx> DekuWriter<Ctx> for Bcd<$typ>
+            where
+                $typ: DekuWriter<Ctx>,
+            {
+                #[inline]
+                fn to_writer<W: Write + Seek>(
+                    &self,
+                    writer: &mut Writer<W>,
+                    ctx: Ctx,
+                ) -> Result<(), DekuError> {
+                    self.to_raw().to_writer(writer, ctx)
+                }
+            }
+
+            impl Gray<$typ> {
+                /// Create a new value
+                #[inline]
+                pub const fn new(value: $typ) -> Self {
+                    Self(value)
+                }
+
+                /// Return the decoded value
+                #[inline]
+                pub const fn get(self) -> $typ {
+                    self.0
+                }
+
+                const fn from_raw(raw: $typ) -> Self {
+                    let mut value = raw;
+                    let mut shift = 1;
+                    while shift < <$typ>::BITS {
+                        value ^= value >> shift;
+                        shift <<= 1;
+                    }
+                    Self(value)
+                }
+
+                const fn to_raw(self) -> $typ {
+                    self.0 ^ (self.0 >> 1)
+                }
+            }
+
+            impl fmt::Display for Gray<$typ> {
+                fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+                    fmt::Display::fmt(&self.0, f)
+                }
+            }
+
+            impl From<Gray<$typ>> for $typ {
+                #[inline]
+                fn from(value: Gray<$typ>) -> $typ {
+                    value.0
+                }
+            }
+
+            impl From<$typ> for Gray<$typ> {
+                #[inline]
+                fn from(value: $typ) -> Self {
+                    Self(value)
+                }
+            }
+
+            impl<'a, Ctx> DekuReader<'a, Ctx> for Gray<$typ>
+            where
+                $typ: DekuReader<'a, Ctx>,
+            {
+                #[inline]
+                fn from_reader_with_ctx<R: Read + Seek>(
+                    reader: &mut Reader<R>,
+                    ctx: Ctx,
+                ) -> Result<Self, DekuError> {
+                    <$typ>::from_reader_with_ctx(reader, ctx).map(Self::from_raw)
+                }
+            }
+
+            impl<Ctx> DekuWriter<Ctx> for Gray<$typ>
+            where
+                $typ: DekuWriter<Ctx>,
+            {
+                #[inline]
+                fn to_writer<W: Write + Seek>(
+                    &self,
+                    writer: &mut Writer<W>,
+                    ctx: Ctx,
+                ) -> Result<(), DekuError> {
+                    self.to_raw().to_writer(writer, ctx)
+                }
+            }
+        )*
+    };
+}
+
+ImplBcdGray!(u8, u16, u32, u64, u128);
+
+#[cfg(all(feature = "alloc", feature = "bits"))]
+#[cfg(test)]
+mod tests {
+    use alloc::vec;
+    use alloc::vec::Vec;
+    use no_std_io::io::Cursor;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[test]
+    fn test_range() {
+        assert_eq!(0, U24::MIN.get());
+        assert_eq!(0xFF_FFFF, U24::MAX.get());
+        assert_eq!(-0x80_0000, I24::MIN.get());
+        assert_eq!(0x7F_FFFF, I24::MAX.get());
+        assert_eq!(0xFF_FFFF_FFFF, U40::MAX.get());
+        assert_eq!(0xFFFF_FFFF_FFFF, U48::MAX.get());
+        assert_eq!(-0x8000_0000_0000, I48::MIN.get());
+        assert_eq!(0x7FFF_FFFF_FFFF, I48::MAX.get());
+        assert_eq!(0xFF_FFFF_FFFF_FFFF, U56::MAX.get());
+
+        assert_eq!(Ok(U24::MAX), U24::try_from(0xFF_FFFF_u32));
+        assert_eq!(Err(TryFromIntError(())), U24::try_from(0x100_0000_u32));
+        assert_eq!(Ok(I24::MIN), I24::try_from(-0x80_0000_i32));
+        assert_eq!(Err(TryFromIntError(())), I24::try_from(-0x80_0001_i32));
+        assert_eq!(Err(TryFromIntError(())), I24::try_from(0x80_0000_i32));
+        assert_eq!(Err(TryFromIntError(())), I48::try_from(i64::MIN));
+        assert_eq!(None, U56::new(u64::MAX));
+
+        assert_eq!(-1, i64::from(I24::from(-1i16)));
+        assert_eq!(0xFFFF, u64::from(U24::from(u16::MAX)));
+        assert_eq!(24, U24::SIZE_BITS);
+        assert_eq!(Some(6), I48::SIZE_BYTES);
+    }
+
+    #[rstest(input, endian, expected,
+        case::u24_le(&[0x01, 0x02, 0x03], Endian::Little, U24(0x03_0201)),
+        case::u24_be(&[0x01, 0x02, 0x03], Endian::Big, U24(0x01_0203)),
+        case::u24_max(&[0xFF, 0xFF, 0xFF], Endian::Big, U24::MAX),
+    )]
+    fn test_u24(input: &[u8], endian: Endian, expected: U24) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = U24::from_reader_with_ctx(&mut reader, endian).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[rstest(input, endian, expected,
+        case::i24_le_neg(&[0xFE, 0xFF, 0xFF], Endian::Little, I24(-2)),
+        case::i24_be_neg(&[0xFF, 0xFF, 0xFE], Endian::Big, I24(-2)),
+        case::i24_le_pos(&[0xFF, 0xFF, 0x7F], Endian::Little, I24::MAX),
+        case::i24_be_min(&[0x80, 0x00, 0x00], Endian::Big, I24::MIN),
+    )]
+    fn test_i24(input: &[u8], endian: Endian, expected: I24) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = I24::from_reader_with_ctx(&mut reader, endian).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[rstest(input, endian, expected,
+        case::i48_le_neg(&[0x00, 0x00, 0x00, 0x00, 0x00, 0x80], Endian::Little, I48::MIN),
+        case::i48_be_neg(&[0xFF, 0xFF, 0xFF, 0xFF, 0xFF, 0xFF], Endian::Big, I48(-1)),
+        case::i48_be_pos(&[0x00, 0x00, 0x00, 0x00, 0x01, 0x00], Endian::Big, I48(0x100)),
+    )]
+    fn test_i48(input: &[u8], endian: Endian, expected: I48) {
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = I48::from_reader_with_ctx(&mut reader, endian).unwrap();
+        assert_eq!(expected, res_read);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, endian).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[test]
+    fn test_wide_unsigned() {
+        let input: Vec<u8> = (1..=(5 + 6 + 7)).collect();
+
+        let mut cursor = Cursor::new(&input);
+        let mut reader = Reader::new(&mut cursor);
+        let a = U40::from_reader_with_ctx(&mut reader, Endian::Big).unwrap();
+        let b = U48::from_reader_with_ctx(&mut reader, Endian::Little).unwrap();
+        let c = U56::from_reader_with_ctx(&mut reader, Endian::Big).unwrap();
+        assert_eq!(U40(0x01_0203_0405), a);
+        assert_eq!(U48(0x0B0A_0908_0706), b);
+        assert_eq!(U56(0x0C_0D0E_0F10_1112), c);
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        a.to_writer(&mut writer, Endian::Big).unwrap();
+        b.to_writer(&mut writer, Endian::Little).unwrap();
+        c.to_writer(&mut writer, Endian::Big).unwrap();
+        assert_eq!(input, writer.inner.into_inner());
+    }
+
+    #[rstest(
+        endian,
+        order,
+        case(Endian::Little, Order::Msb0),
+        case(Endian::Big, Order::Msb0),
+        case(Endian::Little, Order::Lsb0),
+        case(Endian::Big, Order::Lsb0)
+    )]
+    fn test_unaligned_round_trip(endian: Endian, order: Order) {
+        for value in [
+            I24::MIN,
+            I24(-1),
+            I24(-0x1234),
+            I24(0),
+            I24(0x1234),
+            I24::MAX,
+        ] {
+            let mut writer = Writer::new(Cursor::new(vec![]));
+            writer
+                .write_bits_order(
+                    crate::bitvec::bits![u8, crate::bitvec::Msb0; 1, 0, 1],
+                    order,
+                )
+                .unwrap();
+            value.to_writer(&mut writer, (endian, order)).unwrap();
+            writer.finalize().unwrap();
+            let out = writer.inner.into_inner();
+
+            let mut cursor = Cursor::new(&out);
+            let mut reader = Reader::new(&mut cursor);
+            reader.skip_bits(3, order).unwrap();
+            let res_read = I24::from_reader_with_ctx(&mut reader, (endian, order)).unwrap();
+            assert_eq!(value, res_read);
+        }
+    }
+
+    #[rstest(input, endian, size, expected,
+        case::one_byte(&[0x42], Endian::Big, 1, 42),
+        case::be(&[0x12, 0x34, 0x56], Endian::Big, 3, 123456),
+        case::le(&[0x56, 0x34, 0x12], Endian::Little, 3, 123456),
+        case::max(&[0x99, 0x99, 0x99, 0x99], Endian::Big, 4, 99_999_999),
+        #[should_panic(expected = "Parse(\"invalid bcd digit")]
+        case::invalid_nibble(&[0x12, 0xA4, 0x56], Endian::Big, 3, 0),
+    )]
+    fn test_bcd(input: &[u8], endian: Endian, size: usize, expected: u32) {
+        use crate::ctx::ByteSize;
+
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read =
+            Bcd::<u32>::from_reader_with_ctx(&mut reader, (endian, ByteSize(size))).unwrap();
+        assert_eq!(expected, res_read.get());
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read
+            .to_writer(&mut writer, (endian, ByteSize(size)))
+            .unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+
+    #[test]
+    fn test_bcd_range() {
+        assert_eq!(99, Bcd::<u8>::MAX.get());
+        assert_eq!(9999, Bcd::<u16>::MAX.get());
+        assert_eq!(
+            99_999_999_999_999_999_999_999_999_999_999,
+            Bcd::<u128>::MAX.get()
+        );
+        assert_eq!(None, Bcd::<u8>::new(100));
+        assert_eq!(Err(TryFromIntError(())), Bcd::<u16>::try_from(10_000));
+        assert_eq!(0x9999, Bcd::<u16>::MAX.to_raw());
+
+        for value in 0..=9999u16 {
+            let bcd = Bcd::<u16>::new(value).unwrap();
+            assert_eq!(Some(bcd), Bcd::<u16>::from_raw(bcd.to_raw()));
+        }
+    }
+
+    #[test]
+    fn test_bcd_bits() {
+        // a 3 digit bcd field does not fit 1000
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        let res = Bcd::<u16>::new(1000)
+            .unwrap()
+            .to_writer(&mut writer, (Endian::Big, BitSize(12)));
+        assert!(matches!(res, Err(DekuError::InvalidParam(_))));
+
+        let input = [0x98, 0x70];
+        let mut cursor = Cursor::new(&input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read =
+            Bcd::<u16>::from_reader_with_ctx(&mut reader, (Endian::Big, BitSize(12))).unwrap();
+        assert_eq!(987, res_read.get());
+    }
+
+    #[test]
+    fn test_gray() {
+        assert_eq!(0b1101, Gray::<u8>::new(9).to_raw());
+        assert_eq!(9, Gray::<u8>::from_raw(0b1101).get());
+
+        for value in 0..=u16::MAX {
+            let gray = Gray::<u16>::new(value);
+            assert_eq!(gray, Gray::<u16>::from_raw(gray.to_raw()));
+            if let Some(next) = value.checked_add(1) {
+                assert_eq!(
+                    1,
+                    (gray.to_raw() ^ Gray::<u16>::new(next).to_raw()).count_ones()
+                );
+            }
+        }
+        let value = Gray::<u128>::new(u128::MAX / 3);
+        assert_eq!(value, Gray::<u128>::from_raw(value.to_raw()));
+
+        let input = [0x00, 0x0D];
+        let mut cursor = Cursor::new(&input);
+        let mut reader = Reader::new(&mut cursor);
+        let res_read = Gray::<u16>::from_reader_with_ctx(&mut reader, Endian::Big).unwrap();
+        assert_eq!(9, res_read.get());
+
+        let mut writer = Writer::new(Cursor::new(vec![]));
+        res_read.to_writer(&mut writer, Endian::Big).unwrap();
+        assert_eq!(input.to_vec(), writer.inner.into_inner());
+    }
+}
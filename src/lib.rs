@@ -416,7 +416,8 @@ let mut buffer = [0u8; MAX_SIZE];
 ```
 
 Note: Variable-size types like `Vec` do not implement `DekuSize` as their size
-cannot be known at compile-time.
+cannot be known at compile-time. Fixed-capacity containers from the `heapless` and
+`arrayvec` features implement it as their size when full, the maximum they can write.
 
 # Internal variables and previously read fields
 
@@ -496,8 +497,43 @@ The feature `bits` enables the `bitvec` crate to use when reading and writing, w
 This however slows down the reading and writing process if your code doesn't use `bits` and the `bit_offset`
 in `from_bytes`.
 
+# Numeric types
+Integers of uncommon widths such as 24 bits are available in [int]. Enabling the `float16` feature adds
+half precision and bfloat16 floats in `float`, the `fixed-point` feature adds Q-format fixed-point
+numbers in `fixed`.
+
+# Flags
+Enabling the `bitflags` feature allows types declared with the
+[bitflags](https://crates.io/crates/bitflags) crate to be used as fields, see `flags`.
+
+Enabling the `heapless` or `arrayvec` features allows `heapless::Vec`, `heapless::String` and
+`arrayvec::ArrayVec` to be used as fields without `alloc`. They are read with the same `count`,
+`until`, `bytes_read` and `bits_read` limits as `Vec`, failing with [DekuError::Parse] when their
+capacity would be exceeded.
+
+Enabling the `bytes` feature allows `Bytes` and `BytesMut` from the [bytes](https://crates.io/crates/bytes)
+crate to be used as fields, read as slices of the input when parsing from a [Reader::new_shared]. It also
+adds [Reader]/[Writer] constructors over `Buf`/`BufMut` and a frame codec, see `buf`.
+
+# Compression and transforms
+Enabling the `deflate` or `lz4` features allows fields to be compressed with the
+[compress](attributes#compress) attribute, see `compression`. Other encodings of a field,
+such as encryption, can be added with the [transform](attributes#transform) attribute.
+
 # NoSeek
 Unseekable streams such as [TcpStream](https://doc.rust-lang.org/std/net/struct.TcpStream.html) are supported through the [NoSeek](noseek::NoSeek) wrapper.
+Forward seeks, such as [seek_from_current](attributes#seek_from_current) with a positive offset,
+are emulated by discarding input or writing zeros.
+
+Types which never seek when written implement [DekuWriterNoSeek], which `DekuWrite` infers
+from the `seek_*` and `writer` attributes. They can be written with
+[to_writer_no_seek](DekuWriter::to_writer_no_seek) to any [Write](no_std_io::Write), such as a `Vec<u8>`.
+
+# Vectored writes
+[to_io_slices](DekuContainerWrite::to_io_slices) writes into an [IoSliceWriter](vectored::IoSliceWriter),
+which borrows large byte fields such as `Vec<u8>` and `[u8; N]` instead of copying them, and
+coalesces everything else into owned chunks. The slices can then be sent with one vectored write,
+see `vectored`.
 
 */
 #![warn(missing_docs)]
@@ -544,17 +580,35 @@ use ::bitvec::view::BitViewSized;
 pub use deku_derive::*;
 
 pub mod attributes;
+#[cfg(feature = "bytes")]
+pub mod buf;
+#[cfg(any(feature = "deflate", feature = "lz4"))]
+pub mod compression;
 pub mod ctx;
 pub mod error;
+#[cfg(feature = "fixed-point")]
+pub mod fixed;
+#[cfg(feature = "bitflags")]
+pub mod flags;
+#[cfg(feature = "float16")]
+pub mod float;
+pub mod framing;
+pub mod int;
 
 #[macro_use]
 mod impls;
 pub mod noseek;
 pub mod prelude;
 pub mod reader;
+#[cfg(feature = "alloc")]
+pub mod transform;
+#[cfg(feature = "alloc")]
+pub mod vectored;
 pub mod writer;
 
 pub use crate::error::DekuError;
+#[doc(hidden)]
+pub use crate::impls::bulk::BulkLayout;
 use crate::reader::Reader;
 use crate::writer::Writer;
 
@@ -601,6 +655,17 @@ pub trait DekuReader<'a, Ctx = ()> {
     ) -> Result<Self, DekuError>
     where
         Self: Sized;
+
+    /// Byte layout of `Self` read with `ctx`, for primitives which `Vec<Self>` and `[Self; N]`
+    /// read in bulk instead of one at a time
+    #[doc(hidden)]
+    #[inline(always)]
+    fn bulk_layout(_ctx: Ctx) -> Option<BulkLayout<Self>>
+    where
+        Self: Sized,
+    {
+        None
+    }
 }
 
 /// "Reader" trait: implemented on DekuRead struct and enum containers. A `container` is a type which
@@ -675,8 +740,91 @@ pub trait DekuWriter<Ctx = ()> {
         writer: &mut Writer<W>,
         ctx: Ctx,
     ) -> Result<(), DekuError>;
+
+    /// Byte layout of `Self` written with `ctx`, for primitives which `Vec<Self>`, `[Self; N]`
+    /// and `[Self]` write in bulk instead of one at a time
+    #[doc(hidden)]
+    #[inline(always)]
+    fn bulk_layout(_ctx: Ctx) -> Option<BulkLayout<Self>>
+    where
+        Self: Sized,
+    {
+        None
+    }
+
+    /// Write type to an [IoSliceWriter](vectored::IoSliceWriter), borrowing large byte
+    /// fields from `self` instead of copying them
+    ///
+    /// Defaults to [to_writer](Self::to_writer), which copies everything. `DekuWrite`
+    /// implements it by forwarding to the fields, and byte containers such as `Vec<u8>` and
+    /// `[u8; N]` borrow their contents with
+    /// [write_bytes_borrowed](Writer::write_bytes_borrowed).
+    #[cfg(feature = "alloc")]
+    #[inline]
+    fn to_writer_vectored<'a>(
+        &'a self,
+        writer: &mut Writer<vectored::IoSliceWriter<'a>>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError> {
+        self.to_writer(writer, ctx)
+    }
+
+    /// Write type to bytes, with a writer that doesn't implement [Seek](no_std_io::Seek)
+    ///
+    /// Only available for types which never seek, see [DekuWriterNoSeek].
+    #[inline]
+    fn to_writer_no_seek<W: no_std_io::Write>(
+        &self,
+        writer: &mut Writer<W>,
+        ctx: Ctx,
+    ) -> Result<(), DekuError>
+    where
+        Self: DekuWriterNoSeek,
+    {
+        writer.no_seek(|writer| self.to_writer(writer, ctx))
+    }
 }
 
+/// Marker for types which never seek in [DekuWriter::to_writer]
+///
+/// `DekuWrite` implements it for types without `seek_*` or `writer` attributes whose
+/// written fields implement it, which allows them to be written with
+/// [to_writer_no_seek](DekuWriter::to_writer_no_seek) to a `Vec<u8>`, a socket or any other
+/// [Write](no_std_io::Write) without [Seek](no_std_io::Seek) support.
+///
+/// ```rust
+/// # #[cfg(feature = "std")]
+/// # fn main() {
+/// use deku::prelude::*;
+///
+/// #[derive(DekuWrite)]
+/// #[deku(endian = "big")]
+/// struct Packet {
+///     kind: u8,
+///     #[deku(pad_bytes_before = "1")]
+///     len: u16,
+/// }
+///
+/// let mut out = Vec::new();
+/// let mut writer = Writer::new(&mut out);
+/// let packet = Packet { kind: 1, len: 0x0203 };
+/// packet.to_writer_no_seek(&mut writer, ()).unwrap();
+/// writer.finalize().unwrap();
+/// assert_eq!(vec![0x01, 0x00, 0x02, 0x03], out);
+/// # }
+/// #
+/// # #[cfg(not(feature = "std"))]
+/// # fn main() {}
+/// ```
+#[rustversion::attr(
+    since(1.78),
+    diagnostic::on_unimplemented(
+        note = "`{Self}` may seek, from a `seek_*` or `writer` attribute or one of its fields",
+        note = "use `to_writer` with a writer implementing `Seek`, or wrap it in `deku::noseek::NoSeek`",
+    )
+)]
+pub trait DekuWriterNoSeek {}
+
 /// "Writer" trait: implemented on DekuWrite struct and enum containers. A `container` is a type which
 /// doesn't need any context information.
 #[rustversion::attr(
@@ -687,6 +835,10 @@ pub trait DekuWriter<Ctx = ()> {
     )
 )]
 pub trait DekuContainerWrite: DekuWriter<()> {
+    /// Fill used to complete the last byte in `to_bytes` and `to_slice`,
+    /// set with the top-level `pad_value` or `pad_pattern` attribute
+    const PAD: writer::Pad = writer::Pad::ZERO;
+
     /// Write struct/enum to Vec<u8>
     ///
     /// ```rust
@@ -709,6 +861,7 @@ pub trait DekuContainerWrite: DekuWriter<()> {
         let mut out_buf = Vec::new();
         let mut cursor = no_std_io::Cursor::new(&mut out_buf);
         let mut __deku_writer = Writer::new(&mut cursor);
+        __deku_writer.set_pad(Self::PAD);
         DekuWriter::to_writer(self, &mut __deku_writer, ())?;
         __deku_writer.finalize()?;
         Ok(out_buf)
@@ -736,12 +889,39 @@ pub trait DekuContainerWrite: DekuWriter<()> {
     fn to_slice(&self, buf: &mut [u8]) -> Result<usize, DekuError> {
         let mut cursor = no_std_io::Cursor::new(buf);
         let mut writer = Writer::new(&mut cursor);
+        writer.set_pad(Self::PAD);
         DekuWriter::to_writer(self, &mut writer, ())?;
         writer.finalize()?;
 
         Ok(writer.bits_written / 8)
     }
 
+    /// Write struct/enum to an [IoSliceWriter](vectored::IoSliceWriter), to send it with
+    /// a single vectored write without copying large byte fields
+    ///
+    /// ```rust
+    /// # use deku::prelude::*;
+    /// #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+    /// struct S {
+    ///    kind: u8,
+    ///    #[deku(count = "1024")]
+    ///    payload: Vec<u8>,
+    /// }
+    ///
+    /// let s = S { kind: 0x01, payload: vec![0xff; 1024] };
+    /// let slices = s.to_io_slices().unwrap();
+    /// let slices: Vec<&[u8]> = slices.slices().collect();
+    /// assert_eq!(slices, [&[0x01][..], &s.payload[..]]);
+    /// ````
+    #[cfg(feature = "alloc")]
+    fn to_io_slices(&self) -> Result<vectored::IoSliceWriter<'_>, DekuError> {
+        let mut writer = Writer::new(vectored::IoSliceWriter::new());
+        writer.set_pad(Self::PAD);
+        DekuWriter::to_writer_vectored(self, &mut writer, ())?;
+        writer.finalize()?;
+        Ok(writer.inner)
+    }
+
     /// Write struct/enum to BitVec
     ///
     /// ```rust
@@ -834,6 +1014,8 @@ where
     }
 }
 
+impl<T: ?Sized + DekuWriterNoSeek> DekuWriterNoSeek for &T {}
+
 /// Like BitVec but with bounded, local storage
 #[cfg(feature = "bits")]
 #[derive(Clone, Debug)]
@@ -976,15 +1158,6 @@ where
         self.size
     }
 
-    fn insert(&mut self, index: usize, value: bool) {
-        assert!(self.size < self.bits.len());
-        assert!(index < self.size);
-        let (_left, right) = self.bits.split_at_mut(index);
-        right.shift_end(1);
-        right.set(0, value);
-        self.size += 1;
-    }
-
     fn push(&mut self, value: bool) {
         assert!(self.len() < self.bits.len());
         *self.bits.get_mut(self.size).expect("Bad index") = value;
@@ -1000,7 +1173,7 @@ where
     }
 }
 
-/// Integer view of a single-byte `Msb0` leftover, for the reader's fast path.
+/// Integer view of a single-byte `Msb0` leftover, for the integer bit readers.
 ///
 /// A leftover of `size` bits always occupies the *high* `size` bits of the byte,
 /// because it is built by copying a bit-slice into a zeroed array at index 0. So
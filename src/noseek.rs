@@ -1,6 +1,6 @@
 //! Wrapper type that provides a fake [`Seek`] implementation.
 
-use crate::no_std_io::{Read, Result, Seek, SeekFrom, Write};
+use crate::no_std_io::{Error, ErrorKind, Read, Result, Seek, SeekFrom, Write};
 
 /// A wrapper that provides a limited implementation of
 /// [`Seek`] for unseekable [`Read`] and [`Write`] streams.
@@ -8,18 +8,28 @@ use crate::no_std_io::{Read, Result, Seek, SeekFrom, Write};
 /// This is useful when reading or writing from unseekable streams where deku
 /// does not *actually* need to seek to successfully parse or write the data.
 ///
+/// Forward seeks with [`SeekFrom::Start`] and [`SeekFrom::Current`] are emulated: the
+/// skipped bytes are read and discarded before the next read, or written as zeros
+/// before the next write. Any other seek returns an [`ErrorKind::Unsupported`] error.
+///
 /// This implementation was taken from [binrw](https://docs.rs/binrw/0.14.0/binrw/io/struct.NoSeek.html)
 pub struct NoSeek<T> {
     /// The original stream.
     inner: T,
-    /// The virtual position of the seekable stream.
+    /// The position of the original stream.
     pos: u64,
+    /// Bytes to skip before the next read or write, from a forward seek.
+    skip: u64,
 }
 
 impl<T> NoSeek<T> {
     /// Creates a new seekable wrapper for the given value.
     pub fn new(inner: T) -> Self {
-        NoSeek { inner, pos: 0 }
+        NoSeek {
+            inner,
+            pos: 0,
+            skip: 0,
+        }
     }
 
     /// Gets a mutable reference to the underlying value.
@@ -38,27 +48,62 @@ impl<T> NoSeek<T> {
     }
 }
 
+impl<T: Read> NoSeek<T> {
+    /// Read and discard the bytes skipped by a forward seek
+    fn skip_read(&mut self) -> Result<()> {
+        let mut buf = [0; 64];
+        while self.skip > 0 {
+            let len = core::cmp::min(buf.len() as u64, self.skip) as usize;
+            self.inner.read_exact(&mut buf[..len])?;
+            self.pos += len as u64;
+            self.skip -= len as u64;
+        }
+        Ok(())
+    }
+}
+
+impl<T: Write> NoSeek<T> {
+    /// Write zeros for the bytes skipped by a forward seek
+    fn skip_write(&mut self) -> Result<()> {
+        let buf = [0; 64];
+        while self.skip > 0 {
+            let len = core::cmp::min(buf.len() as u64, self.skip) as usize;
+            self.inner.write_all(&buf[..len])?;
+            self.pos += len as u64;
+            self.skip -= len as u64;
+        }
+        Ok(())
+    }
+}
+
 impl<T> Seek for NoSeek<T> {
     fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
-        match pos {
-            SeekFrom::Start(n) if self.pos == n => Ok(n),
-            SeekFrom::Current(0) => Ok(self.pos),
-            // https://github.com/rust-lang/rust/issues/86442
-            #[cfg(feature = "std")]
-            _ => Err(std::io::Error::other("seek on unseekable file")),
-            #[cfg(not(feature = "std"))]
-            _ => panic!("seek on unseekable file"),
+        let target = match pos {
+            SeekFrom::Start(n) => Some(n),
+            SeekFrom::Current(n) => (self.pos + self.skip).checked_add_signed(n),
+            SeekFrom::End(_) => None,
+        };
+        match target {
+            Some(n) if n >= self.pos => {
+                self.skip = n - self.pos;
+                Ok(n)
+            }
+            _ => Err(Error::new(
+                ErrorKind::Unsupported,
+                "backward seek on unseekable stream",
+            )),
         }
     }
 
     #[cfg(feature = "std")]
     fn stream_position(&mut self) -> Result<u64> {
-        Ok(self.pos)
+        Ok(self.pos + self.skip)
     }
 }
 
 impl<T: Read> Read for NoSeek<T> {
     fn read(&mut self, buf: &mut [u8]) -> Result<usize> {
+        self.skip_read()?;
         let n = self.inner.read(buf)?;
         self.pos += n as u64;
         Ok(n)
@@ -66,6 +111,7 @@ impl<T: Read> Read for NoSeek<T> {
 
     #[cfg(feature = "std")]
     fn read_vectored(&mut self, bufs: &mut [std::io::IoSliceMut<'_>]) -> Result<usize> {
+        self.skip_read()?;
         let n = self.inner.read_vectored(bufs)?;
         self.pos += n as u64;
         Ok(n)
@@ -73,6 +119,7 @@ impl<T: Read> Read for NoSeek<T> {
 
     #[cfg(feature = "std")]
     fn read_to_end(&mut self, buf: &mut Vec<u8>) -> Result<usize> {
+        self.skip_read()?;
         let n = self.inner.read_to_end(buf)?;
         self.pos += n as u64;
         Ok(n)
@@ -80,12 +127,14 @@ impl<T: Read> Read for NoSeek<T> {
 
     #[cfg(feature = "std")]
     fn read_to_string(&mut self, buf: &mut String) -> Result<usize> {
+        self.skip_read()?;
         let n = self.inner.read_to_string(buf)?;
         self.pos += n as u64;
         Ok(n)
     }
 
     fn read_exact(&mut self, buf: &mut [u8]) -> Result<()> {
+        self.skip_read()?;
         self.inner.read_exact(buf)?;
         self.pos += buf.len() as u64;
         Ok(())
@@ -94,6 +143,7 @@ impl<T: Read> Read for NoSeek<T> {
 
 impl<T: Write> Write for NoSeek<T> {
     fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.skip_write()?;
         let n = self.inner.write(buf)?;
         self.pos += n as u64;
         Ok(n)
@@ -105,14 +155,74 @@ impl<T: Write> Write for NoSeek<T> {
 
     #[cfg(feature = "std")]
     fn write_vectored(&mut self, bufs: &[std::io::IoSlice<'_>]) -> Result<usize> {
+        self.skip_write()?;
         let n = self.inner.write_vectored(bufs)?;
         self.pos += n as u64;
         Ok(n)
     }
 
     fn write_all(&mut self, buf: &[u8]) -> Result<()> {
+        self.skip_write()?;
         self.inner.write_all(buf)?;
         self.pos += buf.len() as u64;
         Ok(())
     }
 }
+
+#[cfg(test)]
+mod tests {
+    use no_std_io::io::ErrorKind;
+    use rstest::rstest;
+
+    use super::*;
+
+    #[rstest(seek, expected,
+        case(SeekFrom::Current(0), Ok(1)),
+        case(SeekFrom::Current(2), Ok(3)),
+        case(SeekFrom::Start(1), Ok(1)),
+        case(SeekFrom::Start(3), Ok(3)),
+        case(SeekFrom::Current(-1), Err(ErrorKind::Unsupported)),
+        case(SeekFrom::Start(0), Err(ErrorKind::Unsupported)),
+        case(SeekFrom::End(0), Err(ErrorKind::Unsupported)),
+    )]
+    fn test_seek(seek: SeekFrom, expected: core::result::Result<u64, ErrorKind>) {
+        let input = [0x01, 0x02, 0x03, 0x04];
+        let mut reader = NoSeek::new(input.as_slice());
+        let mut buf = [0; 1];
+        reader.read_exact(&mut buf).unwrap();
+
+        let res = reader.seek(seek).map_err(|e| e.kind());
+        assert_eq!(expected, res);
+        reader.read_exact(&mut buf).unwrap();
+        assert_eq!(input[res.unwrap_or(1) as usize], buf[0]);
+    }
+
+    #[test]
+    fn test_seek_read() {
+        let input = [0xaa; 100];
+        let mut reader = NoSeek::new(input.as_slice());
+        assert_eq!(99, reader.seek(SeekFrom::Current(99)).unwrap());
+        assert_eq!(98, reader.seek(SeekFrom::Current(-1)).unwrap());
+        assert_eq!(99, reader.seek(SeekFrom::Start(99)).unwrap());
+        let mut buf = [0; 1];
+        reader.read_exact(&mut buf).unwrap();
+        assert!(reader.get_ref().is_empty());
+
+        reader.seek(SeekFrom::Current(1)).unwrap();
+        let res = reader.read_exact(&mut buf);
+        assert_eq!(ErrorKind::UnexpectedEof, res.unwrap_err().kind());
+    }
+
+    #[test]
+    fn test_seek_write() {
+        let mut out = [0xff; 6];
+        let mut writer = NoSeek::new(out.as_mut_slice());
+        writer.write_all(&[0x01]).unwrap();
+        assert_eq!(3, writer.seek(SeekFrom::Current(2)).unwrap());
+        writer.write_all(&[0x02]).unwrap();
+        assert_eq!(5, writer.seek(SeekFrom::Start(5)).unwrap());
+        assert!(writer.seek(SeekFrom::Start(2)).is_err());
+        writer.flush().unwrap();
+        assert_eq!([0x01, 0x00, 0x00, 0x02, 0xff, 0xff], out);
+    }
+}
@@ -18,9 +18,6 @@ use log;
 pub enum ReaderRet {
     /// Successfully read bytes
     Bytes,
-    /// Successfully read bits
-    #[cfg(all(feature = "bits", feature = "alloc"))]
-    Bits(Option<BitVec<u8, Msb0>>),
 }
 
 /// Bits or Byte stored from previous read, such as the case of id_pat
@@ -28,11 +25,106 @@ pub enum ReaderRet {
 pub enum Leftover {
     /// byte value
     Byte(u8),
-    /// bit values
+    /// bit values, fewer than a byte
+    ///
+    /// Primitives read these through an integer view of the byte, `bitvec` is still used to
+    /// store and split them.
     #[cfg(feature = "bits")]
     Bits(crate::BoundedBitVec<[u8; 1], Msb0>),
 }
 
+/// Bytes read from the inner reader while peeking, replayed by the following reads
+#[cfg(feature = "alloc")]
+#[derive(Debug, Default)]
+struct Lookahead {
+    buf: Vec<u8>,
+    /// Position of the next byte to replay in `buf`
+    pos: usize,
+    /// Depth of nested peeks, bytes read from the inner reader are recorded while non-zero
+    peeking: usize,
+    /// Peeking, or bytes left to replay, checked on every read
+    active: bool,
+}
+
+#[cfg(feature = "alloc")]
+impl Lookahead {
+    #[inline]
+    fn remaining(&self) -> usize {
+        self.buf.len() - self.pos
+    }
+
+    #[inline]
+    fn clear(&mut self) {
+        self.buf.clear();
+        self.pos = 0;
+        self.active = self.peeking != 0;
+    }
+
+    /// Read exactly `buf.len()` bytes, replaying the buffer first, and recording what is read
+    /// from `inner` while peeking
+    ///
+    /// Kept out of line, and away from the rest of `Reader`, so that reads which don't peek stay
+    /// fast.
+    #[cold]
+    #[inline(never)]
+    fn read<R: Read>(&mut self, inner: &mut R, buf: &mut [u8]) -> no_std_io::io::Result<()> {
+        let lookahead = self;
+        let n = core::cmp::min(buf.len(), lookahead.remaining());
+        buf[..n].copy_from_slice(&lookahead.buf[lookahead.pos..][..n]);
+        lookahead.pos += n;
+        let rest = &mut buf[n..];
+
+        if lookahead.peeking == 0 {
+            if lookahead.remaining() == 0 {
+                lookahead.clear();
+            }
+            if rest.is_empty() {
+                return Ok(());
+            }
+            return inner.read_exact(rest);
+        }
+        if rest.is_empty() {
+            return Ok(());
+        }
+
+        // Record what is read, keeping a partial read so that it is replayed after the peek
+        let start = lookahead.buf.len();
+        lookahead.buf.resize(start + rest.len(), 0);
+        let mut filled = 0;
+        while filled < rest.len() {
+            match inner.read(&mut lookahead.buf[start + filled..]) {
+                Ok(0) => break,
+                Ok(n) => filled += n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => {}
+                Err(e) => {
+                    lookahead.buf.truncate(start + filled);
+                    return Err(e);
+                }
+            }
+        }
+        lookahead.buf.truncate(start + filled);
+        lookahead.pos = lookahead.buf.len();
+        if filled < rest.len() {
+            return Err(no_std_io::io::Error::new(
+                ErrorKind::UnexpectedEof,
+                "failed to fill whole buffer",
+            ));
+        }
+        rest.copy_from_slice(&lookahead.buf[start..]);
+        Ok(())
+    }
+}
+
+/// Position of a [Reader], returned by [checkpoint](Reader::checkpoint) and rewound to with
+/// [restore](Reader::restore)
+#[derive(Debug, Clone)]
+pub struct Checkpoint {
+    /// Position of the next byte to read from the stream
+    pos: u64,
+    leftover: Option<Leftover>,
+    bits_read: usize,
+}
+
 /// Reader to use with `from_reader_with_ctx`
 pub struct Reader<R: Read + Seek> {
     inner: R,
@@ -40,6 +132,40 @@ pub struct Reader<R: Read + Seek> {
     pub leftover: Option<Leftover>,
     /// Amount of bits read during the use of [read_bits](Reader::read_bits) and [read_bytes](Reader::read_bytes)
     pub bits_read: usize,
+    #[cfg(feature = "alloc")]
+    lookahead: Lookahead,
+    /// Input which `inner` reads, to read `bytes::Bytes` as slices of it
+    #[cfg(feature = "bytes")]
+    shared: Option<bytes::Bytes>,
+}
+
+#[cfg(feature = "bytes")]
+impl Reader<no_std_io::io::Cursor<bytes::Bytes>> {
+    /// Create a new `Reader` over `bytes`, reading `bytes::Bytes` fields as slices of it
+    /// instead of copying them
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "bytes")]
+    /// # fn main() {
+    /// use bytes::Bytes;
+    /// use deku::ctx::Limit;
+    /// use deku::prelude::*;
+    ///
+    /// let input = Bytes::from_static(&[0x01, 0x02, 0x03]);
+    /// let mut reader = Reader::new_shared(input.clone());
+    /// let payload = Bytes::from_reader_with_ctx(&mut reader, Limit::new_count(2)).unwrap();
+    /// assert_eq!(payload, input[..2]);
+    /// assert_eq!(payload.as_ptr(), input.as_ptr());
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "bytes"))]
+    /// # fn main() {}
+    /// ```
+    pub fn new_shared(bytes: bytes::Bytes) -> Self {
+        let mut reader = Self::new(no_std_io::io::Cursor::new(bytes.clone()));
+        reader.shared = Some(bytes);
+        reader
+    }
 }
 
 impl<R: Read + Seek> Seek for Reader<R> {
@@ -51,6 +177,37 @@ impl<R: Read + Seek> Seek for Reader<R> {
         // clear leftover
         self.leftover = None;
 
+        #[cfg(feature = "alloc")]
+        let pos = {
+            if self.lookahead.peeking != 0 {
+                return Err(no_std_io::io::Error::new(
+                    ErrorKind::Unsupported,
+                    "seek while peeking",
+                ));
+            }
+            let buffered = self.lookahead.remaining();
+            match pos {
+                // Within the peeked bytes, skip them without touching the inner reader
+                SeekFrom::Current(n) if buffered != 0 && (0..=buffered as i64).contains(&n) => {
+                    self.lookahead.pos += n as usize;
+                    let inner_pos = self.inner.stream_position()?;
+                    let new_pos = inner_pos - self.lookahead.remaining() as u64;
+                    self.bits_read = usize::try_from(new_pos)
+                        .unwrap_or(usize::MAX)
+                        .saturating_mul(8);
+                    return Ok(new_pos);
+                }
+                _ => {
+                    self.lookahead.clear();
+                    match pos {
+                        // The inner reader is ahead by the peeked bytes
+                        SeekFrom::Current(n) => SeekFrom::Current(n - buffered as i64),
+                        pos => pos,
+                    }
+                }
+            }
+        };
+
         let new_pos = self.inner.seek(pos)?;
         self.bits_read = usize::try_from(new_pos)
             .unwrap_or(usize::MAX)
@@ -59,6 +216,7 @@ impl<R: Read + Seek> Seek for Reader<R> {
     }
 }
 
+/// Access the inner reader, which is ahead of the `Reader` by any peeked bytes not read yet
 impl<R: Read + Seek> AsMut<R> for Reader<R> {
     #[inline]
     fn as_mut(&mut self) -> &mut R {
@@ -74,15 +232,224 @@ impl<R: Read + Seek> Reader<R> {
             inner,
             leftover: None,
             bits_read: 0,
+            #[cfg(feature = "alloc")]
+            lookahead: Lookahead::default(),
+            #[cfg(feature = "bytes")]
+            shared: None,
         }
     }
 
     /// Consume self, returning inner Reader
+    ///
+    /// Bytes which were peeked but not read yet are lost, the inner reader is past them.
     #[inline]
     pub fn into_inner(self) -> R {
         self.inner
     }
 
+    /// Amount of bytes which were peeked and are not read yet, the inner reader is ahead by as
+    /// many bytes
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub(crate) fn peeked_len(&self) -> usize {
+        self.lookahead.remaining()
+    }
+
+    /// Read exactly `buf.len()` bytes, from the peeked bytes first and then the inner reader
+    #[inline(always)]
+    fn read_inner(&mut self, buf: &mut [u8]) -> no_std_io::io::Result<()> {
+        #[cfg(feature = "alloc")]
+        if self.lookahead.active {
+            return self.lookahead.read(&mut self.inner, buf);
+        }
+        self.inner.read_exact(buf)
+    }
+
+    /// Run `f`, then rewind to where the reader was before it, whether `f` succeeded or not
+    ///
+    /// Bytes read from the inner reader meanwhile are buffered, and read again afterwards, so
+    /// this doesn't need [Seek] support from the inner reader.
+    #[cfg(feature = "alloc")]
+    fn peek_with<T>(
+        &mut self,
+        f: impl FnOnce(&mut Self) -> Result<T, DekuError>,
+    ) -> Result<T, DekuError> {
+        let leftover = self.leftover.clone();
+        let bits_read = self.bits_read;
+        let pos = self.lookahead.pos;
+
+        self.lookahead.peeking += 1;
+        self.lookahead.active = true;
+        let ret = f(self);
+        self.lookahead.peeking -= 1;
+
+        self.lookahead.pos = pos;
+        self.lookahead.active = self.lookahead.peeking != 0 || self.lookahead.remaining() != 0;
+        self.leftover = leftover;
+        self.bits_read = bits_read;
+        ret
+    }
+
+    /// Decode a `T` without consuming it
+    ///
+    /// The reader is left where it was, including any [leftover](Reader::leftover) bits. The
+    /// bytes read to decode `T` are buffered internally and served to the following reads, so
+    /// this works over unseekable streams, such as [NoSeek](crate::noseek::NoSeek). `T` may not
+    /// seek, its padding is skipped by reading it.
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use deku::ctx::Endian;
+    /// use deku::noseek::NoSeek;
+    /// use deku::prelude::*;
+    ///
+    /// let data: &[u8] = &[0x01, 0x02, 0x03];
+    /// let mut reader = Reader::new(NoSeek::new(data));
+    /// let peeked = reader.peek::<u16, _>(Endian::Big).unwrap();
+    /// assert_eq!(0x0102, peeked);
+    ///
+    /// let value = u8::from_reader_with_ctx(&mut reader, ()).unwrap();
+    /// assert_eq!(0x01, value);
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// ```
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn peek<'a, T, Ctx>(&mut self, ctx: Ctx) -> Result<T, DekuError>
+    where
+        T: crate::DekuReader<'a, Ctx>,
+    {
+        self.peek_with(|reader| T::from_reader_with_ctx(reader, ctx))
+    }
+
+    /// Fill `buf` with the next bytes without consuming them
+    ///
+    /// Reads as [read_bytes](Reader::read_bytes) would, see [peek](Reader::peek).
+    #[inline]
+    #[cfg(feature = "alloc")]
+    pub fn peek_bytes(&mut self, buf: &mut [u8]) -> Result<(), DekuError> {
+        self.peek_with(|reader| {
+            reader
+                .read_bytes(buf.len(), buf, Order::default())
+                .map(|_| ())
+        })
+    }
+
+    /// Return the next `amt` bits without consuming them
+    ///
+    /// Reads as [read_bits](Reader::read_bits) would, see [peek](Reader::peek).
+    #[inline]
+    #[cfg(all(feature = "bits", feature = "alloc"))]
+    pub fn peek_bits(
+        &mut self,
+        amt: usize,
+        order: Order,
+    ) -> Result<Option<BitVec<u8, Msb0>>, DekuError> {
+        self.peek_with(|reader| reader.read_bits(amt, order))
+    }
+
+    /// Read `len` bytes, or all remaining bytes if `None`, as a slice of the input of a
+    /// `Reader` created with [new_shared](Reader::new_shared)
+    ///
+    /// `None` if the reader has no shared input, or isn't at a byte boundary.
+    #[cfg(feature = "bytes")]
+    pub(crate) fn read_shared(
+        &mut self,
+        len: Option<usize>,
+    ) -> Result<Option<bytes::Bytes>, DekuError> {
+        let Some(shared) = &self.shared else {
+            return Ok(None);
+        };
+        if self.leftover.is_some() || self.lookahead.peeking != 0 {
+            return Ok(None);
+        }
+
+        let pos = self
+            .inner
+            .stream_position()
+            .map_err(|e| DekuError::Io(e.kind()))?;
+        let start = usize::try_from(pos)
+            .ok()
+            .and_then(|pos| pos.checked_sub(self.peeked_len()))
+            .filter(|start| *start <= shared.len())
+            .ok_or(DekuError::Io(ErrorKind::InvalidData))?;
+        let len = len.unwrap_or(shared.len() - start);
+        if shared.len() - start < len {
+            return Err(DekuError::Incomplete(NeedSize::new(len * 8)));
+        }
+        let bytes = shared.slice(start..start + len);
+
+        #[cfg(feature = "logging")]
+        log::trace!("read_shared: {len} bytes");
+
+        // seeking sets `bits_read` to the position in the input
+        let bits_read = self.bits_read;
+        self.seek(SeekFrom::Current(len as i64))
+            .map_err(|e| DekuError::Io(e.kind()))?;
+        self.bits_read = bits_read + len * 8;
+        Ok(Some(bytes))
+    }
+
+    /// Capture the current position, including [leftover](Reader::leftover) bits and
+    /// [bits_read](Reader::bits_read), to come back to it with [restore](Reader::restore)
+    ///
+    /// ```rust
+    /// # #[cfg(feature = "std")]
+    /// # fn main() {
+    /// use deku::ctx::Endian;
+    /// use deku::prelude::*;
+    /// use std::io::Cursor;
+    ///
+    /// let mut cursor = Cursor::new([0x01, 0x02, 0x03]);
+    /// let mut reader = Reader::new(&mut cursor);
+    /// let checkpoint = reader.checkpoint().unwrap();
+    ///
+    /// // not a big endian u32
+    /// assert!(u32::from_reader_with_ctx(&mut reader, Endian::Big).is_err());
+    ///
+    /// reader.restore(&checkpoint).unwrap();
+    /// let value = u16::from_reader_with_ctx(&mut reader, Endian::Big).unwrap();
+    /// assert_eq!(0x0102, value);
+    /// # }
+    /// #
+    /// # #[cfg(not(feature = "std"))]
+    /// # fn main() {}
+    /// ```
+    #[inline]
+    pub fn checkpoint(&mut self) -> Result<Checkpoint, DekuError> {
+        let pos = self
+            .inner
+            .stream_position()
+            .map_err(|e| DekuError::Io(e.kind()))?;
+        #[cfg(feature = "alloc")]
+        let pos = pos - self.peeked_len() as u64;
+
+        Ok(Checkpoint {
+            pos,
+            leftover: self.leftover.clone(),
+            bits_read: self.bits_read,
+        })
+    }
+
+    /// Rewind to `checkpoint`, taken with [checkpoint](Reader::checkpoint) on this reader
+    ///
+    /// This seeks the inner reader back, which fails for readers that can only seek forward, such
+    /// as [NoSeek](crate::noseek::NoSeek).
+    #[inline]
+    pub fn restore(&mut self, checkpoint: &Checkpoint) -> Result<(), DekuError> {
+        #[cfg(feature = "logging")]
+        log::trace!("restore: {checkpoint:?}");
+
+        self.seek(SeekFrom::Start(checkpoint.pos))
+            .map_err(|e| DekuError::Io(e.kind()))?;
+        self.leftover.clone_from(&checkpoint.leftover);
+        self.bits_read = checkpoint.bits_read;
+        Ok(())
+    }
+
     /// Return the unused bits
     ///
     /// Once the parsing is complete for a struct, if the total size of the field using the `bits` attribute
@@ -156,7 +523,7 @@ impl<R: Read + Seek> Reader<R> {
             false
         } else {
             let mut buf = [0; 1];
-            if let Err(e) = self.inner.read_exact(&mut buf) {
+            if let Err(e) = self.read_inner(&mut buf) {
                 if e.kind() == ErrorKind::UnexpectedEof {
                     #[cfg(feature = "logging")]
                     log::trace!("end");
@@ -185,35 +552,67 @@ impl<R: Read + Seek> Reader<R> {
             log::trace!("skip_bits: {amt}");
 
             if let Some(Leftover::Bits(bits)) = &self.leftover {
-                let mut buf = bitarr!(u8, Msb0; 0; 8);
                 let needed = core::cmp::min(amt, bits.len());
                 amt -= needed;
-                self.read_bits_into(&mut buf[..needed], _order)?;
+                self.read_bits_uint(needed, _order)?;
             }
 
             let bytes_amt = amt / 8;
             let bits_amt = amt % 8;
 
+            #[cfg(feature = "alloc")]
+            let peeking = self.lookahead.peeking != 0;
+            #[cfg(not(feature = "alloc"))]
+            let peeking = false;
+
             // first, seek with bytes
             if bytes_amt != 0 {
                 debug_assert_eq!(self.bits_read % 8, 0);
-                self.seek(SeekFrom::Current(
-                    i64::try_from(bytes_amt).expect("could not convert seek usize into i64"),
-                ))
-                .map_err(|e| DekuError::Io(e.kind()))?;
+                if peeking {
+                    // read them instead, so that they are replayed after the peek
+                    self.skip_bytes(bytes_amt)?;
+                } else {
+                    self.seek(SeekFrom::Current(
+                        i64::try_from(bytes_amt).expect("could not convert seek usize into i64"),
+                    ))
+                    .map_err(|e| DekuError::Io(e.kind()))?;
+                }
             }
 
             // Save, and keep the leftover bits since the read will most likely be less than a byte
             // Note that the leftover bits are kept in self.leftover
-            let mut buf = bitarr!(u8, Msb0; 0; 8);
-            self.read_bits_into(&mut buf[..bits_amt], _order)?;
+            self.read_bits_uint(bits_amt, _order)?;
         }
 
         #[cfg(not(feature = "bits"))]
         {
-            if amt > 0 {
-                panic!("requires deku feature: bits");
+            if !amt.is_multiple_of(8) {
+                return Err(crate::deku_error!(
+                    DekuError::InvalidParam,
+                    "bit granular skip requires deku feature: bits",
+                    "{} bits",
+                    amt
+                ));
             }
+            self.skip_bytes(amt / 8)?;
+        }
+        Ok(())
+    }
+
+    /// Skip `amt` bytes by reading and discarding them, this doesn't require [Seek] support
+    /// from the inner reader.
+    ///
+    /// This will increment `bits_read`.
+    pub fn skip_bytes(&mut self, amt: usize) -> Result<(), DekuError> {
+        #[cfg(feature = "logging")]
+        log::trace!("skip_bytes: {amt}");
+
+        let mut buf = [0u8; 64];
+        let mut remaining = amt;
+        while remaining > 0 {
+            let chunk = core::cmp::min(buf.len(), remaining);
+            self.read_bytes(chunk, &mut buf[..chunk], Order::default())?;
+            remaining -= chunk;
         }
         Ok(())
     }
@@ -309,7 +708,7 @@ impl<R: Read + Seek> Reader<R> {
                     let mut iter = dst[..end].rchunks_exact_mut(8);
                     for slot in iter.by_ref() {
                         let mut buf: [u8; 1] = [0u8];
-                        if let Err(e) = self.inner.read_exact(&mut buf) {
+                        if let Err(e) = self.read_inner(&mut buf) {
                             if e.kind() == ErrorKind::UnexpectedEof {
                                 return Err(DekuError::Incomplete(NeedSize::new(dst.len())));
                             }
@@ -322,7 +721,7 @@ impl<R: Read + Seek> Reader<R> {
                     let mut iter = dst[start..end].chunks_exact_mut(8);
                     for slot in iter.by_ref() {
                         let mut buf: [u8; 1] = [0u8];
-                        if let Err(e) = self.inner.read_exact(&mut buf) {
+                        if let Err(e) = self.read_inner(&mut buf) {
                             if e.kind() == ErrorKind::UnexpectedEof {
                                 return Err(DekuError::Incomplete(NeedSize::new(dst.len())));
                             }
@@ -335,7 +734,7 @@ impl<R: Read + Seek> Reader<R> {
                 if order == Order::Lsb0 {
                     if !remainder.is_empty() {
                         let mut buf: [u8; 1] = [0u8];
-                        if let Err(e) = self.inner.read_exact(&mut buf) {
+                        if let Err(e) = self.read_inner(&mut buf) {
                             if e.kind() == ErrorKind::UnexpectedEof {
                                 return Err(DekuError::Incomplete(NeedSize::new(dst.len())));
                             }
@@ -354,7 +753,7 @@ impl<R: Read + Seek> Reader<R> {
                 } else if !remainder.is_empty() {
                     debug_assert_eq!(Order::Msb0, order);
                     let mut buf: [u8; 1] = [0u8];
-                    if let Err(e) = self.inner.read_exact(&mut buf) {
+                    if let Err(e) = self.read_inner(&mut buf) {
                         if e.kind() == ErrorKind::UnexpectedEof {
                             return Err(DekuError::Incomplete(NeedSize::new(dst.len())));
                         }
@@ -379,8 +778,8 @@ impl<R: Read + Seek> Reader<R> {
     /// Reads `amt` bits (`1..=64`) most-significant-bit first, returning them
     /// right-aligned in a `u64`.
     ///
-    /// This is the integer fast path for the common big-endian / `Msb0` case. It
-    /// is equivalent to `read_bits_into` followed by `load_be`, but the value
+    /// This is the integer bit reader behind every primitive bitfield read in
+    /// `Msb0` order. It is equivalent to `read_bits_into` followed by `load_be`, but the value
     /// never touches a `BitSlice`: the leftover is a byte and a length, so
     /// serving a field is a shift and a mask. `read_bits_into` instead rebuilds a
     /// `BoundedBitVec` per call, whose bit-unaligned `copy_from_bitslice` falls
@@ -388,7 +787,7 @@ impl<R: Read + Seek> Reader<R> {
     ///
     /// Reads whole bytes from the stream only when the leftover cannot satisfy
     /// `amt`, exactly as `read_bits_into` does, so it consumes no more input.
-    #[inline]
+    #[inline(always)]
     #[cfg(feature = "bits")]
     pub(crate) fn read_bits_uint_msb0(&mut self, amt: usize) -> Result<u64, DekuError> {
         debug_assert!((1..=64).contains(&amt));
@@ -418,7 +817,7 @@ impl<R: Read + Seek> Reader<R> {
         // the extra branch costs more than it saves.
         while have < amt {
             let mut buf = [0u8; 1];
-            if let Err(e) = self.inner.read_exact(&mut buf) {
+            if let Err(e) = self.read_inner(&mut buf) {
                 if e.kind() == ErrorKind::UnexpectedEof {
                     return Err(DekuError::Incomplete(NeedSize::new(amt)));
                 }
@@ -442,6 +841,93 @@ impl<R: Read + Seek> Reader<R> {
         Ok(value)
     }
 
+    /// Reads `amt` bits (`1..=64`) least-significant-bit first, returning them
+    /// right-aligned in a `u64`.
+    ///
+    /// The `Lsb0` mirror of `read_bits_uint_msb0`: the leftover bits are the
+    /// low end of the value, and every new byte from the stream is stacked above
+    /// the bits already held. Of a partially consumed byte, the low bits are
+    /// used and the high bits are kept as leftover, exactly as `read_bits_into`
+    /// does for `Order::Lsb0`.
+    #[inline(always)]
+    #[cfg(feature = "bits")]
+    pub(crate) fn read_bits_uint_lsb0(&mut self, amt: usize) -> Result<u64, DekuError> {
+        debug_assert!((1..=64).contains(&amt));
+
+        let mut acc: u128 = 0;
+        let mut have: usize = 0;
+        match self.leftover.take() {
+            Some(Leftover::Byte(byte)) => {
+                acc = u128::from(byte);
+                have = 8;
+            }
+            Some(Leftover::Bits(bits)) => {
+                let (byte, size) = bits.as_msb0_byte();
+                if size != 0 {
+                    acc = u128::from(byte >> (8 - size));
+                    have = size;
+                }
+            }
+            None => {}
+        }
+
+        while have < amt {
+            let mut buf = [0u8; 1];
+            if let Err(e) = self.read_inner(&mut buf) {
+                if e.kind() == ErrorKind::UnexpectedEof {
+                    return Err(DekuError::Incomplete(NeedSize::new(amt)));
+                }
+                return Err(DekuError::Io(e.kind()));
+            }
+            acc |= u128::from(buf[0]) << have;
+            have += 8;
+        }
+
+        let rest = have - amt;
+        let value = (acc as u64) & (u64::MAX >> (64 - amt));
+        if rest != 0 {
+            let tail = (acc >> amt) as u8;
+            self.leftover = Some(Leftover::Bits(crate::BoundedBitVec::from_msb0_byte(
+                tail << (8 - rest),
+                rest,
+            )));
+        }
+
+        self.bits_read += amt;
+        Ok(value)
+    }
+
+    /// Reads `amt` bits (`0..=64`) in `order`, returning them right-aligned in a `u64`.
+    ///
+    /// Dispatches to `read_bits_uint_msb0` or `read_bits_uint_lsb0`.
+    #[inline(always)]
+    #[cfg(feature = "bits")]
+    pub(crate) fn read_bits_uint(&mut self, amt: usize, order: Order) -> Result<u64, DekuError> {
+        if amt == 0 {
+            return Ok(0);
+        }
+        match order {
+            Order::Msb0 => self.read_bits_uint_msb0(amt),
+            Order::Lsb0 => self.read_bits_uint_lsb0(amt),
+        }
+    }
+
+    /// Fill `buf` from a reader that is not byte aligned, one 8-bit read per byte.
+    ///
+    /// Equivalent to `read_bits_into` over the whole of `buf`, where `Lsb0` also reverses the
+    /// bytes.
+    #[cfg(feature = "bits")]
+    fn read_bytes_unaligned(&mut self, buf: &mut [u8], order: Order) -> Result<(), DekuError> {
+        let amt = buf.len();
+        for byte in buf.iter_mut() {
+            *byte = self.read_bits_uint(8, order).map_err(|e| match e {
+                DekuError::Incomplete(_) => DekuError::Incomplete(NeedSize::new(amt * 8)),
+                e => e,
+            })? as u8;
+        }
+        Ok(())
+    }
+
     /// Attempt to read bits from `Reader`. If enough bits are already "Read", we just grab
     /// enough bits to satisfy `amt`, but will also "Read" more from the stream and store the
     /// leftovers if enough are not already "Read".
@@ -465,8 +951,8 @@ impl<R: Read + Seek> Reader<R> {
     }
 
     /// Attempt to read bytes from `Reader`. This will return `ReaderRet::Bytes` with a valid
-    /// `buf` of bytes if we have no "leftover" bytes and thus are byte aligned. If we are not byte
-    /// aligned, this will call `read_bits` and return `ReaderRet::Bits(_)` of size `amt` * 8.
+    /// `buf` of bytes, whether or not we have "leftover" bits. If we are not byte aligned, the
+    /// bytes are read through the integer bit reader.
     ///
     /// # Params
     /// `amt`    - Amount of bytes that will be read
@@ -482,7 +968,7 @@ impl<R: Read + Seek> Reader<R> {
         log::trace!("read_bytes: requesting {amt} bytes");
 
         if self.leftover.is_none() {
-            if let Err(e) = self.inner.read_exact(&mut buf[..amt]) {
+            if let Err(e) = self.read_inner(&mut buf[..amt]) {
                 if e.kind() == ErrorKind::UnexpectedEof {
                     return Err(DekuError::Incomplete(NeedSize::new(amt * 8)));
                 }
@@ -502,6 +988,7 @@ impl<R: Read + Seek> Reader<R> {
         self.read_bytes_other(amt, buf, order)
     }
 
+    #[cfg_attr(not(feature = "bits"), allow(unused_variables))]
     fn read_bytes_other(
         &mut self,
         amt: usize,
@@ -512,11 +999,7 @@ impl<R: Read + Seek> Reader<R> {
             Some(Leftover::Byte(byte)) => self.read_bytes_leftover(buf, byte, amt),
             #[cfg(feature = "bits")]
             Some(Leftover::Bits(_)) => {
-                let slice = BitSlice::from_slice_mut(&mut buf[..amt]);
-                self.read_bits_into(slice, order)?;
-                if order == Order::Lsb0 {
-                    buf[..amt].reverse();
-                }
+                self.read_bytes_unaligned(&mut buf[..amt], order)?;
                 Ok(ReaderRet::Bytes)
             }
             _ => unreachable!(),
@@ -547,10 +1030,7 @@ impl<R: Read + Seek> Reader<R> {
         if buf_len < remaining {
             return Err(DekuError::Incomplete(NeedSize::new(remaining * 8)));
         }
-        if let Err(e) = self
-            .inner
-            .read_exact(&mut buf[amt - remaining..][..remaining])
-        {
+        if let Err(e) = self.read_inner(&mut buf[amt - remaining..][..remaining]) {
             if e.kind() == ErrorKind::UnexpectedEof {
                 return Err(DekuError::Incomplete(NeedSize::new(remaining * 8)));
             }
@@ -564,9 +1044,58 @@ impl<R: Read + Seek> Reader<R> {
         Ok(ReaderRet::Bytes)
     }
 
+    /// Read `bytes` bytes, or the rest of the input when `None`
+    #[cfg(feature = "alloc")]
+    pub(crate) fn read_window(&mut self, bytes: Option<usize>) -> Result<Vec<u8>, DekuError> {
+        match bytes {
+            Some(bytes) => {
+                let mut input = alloc::vec![0; bytes];
+                self.read_bytes(bytes, &mut input, Order::default())?;
+                Ok(input)
+            }
+            None => {
+                let mut input = Vec::new();
+                self.read_to_end(&mut input)?;
+                Ok(input)
+            }
+        }
+    }
+
+    /// Read the rest of the input into `out`
+    ///
+    /// Fails with [Incomplete](DekuError::Incomplete) if the input doesn't end on a byte boundary.
+    #[cfg(feature = "alloc")]
+    pub(crate) fn read_to_end(&mut self, out: &mut Vec<u8>) -> Result<(), DekuError> {
+        #[cfg(feature = "logging")]
+        log::trace!("read_to_end");
+
+        let mut buf = [0u8; 64];
+
+        // a byte buffered by `end`, bits which keep the reads unaligned to the end, or peeked
+        // bytes, which are replayed, and recorded while peeking
+        while self.leftover.is_some() || self.lookahead.active {
+            if self.leftover.is_none() && self.end() {
+                return Ok(());
+            }
+            self.read_bytes(1, &mut buf[..1], Order::default())?;
+            out.push(buf[0]);
+        }
+
+        loop {
+            let n = match self.inner.read(&mut buf) {
+                Ok(0) => return Ok(()),
+                Ok(n) => n,
+                Err(e) if e.kind() == ErrorKind::Interrupted => continue,
+                Err(e) => return Err(DekuError::Io(e.kind())),
+            };
+            out.extend_from_slice(&buf[..n]);
+            self.bits_read += n * 8;
+        }
+    }
+
     /// Attempt to read bytes from `Reader`. This will return `ReaderRet::Bytes` with a valid
-    /// `buf` of bytes if we have no "leftover" bytes and thus are byte aligned. If we are not byte
-    /// aligned, this will call `read_bits` and return `ReaderRet::Bits(_)` of size `N` * 8.
+    /// `buf` of bytes, whether or not we have "leftover" bits. If we are not byte aligned, the
+    /// bytes are read through the integer bit reader.
     ///
     /// # Params
     /// `buf`    - result bytes
@@ -580,7 +1109,7 @@ impl<R: Read + Seek> Reader<R> {
         log::trace!("read_bytes_const: requesting {N} bytes");
 
         if self.leftover.is_none() {
-            if let Err(e) = self.inner.read_exact(buf) {
+            if let Err(e) = self.read_inner(buf) {
                 if e.kind() == ErrorKind::UnexpectedEof {
                     return Err(DekuError::Incomplete(NeedSize::new(N * 8)));
                 }
@@ -599,6 +1128,7 @@ impl<R: Read + Seek> Reader<R> {
         self.read_bytes_const_other::<N>(buf, order)
     }
 
+    #[cfg_attr(not(feature = "bits"), allow(unused_variables))]
     fn read_bytes_const_other<const N: usize>(
         &mut self,
         buf: &mut [u8; N],
@@ -611,11 +1141,7 @@ impl<R: Read + Seek> Reader<R> {
             }
             #[cfg(feature = "bits")]
             Some(Leftover::Bits(_)) => {
-                let slice = BitSlice::from_slice_mut(buf);
-                self.read_bits_into(slice, order)?;
-                if order == Order::Lsb0 {
-                    buf.reverse();
-                }
+                self.read_bytes_unaligned(buf, order)?;
                 Ok(ReaderRet::Bytes)
             }
             _ => unreachable!(),
@@ -630,13 +1156,14 @@ impl<R: Read + Seek> Reader<R> {
     ///
     /// # Params
     /// `buf` - result bytes
+    #[cfg_attr(not(feature = "bits"), allow(unused_variables))]
     pub fn read_bytes_const_into<const N: usize>(
         &mut self,
         buf: &mut [u8; N],
         order: Order,
     ) -> Result<(), DekuError> {
         if self.leftover.is_none() {
-            if let Err(e) = self.inner.read_exact(buf) {
+            if let Err(e) = self.read_inner(buf) {
                 if e.kind() == ErrorKind::UnexpectedEof {
                     return Err(DekuError::Incomplete(NeedSize::new(N * 8)));
                 }
@@ -650,14 +1177,7 @@ impl<R: Read + Seek> Reader<R> {
         match self.leftover {
             Some(Leftover::Byte(byte)) => self.read_bytes_const_leftover(buf, byte),
             #[cfg(feature = "bits")]
-            Some(Leftover::Bits(_)) => {
-                let slice = BitSlice::from_slice_mut(buf);
-                self.read_bits_into(slice, order)?;
-                if order == Order::Lsb0 {
-                    buf.reverse();
-                }
-                Ok(())
-            }
+            Some(Leftover::Bits(_)) => self.read_bytes_unaligned(buf, order),
             None => unreachable!(),
         }
     }
@@ -688,10 +1208,7 @@ impl<R: Read + Seek> Reader<R> {
         if buf_len < remaining {
             return Err(DekuError::Incomplete(NeedSize::new(remaining * 8)));
         }
-        if let Err(e) = self
-            .inner
-            .read_exact(&mut buf[N - remaining..][..remaining])
-        {
+        if let Err(e) = self.read_inner(&mut buf[N - remaining..][..remaining]) {
             if e.kind() == ErrorKind::UnexpectedEof {
                 return Err(DekuError::Incomplete(NeedSize::new(remaining * 8)));
             }
@@ -766,6 +1283,35 @@ mod tests {
         assert_eq!(reader.bits_read, 8);
     }
 
+    #[test]
+    fn test_skip_bytes() {
+        let input = [0xaa; 130];
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        reader.skip_bytes(129).unwrap();
+        assert_eq!(reader.bits_read, 129 * 8);
+        assert!(!reader.end());
+        reader.skip_bits(8, Order::Msb0).unwrap();
+        assert!(reader.end());
+        assert!(matches!(
+            reader.skip_bytes(1),
+            Err(DekuError::Incomplete(_))
+        ));
+    }
+
+    #[cfg(not(feature = "bits"))]
+    #[test]
+    fn test_skip_bits_unaligned() {
+        let input = hex!("aabb");
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        assert!(matches!(
+            reader.skip_bits(4, Order::Msb0),
+            Err(DekuError::InvalidParam(_))
+        ));
+        assert_eq!(reader.bits_read, 0);
+    }
+
     #[cfg(all(feature = "alloc", feature = "bits"))]
     #[test]
     fn test_bit_order() {
@@ -935,4 +1481,170 @@ mod tests {
             Some(bitvec![u8, Msb0; 0, 1, 0, 0, 0, 0, 0, 0, 1, 1, 0, 0, 1, 0, 0, 1, 0])
         );
     }
+
+    #[cfg(feature = "bits")]
+    #[test]
+    fn test_read_bits_uint_lsb0() {
+        // Same input as `test_regression_lsb0`: the leftover is the low end of the value
+        let input = [0x64, 0x20];
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        reader.leftover = Some(Leftover::Bits([true, false].as_slice().into()));
+        let value = reader.read_bits_uint_lsb0(17).unwrap();
+        assert_eq!(value, 0b0_1000_0001_1001_0010);
+        assert_eq!(reader.bits_read, 17);
+        assert!(!reader.end());
+        assert_eq!(reader.read_bits_uint_lsb0(1).unwrap(), 0);
+        assert!(reader.end());
+    }
+
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    #[test]
+    fn test_peek_bytes() {
+        use crate::noseek::NoSeek;
+
+        let input = hex!("aabbccdd");
+        let mut reader = Reader::new(NoSeek::new(&input[..]));
+        let mut buf = [0; 3];
+        reader.peek_bytes(&mut buf).unwrap();
+        assert_eq!(buf, hex!("aabbcc"));
+        assert_eq!(reader.bits_read, 0);
+
+        // nested peeks replay and extend the peeked bytes
+        let mut buf = [0; 1];
+        reader.peek_bytes(&mut buf).unwrap();
+        assert_eq!(buf, hex!("aa"));
+        let mut buf = [0; 4];
+        reader.peek_bytes(&mut buf).unwrap();
+        assert_eq!(buf, input);
+
+        // peeking past the end keeps what was read
+        let mut buf = [0; 5];
+        assert!(matches!(
+            reader.peek_bytes(&mut buf),
+            Err(DekuError::Incomplete(_))
+        ));
+
+        let mut buf = [0; 2];
+        reader.read_bytes(2, &mut buf, Order::Lsb0).unwrap();
+        assert_eq!(buf, hex!("aabb"));
+        reader.peek_bytes(&mut buf).unwrap();
+        assert_eq!(buf, hex!("ccdd"));
+        reader.read_bytes(2, &mut buf, Order::Lsb0).unwrap();
+        assert_eq!(buf, hex!("ccdd"));
+        assert_eq!(reader.bits_read, 4 * 8);
+        assert!(reader.end());
+    }
+
+    #[cfg(all(feature = "alloc", feature = "std"))]
+    #[test]
+    fn test_peek_read_to_end() {
+        use crate::noseek::NoSeek;
+
+        let input: Vec<u8> = (0..100).collect();
+        let mut reader = Reader::new(NoSeek::new(input.as_slice()));
+        let mut buf = [0; 2];
+        reader.peek_bytes(&mut buf).unwrap();
+
+        let mut rest = Vec::new();
+        reader
+            .peek_with(|reader| reader.read_to_end(&mut rest))
+            .unwrap();
+        assert_eq!(rest, input);
+        assert_eq!(reader.bits_read, 0);
+
+        let mut rest = Vec::new();
+        reader.read_to_end(&mut rest).unwrap();
+        assert_eq!(rest, input);
+        assert_eq!(reader.bits_read, 100 * 8);
+        assert!(reader.end());
+    }
+
+    #[cfg(all(feature = "alloc", feature = "bits"))]
+    #[test]
+    fn test_peek_bits_unaligned() {
+        let input = hex!("f00f");
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        assert_eq!(
+            reader.read_bits(4, Order::Msb0).unwrap(),
+            Some(bitvec![u8, Msb0; 1, 1, 1, 1])
+        );
+        assert_eq!(
+            reader.peek_bits(8, Order::Msb0).unwrap(),
+            Some(bitvec![u8, Msb0; 0, 0, 0, 0, 0, 0, 0, 0])
+        );
+        assert_eq!(reader.bits_read, 4);
+        assert_eq!(
+            reader.read_bits(12, Order::Msb0).unwrap(),
+            Some(bitvec![u8, Msb0; 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1])
+        );
+        assert!(reader.end());
+    }
+
+    #[cfg(feature = "alloc")]
+    #[test]
+    fn test_peek_seek() {
+        use crate::ctx::Endian;
+        use crate::DekuReader;
+
+        let input = hex!("0102030405");
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        assert_eq!(reader.peek::<u16, _>(Endian::Big).unwrap(), 0x0102);
+        assert_eq!(reader.peek::<u32, _>(Endian::Big).unwrap(), 0x01020304);
+
+        // seek within the peeked bytes
+        assert_eq!(reader.seek(SeekFrom::Current(1)).unwrap(), 1);
+        assert_eq!(reader.bits_read, 8);
+        assert_eq!(u8::from_reader_with_ctx(&mut reader, ()).unwrap(), 0x02);
+
+        // seek past the peeked bytes
+        assert_eq!(reader.seek(SeekFrom::Current(2)).unwrap(), 4);
+        assert_eq!(u8::from_reader_with_ctx(&mut reader, ()).unwrap(), 0x05);
+
+        reader.seek(SeekFrom::Start(0)).unwrap();
+        assert_eq!(reader.peek::<u8, _>(()).unwrap(), 0x01);
+        assert_eq!(reader.seek(SeekFrom::Current(-0)).unwrap(), 0);
+        assert_eq!(
+            u16::from_reader_with_ctx(&mut reader, Endian::Big).unwrap(),
+            0x0102
+        );
+    }
+
+    #[cfg(all(feature = "alloc", feature = "bits"))]
+    #[test]
+    fn test_checkpoint_restore() {
+        let input = hex!("f00faa");
+        let mut cursor = Cursor::new(input);
+        let mut reader = Reader::new(&mut cursor);
+        reader.read_bits(4, Order::Msb0).unwrap();
+        let mut buf = [0; 1];
+        reader.peek_bytes(&mut buf).unwrap();
+
+        let checkpoint = reader.checkpoint().unwrap();
+        assert_eq!(
+            reader.read_bits(12, Order::Msb0).unwrap(),
+            Some(bitvec![u8, Msb0; 0, 0, 0, 0, 0, 0, 0, 0, 1, 1, 1, 1])
+        );
+        assert_eq!(reader.bits_read, 16);
+
+        // twice from the same checkpoint
+        for _ in 0..2 {
+            reader.restore(&checkpoint).unwrap();
+            assert_eq!(reader.bits_read, 4);
+            assert_eq!(
+                reader.read_bits(8, Order::Msb0).unwrap(),
+                Some(bitvec![u8, Msb0; 0, 0, 0, 0, 0, 0, 0, 0])
+            );
+        }
+        let mut buf = [0; 1];
+        reader.read_bytes(1, &mut buf, Order::Msb0).unwrap();
+        assert_eq!(buf, [0xfa]);
+        assert_eq!(
+            reader.read_bits(4, Order::Msb0).unwrap(),
+            Some(bitvec![u8, Msb0; 1, 0, 1, 0])
+        );
+        assert!(reader.end());
+    }
 }
@@ -0,0 +1,179 @@
+//! Transformed fields
+//!
+//! A field with the `transform` attribute is read from a window of the input decoded by a
+//! [`Transform`], and written encoded by it, see [transform](crate::attributes#transform).
+//! This allows fields to be obfuscated or encrypted as a whole, with a key coming from previous
+//! fields or the ctx.
+//!
+//! ```rust
+//! use deku::prelude::*;
+//! use deku::transform::Transform;
+//!
+//! /// XOR every byte with a key
+//! struct Xor;
+//!
+//! impl Transform<u8> for Xor {
+//!     fn decode(&self, input: &[u8], key: u8) -> Result<Vec<u8>, DekuError> {
+//!         Ok(input.iter().map(|b| b ^ key).collect())
+//!     }
+//!
+//!     fn encode(&self, input: &[u8], key: u8) -> Result<Vec<u8>, DekuError> {
+//!         self.decode(input, key)
+//!     }
+//! }
+//!
+//! #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+//! #[deku(endian = "big")]
+//! struct Block {
+//!     key: u8,
+//!     #[deku(transform = "Xor", transform_ctx = "*key", bytes = 2)]
+//!     value: u16,
+//! }
+//!
+//! let data: &[u8] = &[0xFF, 0xED, 0xCB];
+//! let (_rest, block) = Block::from_bytes((data, 0)).unwrap();
+//! assert_eq!(Block { key: 0xFF, value: 0x1234 }, block);
+//! assert_eq!(data, block.to_bytes().unwrap());
+//! ```
+
+use alloc::vec::Vec;
+
+use no_std_io::io::{Cursor, Read, Seek, Write};
+
+use crate::reader::Reader;
+use crate::writer::Writer;
+use crate::DekuError;
+
+/// Decoding and encoding of the bytes of a field
+///
+/// `Ctx` is the value of `transform_ctx`, such as a key.
+pub trait Transform<Ctx = ()> {
+    /// Decode the bytes read from the input
+    fn decode(&self, input: &[u8], ctx: Ctx) -> Result<Vec<u8>, DekuError>;
+
+    /// Encode the bytes of the field before writing them
+    fn encode(&self, input: &[u8], ctx: Ctx) -> Result<Vec<u8>, DekuError>;
+}
+
+/// Read `bytes` bytes, or the rest of the input when `None`, and run `f` on the bytes decoded
+/// by `transform`
+pub fn read<R, T, X, Ctx, F>(
+    reader: &mut Reader<R>,
+    transform: &X,
+    ctx: Ctx,
+    bytes: Option<usize>,
+    f: F,
+) -> Result<T, DekuError>
+where
+    R: Read + Seek,
+    X: Transform<Ctx> + ?Sized,
+    F: FnOnce(&mut Reader<Cursor<&[u8]>>) -> Result<T, DekuError>,
+{
+    let input = reader.read_window(bytes)?;
+    let data = transform.decode(&input, ctx)?;
+    let mut inner = Reader::new(Cursor::new(data.as_slice()));
+    f(&mut inner)
+}
+
+/// Run `f` into a buffer and write it encoded by `transform`, failing with
+/// [InvalidParam](DekuError::InvalidParam) if `bytes` is given and isn't the encoded size
+///
+/// The last byte written by `f` is completed with zeros.
+pub fn write<W, X, Ctx, F>(
+    writer: &mut Writer<W>,
+    transform: &X,
+    ctx: Ctx,
+    bytes: Option<usize>,
+    f: F,
+) -> Result<(), DekuError>
+where
+    W: Write + Seek,
+    X: Transform<Ctx> + ?Sized,
+    F: FnOnce(&mut Writer<Cursor<&mut Vec<u8>>>) -> Result<(), DekuError>,
+{
+    let mut data = Vec::new();
+    let mut inner = Writer::new(Cursor::new(&mut data));
+    f(&mut inner)?;
+    inner.finalize()?;
+
+    writer.write_encoded(&transform.encode(&data, ctx)?, bytes)
+}
+
+#[cfg(all(test, feature = "std"))]
+mod tests {
+    use super::*;
+    use crate::ctx::{Endian, Limit};
+    use crate::{DekuReader, DekuWriter};
+
+    /// Reverse the bytes, failing on empty input
+    struct Reverse;
+
+    impl Transform for Reverse {
+        fn decode(&self, input: &[u8], _: ()) -> Result<Vec<u8>, DekuError> {
+            if input.is_empty() {
+                return Err(crate::deku_error!(DekuError::Parse, "empty"));
+            }
+            Ok(input.iter().rev().copied().collect())
+        }
+
+        fn encode(&self, input: &[u8], ctx: ()) -> Result<Vec<u8>, DekuError> {
+            self.decode(input, ctx)
+        }
+    }
+
+    #[test]
+    fn test_round_trip() {
+        let value: Vec<u16> = vec![0x0102, 0x0304];
+
+        let mut out = Vec::new();
+        let mut writer = Writer::new(Cursor::new(&mut out));
+        write(&mut writer, &Reverse, (), None, |writer| {
+            value.to_writer(writer, Endian::Big)
+        })
+        .unwrap();
+        assert_eq!(vec![0x04, 0x03, 0x02, 0x01], out);
+
+        out.push(0xAA);
+        let mut cursor = Cursor::new(out.as_slice());
+        let mut reader = Reader::new(&mut cursor);
+        let res = read(&mut reader, &Reverse, (), Some(4), |reader| {
+            Vec::<u16>::from_reader_with_ctx(reader, (Limit::end(), Endian::Big))
+        })
+        .unwrap();
+        assert_eq!(value, res);
+        assert_eq!(32, reader.bits_read);
+    }
+
+    #[test]
+    fn test_rest() {
+        let mut cursor = Cursor::new([0x01, 0x02, 0x03].as_slice());
+        let mut reader = Reader::new(&mut cursor);
+        let res = read(&mut reader, &Reverse, (), None, |reader| {
+            u8::from_reader_with_ctx(reader, ())
+        })
+        .unwrap();
+        assert_eq!(0x03, res);
+        assert!(reader.end());
+        assert_eq!(24, reader.bits_read);
+
+        let res = read(&mut reader, &Reverse, (), None, |reader| {
+            u8::from_reader_with_ctx(reader, ())
+        });
+        assert!(matches!(res, Err(DekuError::Parse(_))));
+    }
+
+    #[test]
+    fn test_rest_chunks() {
+        // longer than a chunk, after a byte buffered by `end`
+        let input: Vec<u8> = (0..=200).collect();
+        let mut cursor = Cursor::new(input.as_slice());
+        let mut reader = Reader::new(&mut cursor);
+        assert!(!reader.end());
+        let res = read(&mut reader, &Reverse, (), None, |reader| {
+            Vec::<u8>::from_reader_with_ctx(reader, Limit::end())
+        })
+        .unwrap();
+        assert_eq!(input.iter().rev().copied().collect::<Vec<_>>(), res);
+        assert_eq!(input.len() * 8, reader.bits_read);
+    }
+}
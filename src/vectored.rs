@@ -0,0 +1,302 @@
+//! Scatter-gather writing, borrowing large byte fields instead of copying them
+//!
+//! [IoSliceWriter] collects the written bytes as a list of slices: small writes, such as
+//! bitfields and primitives, are coalesced into owned chunks, while large byte slices written
+//! with [Writer::write_bytes_borrowed] are kept as references into the written value. The
+//! slices can then be sent in a single vectored write, see
+//! [DekuContainerWrite::to_io_slices](crate::DekuContainerWrite::to_io_slices).
+//!
+//! ```rust
+//! # #[cfg(feature = "std")]
+//! # fn main() {
+//! use deku::prelude::*;
+//!
+//! #[derive(DekuRead, DekuWrite)]
+//! #[deku(endian = "big")]
+//! struct Packet {
+//!     #[deku(update = "self.payload.len()")]
+//!     len: u16,
+//!     #[deku(count = "len")]
+//!     payload: Vec<u8>,
+//! }
+//!
+//! let packet = Packet { len: 1024, payload: vec![0xaa; 1024] };
+//! let slices = packet.to_io_slices().unwrap();
+//! // the header is owned, the payload is borrowed from `packet`
+//! assert_eq!(slices.slices().count(), 2);
+//! assert_eq!(slices.len(), 1026);
+//!
+//! let mut out = Vec::new();
+//! slices.write_to(&mut out).unwrap();
+//! assert_eq!(out, packet.to_bytes().unwrap());
+//! # }
+//! #
+//! # #[cfg(not(feature = "std"))]
+//! # fn main() {}
+//! ```
+
+use core::ops::Range;
+
+use alloc::vec::Vec;
+
+use crate::no_std_io::{Error, ErrorKind, Result, Seek, SeekFrom, Write};
+use crate::writer::Writer;
+use crate::DekuError;
+
+/// Default size from which [IoSliceWriter::write_borrowed] borrows instead of copying
+pub const DEFAULT_BORROW_THRESHOLD: usize = 256;
+
+#[derive(Debug, Clone)]
+enum Segment<'a> {
+    /// Range of the owned buffer
+    Owned(Range<usize>),
+    /// Bytes borrowed from the written value
+    Borrowed(&'a [u8]),
+}
+
+/// [Write]r collecting a list of owned and borrowed byte slices
+///
+/// Bytes written through [Write] are appended to an owned buffer, slices written with
+/// [write_borrowed](Self::write_borrowed) are kept by reference when they are at least
+/// [threshold](Self::with_threshold) bytes long.
+///
+/// Only seeks to the current position, or forward from it, are supported: skipped bytes are
+/// written as zeros. Any other seek returns an [ErrorKind::Unsupported] error.
+#[derive(Debug, Clone)]
+pub struct IoSliceWriter<'a> {
+    buf: Vec<u8>,
+    segments: Vec<Segment<'a>>,
+    /// Start of the bytes in `buf` which aren't in `segments` yet
+    pending: usize,
+    len: usize,
+    threshold: usize,
+}
+
+impl Default for IoSliceWriter<'_> {
+    fn default() -> Self {
+        Self::new()
+    }
+}
+
+impl<'a> IoSliceWriter<'a> {
+    /// Create a new `IoSliceWriter`, borrowing slices of at least [DEFAULT_BORROW_THRESHOLD] bytes
+    #[inline]
+    pub fn new() -> Self {
+        Self::with_threshold(DEFAULT_BORROW_THRESHOLD)
+    }
+
+    /// Create a new `IoSliceWriter`, borrowing slices of at least `threshold` bytes
+    #[inline]
+    pub fn with_threshold(threshold: usize) -> Self {
+        Self {
+            buf: Vec::new(),
+            segments: Vec::new(),
+            pending: 0,
+            len: 0,
+            threshold,
+        }
+    }
+
+    /// Total bytes written
+    #[inline]
+    pub fn len(&self) -> usize {
+        self.len
+    }
+
+    /// No bytes were written
+    #[inline]
+    pub fn is_empty(&self) -> bool {
+        self.len == 0
+    }
+
+    /// Append `buf`, by reference if it is at least `threshold` bytes long
+    pub fn write_borrowed(&mut self, buf: &'a [u8]) {
+        if buf.len() < self.threshold || buf.is_empty() {
+            self.write_owned(buf);
+            return;
+        }
+        self.flush_pending();
+        self.segments.push(Segment::Borrowed(buf));
+        self.len += buf.len();
+    }
+
+    /// Written bytes, in order
+    pub fn slices(&self) -> impl Iterator<Item = &[u8]> + '_ {
+        let pending = &self.buf[self.pending..];
+        self.segments
+            .iter()
+            .map(|segment| match segment {
+                Segment::Owned(range) => &self.buf[range.clone()],
+                Segment::Borrowed(buf) => *buf,
+            })
+            .chain((!pending.is_empty()).then_some(pending))
+    }
+
+    /// Copy the written bytes into a `Vec`
+    pub fn to_vec(&self) -> Vec<u8> {
+        let mut out = Vec::with_capacity(self.len);
+        for slice in self.slices() {
+            out.extend_from_slice(slice);
+        }
+        out
+    }
+
+    /// Write all slices to `writer`, with as few calls to
+    /// [write_vectored](std::io::Write::write_vectored) as it allows
+    #[cfg(feature = "std")]
+    pub fn write_to<W: std::io::Write>(&self, writer: &mut W) -> std::io::Result<()> {
+        let mut slices: Vec<std::io::IoSlice<'_>> =
+            self.slices().map(std::io::IoSlice::new).collect();
+        let mut slices = &mut slices[..];
+        while !slices.is_empty() {
+            match writer.write_vectored(slices) {
+                Ok(0) => {
+                    return Err(std::io::Error::new(
+                        std::io::ErrorKind::WriteZero,
+                        "failed to write whole buffer",
+                    ))
+                }
+                Ok(n) => std::io::IoSlice::advance_slices(&mut slices, n),
+                Err(e) if e.kind() == std::io::ErrorKind::Interrupted => {}
+                Err(e) => return Err(e),
+            }
+        }
+        Ok(())
+    }
+
+    fn write_owned(&mut self, buf: &[u8]) {
+        self.buf.extend_from_slice(buf);
+        self.len += buf.len();
+    }
+
+    /// Close the owned bytes written since the last borrowed slice into a segment
+    fn flush_pending(&mut self) {
+        if self.pending < self.buf.len() {
+            self.segments
+                .push(Segment::Owned(self.pending..self.buf.len()));
+            self.pending = self.buf.len();
+        }
+    }
+}
+
+impl Write for IoSliceWriter<'_> {
+    #[inline]
+    fn write(&mut self, buf: &[u8]) -> Result<usize> {
+        self.write_owned(buf);
+        Ok(buf.len())
+    }
+
+    #[inline]
+    fn flush(&mut self) -> Result<()> {
+        Ok(())
+    }
+}
+
+impl Seek for IoSliceWriter<'_> {
+    fn seek(&mut self, pos: SeekFrom) -> Result<u64> {
+        let len = self.len as u64;
+        let target = match pos {
+            SeekFrom::Start(n) => Some(n),
+            SeekFrom::Current(n) | SeekFrom::End(n) => len.checked_add_signed(n),
+        };
+        match target {
+            Some(n) if n >= len => {
+                let skip = usize::try_from(n - len)
+                    .map_err(|_| Error::new(ErrorKind::InvalidInput, "seek out of range"))?;
+                self.buf.resize(self.buf.len() + skip, 0);
+                self.len += skip;
+                Ok(n)
+            }
+            _ => Err(Error::new(
+                ErrorKind::Unsupported,
+                "backward seek on IoSliceWriter",
+            )),
+        }
+    }
+}
+
+impl<'a> Writer<IoSliceWriter<'a>> {
+    /// Write `buf` into `Writer`, borrowing it instead of copying it if it is large enough
+    ///
+    /// `buf` is copied when there are leftover bits, as it has to be shifted.
+    #[inline]
+    pub fn write_bytes_borrowed(&mut self, buf: &'a [u8]) -> core::result::Result<(), DekuError> {
+        #[cfg(feature = "bits")]
+        if !self.leftover.0.is_empty() {
+            return self.write_bytes(buf);
+        }
+
+        #[cfg(feature = "logging")]
+        log::trace!("writing {} borrowed bytes", buf.len());
+
+        self.inner.write_borrowed(buf);
+        self.bits_written += buf.len() * 8;
+        Ok(())
+    }
+}
+
+#[cfg(feature = "std")]
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn test_coalesce() {
+        let big = [0xaa; 4];
+        let mut writer = IoSliceWriter::with_threshold(4);
+        writer.write_all(&[1, 2]).unwrap();
+        writer.write_borrowed(&[3]);
+        writer.write_borrowed(&big);
+        writer.write_all(&[4]).unwrap();
+        writer.write_borrowed(&big);
+        writer.write_borrowed(&big);
+
+        let slices: Vec<&[u8]> = writer.slices().collect();
+        assert_eq!(slices, [&[1, 2, 3][..], &big, &[4], &big, &big],);
+        assert!(core::ptr::eq(slices[1], &big[..]));
+        assert_eq!(writer.len(), 16);
+        assert_eq!(writer.to_vec().len(), 16);
+    }
+
+    #[test]
+    fn test_seek() {
+        let mut writer = IoSliceWriter::new();
+        writer.write_all(&[1]).unwrap();
+        assert_eq!(writer.stream_position().unwrap(), 1);
+        assert_eq!(writer.seek(SeekFrom::Current(2)).unwrap(), 3);
+        assert_eq!(writer.seek(SeekFrom::Start(4)).unwrap(), 4);
+        assert_eq!(
+            writer.seek(SeekFrom::Start(0)).unwrap_err().kind(),
+            ErrorKind::Unsupported
+        );
+        writer.write_all(&[2]).unwrap();
+        assert_eq!(writer.to_vec(), [1, 0, 0, 0, 2]);
+    }
+
+    #[test]
+    fn test_write_to() {
+        let big = [0xbb; 300];
+        let mut writer = IoSliceWriter::new();
+        writer.write_all(&[1]).unwrap();
+        writer.write_borrowed(&big);
+        writer.write_all(&[2]).unwrap();
+
+        // a writer accepting a few bytes per call
+        struct Trickle(Vec<u8>);
+        impl std::io::Write for Trickle {
+            fn write(&mut self, buf: &[u8]) -> std::io::Result<usize> {
+                let n = buf.len().min(7);
+                self.0.extend_from_slice(&buf[..n]);
+                Ok(n)
+            }
+            fn flush(&mut self) -> std::io::Result<()> {
+                Ok(())
+            }
+        }
+
+        let mut out = Trickle(Vec::new());
+        writer.write_to(&mut out).unwrap();
+        assert_eq!(out.0, writer.to_vec());
+        assert_eq!(out.0.len(), 302);
+    }
+}
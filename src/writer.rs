@@ -10,6 +10,7 @@ use log;
 #[cfg(feature = "bits")]
 use crate::ctx::Order;
 
+use crate::noseek::NoSeek;
 use crate::DekuError;
 
 #[cfg(feature = "bits")]
@@ -17,14 +18,52 @@ const fn bits_of<T>() -> usize {
     core::mem::size_of::<T>().saturating_mul(<u8>::BITS as usize)
 }
 
+/// Fill used when writing padding
+///
+/// The fill starts at the beginning of each padding. When padding bits with
+/// [`Order::Lsb0`](crate::ctx::Order::Lsb0), a partial byte takes the low bits of the fill.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Hash)]
+pub enum Pad {
+    /// Repeat a single byte
+    Value(u8),
+    /// Repeat a sequence of bytes, an empty pattern pads with zeros
+    Pattern(&'static [u8]),
+}
+
+impl Pad {
+    /// Zero padding, the default
+    pub const ZERO: Self = Self::Value(0);
+
+    /// Byte of the fill at `index` from the start of the padding
+    #[inline]
+    pub const fn byte(&self, index: usize) -> u8 {
+        match self {
+            Self::Value(value) => *value,
+            Self::Pattern([]) => 0,
+            Self::Pattern(pattern) => pattern[index % pattern.len()],
+        }
+    }
+}
+
+impl Default for Pad {
+    fn default() -> Self {
+        Self::ZERO
+    }
+}
+
 /// Container to use with `from_reader`
-pub struct Writer<W: Write + Seek> {
+///
+/// Only [DekuWriter::to_writer](crate::DekuWriter::to_writer) requires `W` to implement [Seek],
+/// see [DekuWriterNoSeek](crate::DekuWriterNoSeek) to write to a `W` without it.
+pub struct Writer<W: Write> {
     pub(crate) inner: W,
     /// Leftover bits
     #[cfg(feature = "bits")]
     pub leftover: (BoundedBitVec<[u8; 1], Msb0>, Order),
     /// Total bits written
     pub bits_written: usize,
+    /// Fill used by `finalize`
+    pad: Pad,
 }
 
 impl<W: Write + Seek> Seek for Writer<W> {
@@ -43,7 +82,7 @@ impl<W: Write + Seek> Seek for Writer<W> {
     }
 }
 
-impl<W: Write + Seek> Writer<W> {
+impl<W: Write> Writer<W> {
     /// Create a new `Writer`
     #[inline]
     pub fn new(inner: W) -> Self {
@@ -52,7 +91,52 @@ impl<W: Write + Seek> Writer<W> {
             #[cfg(feature = "bits")]
             leftover: (BoundedBitVec::new(), Order::Msb0),
             bits_written: 0,
+            pad: Pad::ZERO,
+        }
+    }
+
+    /// Run `f` with a `Writer` over [NoSeek] sharing the position and leftover bits of `self`
+    pub(crate) fn no_seek<F>(&mut self, f: F) -> Result<(), DekuError>
+    where
+        F: FnOnce(&mut Writer<NoSeek<&mut W>>) -> Result<(), DekuError>,
+    {
+        let mut writer = Writer {
+            inner: NoSeek::new(&mut self.inner),
+            #[cfg(feature = "bits")]
+            leftover: core::mem::replace(&mut self.leftover, (BoundedBitVec::new(), Order::Msb0)),
+            bits_written: self.bits_written,
+            pad: self.pad,
+        };
+        let res = f(&mut writer);
+
+        #[cfg(feature = "bits")]
+        {
+            self.leftover = writer.leftover;
         }
+        self.bits_written = writer.bits_written;
+        res
+    }
+
+    /// Set the fill used by `finalize` to complete the last byte
+    #[inline]
+    pub fn set_pad(&mut self, pad: Pad) {
+        self.pad = pad;
+    }
+
+    /// Fill used by `finalize` to complete the last byte
+    #[inline]
+    pub fn pad(&self) -> Pad {
+        self.pad
+    }
+
+    /// Total bits written, including leftover bits not yet flushed to the inner writer
+    #[inline]
+    pub fn bit_position(&self) -> usize {
+        #[cfg(feature = "bits")]
+        return self.bits_written + self.leftover.0.len();
+
+        #[cfg(not(feature = "bits"))]
+        self.bits_written
     }
 
     /// Return the unused bits
@@ -398,16 +482,93 @@ impl<W: Write + Seek> Writer<W> {
         Ok(())
     }
 
-    /// Write all remaining bits into `Writer`, adding empty bits to the end so that we can write
-    /// into a byte buffer
+    /// Write the encoded bytes of a field, failing if they aren't `bytes` bytes long when given
+    #[cfg(feature = "alloc")]
+    pub(crate) fn write_encoded(
+        &mut self,
+        buf: &[u8],
+        bytes: Option<usize>,
+    ) -> Result<(), DekuError> {
+        if let Some(bytes) = bytes {
+            if buf.len() != bytes {
+                return Err(crate::deku_error!(
+                    DekuError::InvalidParam,
+                    "encoded size does not match bytes",
+                    "{} encoded bytes, expected {}",
+                    buf.len(),
+                    bytes
+                ));
+            }
+        }
+        self.write_bytes(buf)
+    }
+
+    /// Write `bytes` bytes of padding filled with `pad`
+    #[inline]
+    pub fn write_pad_bytes(&mut self, bytes: usize, pad: Pad) -> Result<(), DekuError> {
+        self.write_pad_bytes_from(0, bytes, pad)
+    }
+
+    /// Write `bytes` bytes of padding, starting at byte `index` of the fill
+    fn write_pad_bytes_from(
+        &mut self,
+        mut index: usize,
+        bytes: usize,
+        pad: Pad,
+    ) -> Result<(), DekuError> {
+        let mut buf = [0u8; 64];
+        let mut remaining = bytes;
+        while remaining > 0 {
+            let chunk = core::cmp::min(buf.len(), remaining);
+            for (i, byte) in buf[..chunk].iter_mut().enumerate() {
+                *byte = pad.byte(index + i);
+            }
+            self.write_bytes(&buf[..chunk])?;
+            index += chunk;
+            remaining -= chunk;
+        }
+        Ok(())
+    }
+
+    /// Write `bits` bits of padding filled with `pad`
+    #[cfg(feature = "bits")]
+    pub fn write_pad_bits(&mut self, bits: usize, pad: Pad, order: Order) -> Result<(), DekuError> {
+        let mut index = 0;
+        let mut remaining = bits;
+        while remaining > 0 {
+            // whole bytes of the fill are written the same in both orders
+            // unless they have to be merged with leftover `Lsb0` bits
+            if remaining >= 8 && (order == Order::Msb0 || self.leftover.0.is_empty()) {
+                let bytes = remaining / 8;
+                self.write_pad_bytes_from(index, bytes, pad)?;
+                index += bytes;
+                remaining -= bytes * 8;
+                continue;
+            }
+
+            let byte = pad.byte(index);
+            let take = core::cmp::min(8, remaining);
+            let bits = byte.view_bits::<Msb0>();
+            let bits = match order {
+                Order::Msb0 => &bits[..take],
+                Order::Lsb0 => &bits[8 - take..],
+            };
+            self.write_bits_order(bits, order)?;
+            index += 1;
+            remaining -= take;
+        }
+        Ok(())
+    }
+
+    /// Write all remaining bits into `Writer`, adding bits filled with [`pad`](Self::pad) to the
+    /// end so that we can write into a byte buffer
     #[inline]
     pub fn finalize(&mut self) -> Result<(), DekuError> {
         #[cfg(feature = "bits")]
         {
-            let padded = bitarr!(u8, Msb0; 0; 8);
             debug_assert!(self.leftover.0.len() < 8);
             let len = (8 - self.leftover.0.len()) % 8;
-            self.write_bits_order(&padded[..len], self.leftover.1)?;
+            self.write_pad_bits(len, self.pad, self.leftover.1)?;
         }
         Ok(())
     }
@@ -551,4 +712,41 @@ mod tests {
         writer.finalize().unwrap();
         assert_eq!(out_buf.into_inner(), [0b1001_0101, 0b0000_1010]);
     }
+
+    #[test]
+    fn test_pad() {
+        let mut out_buf = Cursor::new(vec![]);
+        let mut writer = Writer::new(&mut out_buf);
+        writer.set_pad(Pad::Value(0xff));
+        writer
+            .write_bits_order(&bitvec![u8, Msb0; 0, 0, 0], Order::Msb0)
+            .unwrap();
+        writer.finalize().unwrap();
+        assert_eq!(out_buf.into_inner(), [0b0001_1111]);
+
+        let mut out_buf = Cursor::new(vec![]);
+        let mut writer = Writer::new(&mut out_buf);
+        writer.set_pad(Pad::Value(0xff));
+        writer
+            .write_bits_order(&bitvec![u8, Msb0; 0, 0, 0], Order::Lsb0)
+            .unwrap();
+        writer.finalize().unwrap();
+        assert_eq!(out_buf.into_inner(), [0b1111_1000]);
+
+        let mut out_buf = Cursor::new(vec![]);
+        let mut writer = Writer::new(&mut out_buf);
+        writer
+            .write_pad_bytes(5, Pad::Pattern(b"\xde\xad"))
+            .unwrap();
+        writer
+            .write_pad_bits(12, Pad::Pattern(b"\xab\xcd"), Order::Msb0)
+            .unwrap();
+        writer
+            .write_pad_bits(4, Pad::Pattern(b"\xab\xcd"), Order::Lsb0)
+            .unwrap();
+        assert_eq_hex!(
+            out_buf.into_inner(),
+            [0xde, 0xad, 0xde, 0xad, 0xde, 0xab, 0xcb]
+        );
+    }
 }
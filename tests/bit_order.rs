@@ -565,6 +565,29 @@ mod tests {
         assert_eq!(data.to_bytes().unwrap(), bytes);
     }
 
+    #[test]
+    fn test_bit_order_be_wide_container() {
+        #[derive(DekuRead, DekuWrite, Debug, PartialEq)]
+        #[deku(endian = "big", bit_order = "lsb")]
+        pub struct Wide {
+            #[deku(bits = "24")]
+            value: u32,
+            #[deku(bits = "16")]
+            other: u64,
+        }
+
+        let bytes = [0x01, 0x02, 0x03, 0x04, 0x05];
+        let data = Wide::try_from(bytes.as_slice()).unwrap();
+        assert_eq!(
+            data,
+            Wide {
+                value: 0x010203,
+                other: 0x0405,
+            }
+        );
+        assert_eq!(data.to_bytes().unwrap(), bytes);
+    }
+
     #[cfg(feature = "descriptive-errors")]
     #[test]
     #[should_panic(expected = "bit size of input is larger than requested size: 32 exceeds 31")]
@@ -4,13 +4,18 @@ mod test_assert;
 mod test_assert_eq;
 #[cfg(feature = "bits")]
 mod test_bitfield_values_range_check;
+#[cfg(feature = "deflate")]
+mod test_compress;
 mod test_cond;
 mod test_ctx;
 mod test_limits;
 mod test_map;
 mod test_padding;
+mod test_reserved;
 mod test_skip;
+mod test_struct_size;
 mod test_temp;
 #[cfg(feature = "bits")]
 mod test_temp_value_with_cond;
+mod test_transform;
 mod test_update;
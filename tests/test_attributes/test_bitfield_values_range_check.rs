@@ -532,3 +532,33 @@ fn check_non_bitfield_little_signed_i16_decode_encode_positive_negative_value()
     let mut cursor = Cursor::new(&buffer[0..1]);
     assert!(TestStruct::from_reader((&mut cursor, 0)).is_err());
 }
+
+#[test]
+fn check_signed_bitfield_sign_extension() {
+    #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+    pub struct TestStruct {
+        #[deku(bits = 5)]
+        pub a: i8,
+        #[deku(bits = 3)]
+        pub b: i8,
+        #[deku(bytes = 3, endian = "big")]
+        pub c: i32,
+    }
+
+    let buffer = vec![0b11101_011, 0xFF, 0xFF, 0xFE];
+    let (_, mut test_struct) = TestStruct::from_bytes((&buffer, 0)).unwrap();
+    assert_eq!(test_struct, TestStruct { a: -3, b: 3, c: -2 });
+    assert_eq!(buffer, test_struct.to_bytes().unwrap());
+
+    // values that do not fit in the field are rejected instead of truncated
+    test_struct.a = 16;
+    assert!(matches!(
+        test_struct.to_bytes(),
+        Err(DekuError::InvalidParam(_))
+    ));
+    test_struct.a = -17;
+    assert!(matches!(
+        test_struct.to_bytes(),
+        Err(DekuError::InvalidParam(_))
+    ));
+}
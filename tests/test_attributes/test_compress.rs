@@ -0,0 +1,166 @@
+use deku::compression::Compression;
+use deku::prelude::*;
+
+#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[deku(ctx = "endian: deku::ctx::Endian", endian = "endian")]
+struct Record {
+    id: u16,
+    value: u32,
+}
+
+#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[deku(endian = "big")]
+struct Chunk {
+    #[deku(update = "Compression::Zlib.compress(&self.records_data()?).len()")]
+    len: usize,
+    count: u8,
+    #[deku(compress = "zlib", bytes = "len", count = "count")]
+    records: Vec<Record>,
+    trailer: u8,
+}
+
+impl Chunk {
+    fn records_data(&self) -> Result<Vec<u8>, DekuError> {
+        let mut out = Vec::new();
+        let mut writer = Writer::new(std::io::Cursor::new(&mut out));
+        self.records
+            .to_writer(&mut writer, deku::ctx::Endian::Big)?;
+        writer.finalize()?;
+        Ok(out)
+    }
+}
+
+#[test]
+fn test_compress() {
+    let records: Vec<Record> = (0..20).map(|id| Record { id, value: 0xAABB }).collect();
+    let mut chunk = Chunk {
+        len: 0,
+        count: records.len() as u8,
+        records,
+        trailer: 0xFF,
+    };
+    chunk.update().unwrap();
+    assert!(chunk.len > 0 && chunk.len < 20 * 6);
+
+    let bytes = chunk.to_bytes().unwrap();
+    assert_eq!(8 + 1 + chunk.len + 1, bytes.len());
+    assert_eq!(0xFF, bytes[bytes.len() - 1]);
+
+    let (_rest, ret_read) = Chunk::from_bytes((&bytes, 0)).unwrap();
+    assert_eq!(chunk, ret_read);
+}
+
+#[test]
+fn test_compress_len_mismatch() {
+    let records: Vec<Record> = (0..20).map(|id| Record { id, value: 0xAABB }).collect();
+    let mut chunk = Chunk {
+        len: 0,
+        count: records.len() as u8,
+        records,
+        trailer: 0xFF,
+    };
+    chunk.update().unwrap();
+
+    // a stale `len` would not read back
+    chunk.len -= 1;
+    assert!(matches!(chunk.to_bytes(), Err(DekuError::InvalidParam(_))));
+}
+
+#[test]
+fn test_compress_rest() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    struct Log {
+        version: u8,
+        #[deku(compress = "deflate", read_all)]
+        lines: Vec<u8>,
+    }
+
+    let value = Log {
+        version: 1,
+        lines: b"line\nline\nline\nline\nline\n".to_vec(),
+    };
+    let bytes = value.to_bytes().unwrap();
+    assert!(bytes.len() < value.lines.len());
+
+    let (_rest, ret_read) = Log::from_bytes((&bytes, 0)).unwrap();
+    assert_eq!(value, ret_read);
+}
+
+#[test]
+fn test_compress_invalid() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    struct Data {
+        #[deku(compress = "zlib", bytes = 2)]
+        value: u8,
+    }
+
+    assert!(matches!(
+        Data::from_bytes((&[0x00, 0x00], 0)),
+        Err(DekuError::Parse(_))
+    ));
+}
+
+#[cfg(feature = "lz4")]
+#[test]
+fn test_compress_lz4() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    struct Data {
+        #[deku(compress = "lz4")]
+        value: [u8; 64],
+    }
+
+    let value = Data { value: [0x55; 64] };
+    let bytes = value.to_bytes().unwrap();
+    assert_eq!(64, u32::from_le_bytes(bytes[..4].try_into().unwrap()));
+
+    let (_rest, ret_read) = Data::from_bytes((&bytes, 0)).unwrap();
+    assert_eq!(value, ret_read);
+}
+
+#[test]
+fn test_compress_limit() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    struct Data {
+        limit: u16,
+        #[deku(compress = "zlib", compress_limit = "*limit", read_all)]
+        data: Vec<u8>,
+    }
+
+    // a megabyte of zeros compresses to about a kilobyte
+    let value = Data {
+        limit: 0,
+        data: vec![0; 1024 * 1024],
+    };
+    let bytes = value.to_bytes().unwrap();
+    assert!(bytes.len() < 8 * 1024);
+    assert!(matches!(
+        Data::from_bytes((&bytes, 0)),
+        Err(DekuError::Parse(_))
+    ));
+
+    let value = Data {
+        limit: 16,
+        data: vec![0; 16],
+    };
+    let bytes = value.to_bytes().unwrap();
+    let (_rest, ret_read) = Data::from_bytes((&bytes, 0)).unwrap();
+    assert_eq!(value, ret_read);
+}
+
+#[test]
+fn test_compress_default_limit() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    struct Data {
+        #[deku(compress = "deflate", read_all)]
+        data: Vec<u8>,
+    }
+
+    let value = Data {
+        data: vec![0; deku::compression::DEFAULT_LIMIT + 1],
+    };
+    let bytes = value.to_bytes().unwrap();
+    assert!(matches!(
+        Data::from_bytes((&bytes, 0)),
+        Err(DekuError::Parse(_))
+    ));
+}
@@ -0,0 +1,108 @@
+use deku::prelude::*;
+
+#[test]
+fn test_pad_value() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    struct TestStruct {
+        #[deku(pad_bytes_after = "2", pad_value = "0xff")]
+        field_a: u8,
+        #[deku(pad_bytes_before = "1")]
+        field_b: u8,
+    }
+
+    let data: Vec<u8> = vec![0xaa, 0x12, 0x34, 0x56, 0xbb];
+    let (_rest, ret_read) = TestStruct::from_bytes((&data, 0)).unwrap();
+    assert_eq!(
+        TestStruct {
+            field_a: 0xaa,
+            field_b: 0xbb,
+        },
+        ret_read
+    );
+
+    let ret_write = ret_read.to_bytes().unwrap();
+    assert_eq!(vec![0xaa, 0xff, 0xff, 0x00, 0xbb], ret_write);
+}
+
+#[test]
+fn test_pad_pattern() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    struct TestStruct {
+        field_a: u8,
+        #[deku(pad_bytes_before = "5", pad_pattern = b"\xde\xad")]
+        field_b: u8,
+    }
+
+    let value = TestStruct {
+        field_a: 0xaa,
+        field_b: 0xbb,
+    };
+    assert_eq!(
+        vec![0xaa, 0xde, 0xad, 0xde, 0xad, 0xde, 0xbb],
+        value.to_bytes().unwrap()
+    );
+}
+
+#[test]
+fn test_pad_top_level() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    #[deku(pad_value = "0xff", bytes = 4)]
+    struct TestStruct {
+        #[deku(pad_bytes_after = "1")]
+        field_a: u8,
+        #[deku(pad_bytes_after = "1", pad_value = "0x55")]
+        field_b: u8,
+    }
+
+    let value = TestStruct {
+        field_a: 0xaa,
+        field_b: 0xbb,
+    };
+    let bytes = value.to_bytes().unwrap();
+    assert_eq!(vec![0xaa, 0xff, 0xbb, 0x55], bytes);
+
+    let (_rest, ret_read) = TestStruct::from_bytes((&bytes, 0)).unwrap();
+    assert_eq!(value, ret_read);
+}
+
+#[test]
+#[cfg(feature = "bits")]
+fn test_pad_finalize() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    #[deku(pad_value = "0xff")]
+    struct TestStruct {
+        #[deku(bits = 4)]
+        field_a: u8,
+        #[deku(bits = 2, pad_bits_before = "1")]
+        field_b: u8,
+    }
+
+    let value = TestStruct {
+        field_a: 0b1010,
+        field_b: 0b00,
+    };
+    assert_eq!(vec![0b1010_1001], value.to_bytes().unwrap());
+
+    let mut buf = [0u8; 1];
+    assert_eq!(1, value.to_slice(&mut buf).unwrap());
+    assert_eq!([0b1010_1001], buf);
+}
+
+#[test]
+#[cfg(feature = "bits")]
+fn test_pad_bits_lsb0() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    #[deku(bit_order = "lsb")]
+    struct TestStruct {
+        #[deku(bits = 2)]
+        field_a: u8,
+        #[deku(bits = 2, pad_bits_before = "4", pad_value = "0b1001")]
+        field_b: u8,
+    }
+
+    let value = TestStruct {
+        field_a: 0b11,
+        field_b: 0b00,
+    };
+    assert_eq!(vec![0b00_1001_11], value.to_bytes().unwrap());
+}
@@ -0,0 +1,122 @@
+use core::convert::{TryFrom, TryInto};
+
+use deku::prelude::*;
+#[cfg(feature = "bits")]
+use rstest::rstest;
+
+#[cfg(feature = "bits")]
+#[derive(PartialEq, Debug, DekuRead, DekuWrite, DekuSize)]
+#[deku(endian = "big")]
+struct Register {
+    #[deku(bits = 3)]
+    mode: u8,
+    #[deku(reserved = 5)]
+    reserved: (),
+    #[deku(reserved = 4, value = 0b1010)]
+    pattern: (),
+    #[deku(bits = 12)]
+    value: u16,
+}
+
+#[cfg(feature = "bits")]
+#[rstest(input, expected,
+    case(&[0b101_00000, 0b1010_0001, 0x23], Ok(Register { mode: 0b101, reserved: (), pattern: (), value: 0x123 })),
+    case(&[0b101_00001, 0b1010_0001, 0x23], Err(deku::deku_error!(DekuError::Assertion, "Reserved bits mismatch", "Register.reserved: expected 0x0, found 0x1"))),
+    case(&[0b101_00000, 0b0000_0001, 0x23], Err(deku::deku_error!(DekuError::Assertion, "Reserved bits mismatch", "Register.pattern: expected 0xa, found 0x0"))),
+)]
+fn test_reserved_bits(input: &[u8], expected: Result<Register, DekuError>) {
+    let ret_read = Register::try_from(input);
+    match expected {
+        Ok(expected) => {
+            assert_eq!(expected, ret_read.unwrap());
+            let ret_write: Vec<u8> = expected.try_into().unwrap();
+            assert_eq!(input.to_vec(), ret_write);
+        }
+        Err(expected) => {
+            let err = ret_read.unwrap_err();
+            #[cfg(feature = "descriptive-errors")]
+            assert_eq!(expected, err);
+            #[cfg(not(feature = "descriptive-errors"))]
+            assert!(matches!(
+                (expected, err),
+                (DekuError::Assertion(_), DekuError::Assertion(_))
+            ));
+        }
+    }
+}
+
+#[cfg(feature = "bits")]
+#[test]
+fn test_reserved_size() {
+    assert_eq!(24, Register::SIZE_BITS);
+}
+
+#[test]
+fn test_reserved_bytes() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite, DekuSize)]
+    #[deku(endian = "little")]
+    struct Header {
+        version: u8,
+        #[deku(reserved = 16, value = 0xFFFE)]
+        reserved: (),
+        length: u16,
+    }
+
+    assert_eq!(Some(5), Header::SIZE_BYTES);
+
+    let data: Vec<u8> = vec![0x01, 0xFE, 0xFF, 0x10, 0x00];
+    let ret_read = Header::try_from(data.as_slice()).unwrap();
+    assert_eq!(
+        Header {
+            version: 1,
+            reserved: (),
+            length: 0x10,
+        },
+        ret_read
+    );
+
+    let ret_write: Vec<u8> = ret_read.try_into().unwrap();
+    assert_eq!(data, ret_write);
+
+    let data: Vec<u8> = vec![0x01, 0x00, 0x00, 0x10, 0x00];
+    assert!(matches!(
+        Header::try_from(data.as_slice()),
+        Err(DekuError::Assertion(_))
+    ));
+}
+
+#[test]
+fn test_reserved_check_ignore() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    struct Ignore {
+        #[deku(reserved = 8, check = "ignore")]
+        reserved: (),
+        a: u8,
+    }
+
+    // mismatching reserved bits are accepted, and written back as `value`
+    let data: Vec<u8> = vec![0xFF, 0xAA];
+    let ret_read = Ignore::try_from(data.as_slice()).unwrap();
+    assert_eq!(0xAA, ret_read.a);
+
+    let ret_write: Vec<u8> = ret_read.try_into().unwrap();
+    assert_eq!(vec![0x00, 0xAA], ret_write);
+}
+
+#[test]
+fn test_reserved_check_warn() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    struct Warn {
+        #[deku(reserved = 8, check = "warn")]
+        reserved: (),
+        a: u8,
+    }
+
+    // mismatching reserved bits are accepted, and written back as `value`
+    let data: Vec<u8> = vec![0xFF, 0xAA];
+    let ret_read = Warn::try_from(data.as_slice()).unwrap();
+    assert_eq!(0xAA, ret_read.a);
+
+    let ret_write: Vec<u8> = ret_read.try_into().unwrap();
+    assert_eq!(vec![0x00, 0xAA], ret_write);
+}
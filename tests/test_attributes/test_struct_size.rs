@@ -0,0 +1,163 @@
+use core::convert::{TryFrom, TryInto};
+
+use deku::prelude::*;
+
+#[cfg(feature = "bits")]
+#[test]
+fn test_struct_bits() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite, DekuSize)]
+    #[deku(bits = 32, endian = "big")]
+    struct Register {
+        #[deku(bits = 4)]
+        mode: u8,
+        #[deku(bits = 12)]
+        count: u16,
+        status: u8,
+    }
+
+    assert_eq!(32, Register::SIZE_BITS);
+
+    let data: Vec<u8> = vec![0xA1, 0x23, 0x45, 0xFF, 0xBB];
+    let ((rest, offset), ret_read) = Register::from_bytes((&data, 0)).unwrap();
+    assert_eq!(
+        Register {
+            mode: 0xA,
+            count: 0x123,
+            status: 0x45,
+        },
+        ret_read
+    );
+    assert_eq!((&[0xBB][..], 0), (rest, offset));
+
+    // padding is written as zeros
+    let ret_write: Vec<u8> = ret_read.try_into().unwrap();
+    assert_eq!(vec![0xA1, 0x23, 0x45, 0x00], ret_write);
+}
+
+#[cfg(feature = "bits")]
+#[test]
+fn test_struct_bits_nested() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite, DekuSize)]
+    #[deku(bits = 6)]
+    struct Inner {
+        #[deku(bits = 3)]
+        a: u8,
+    }
+
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite, DekuSize)]
+    struct Outer {
+        inner: Inner,
+        #[deku(bits = 2)]
+        b: u8,
+    }
+
+    assert_eq!(8, Outer::SIZE_BITS);
+
+    let data: Vec<u8> = vec![0b101_111_10];
+    let ret_read = Outer::try_from(data.as_slice()).unwrap();
+    assert_eq!(
+        Outer {
+            inner: Inner { a: 0b101 },
+            b: 0b10,
+        },
+        ret_read
+    );
+
+    let ret_write: Vec<u8> = ret_read.try_into().unwrap();
+    assert_eq!(vec![0b101_000_10], ret_write);
+}
+
+#[test]
+fn test_struct_bytes() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite, DekuSize)]
+    #[deku(bytes = 4, magic = b"\x7f")]
+    struct Header {
+        version: u8,
+    }
+
+    assert_eq!(Some(4), Header::SIZE_BYTES);
+
+    let data: Vec<u8> = vec![0x7F, 0x02, 0xAA, 0xBB];
+    let ret_read = Header::try_from(data.as_slice()).unwrap();
+    assert_eq!(Header { version: 2 }, ret_read);
+
+    let ret_write: Vec<u8> = ret_read.try_into().unwrap();
+    assert_eq!(vec![0x7F, 0x02, 0x00, 0x00], ret_write);
+}
+
+#[test]
+fn test_struct_bytes_ctx() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    #[deku(ctx = "len: usize", bytes = "len")]
+    struct Record {
+        id: u8,
+    }
+
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    struct Table {
+        len: u8,
+        #[deku(ctx = "*len as usize")]
+        first: Record,
+        #[deku(ctx = "*len as usize")]
+        second: Record,
+    }
+
+    let data: Vec<u8> = vec![0x03, 0x01, 0xAA, 0xAA, 0x02, 0xBB, 0xBB];
+    let ret_read = Table::try_from(data.as_slice()).unwrap();
+    assert_eq!(
+        Table {
+            len: 3,
+            first: Record { id: 1 },
+            second: Record { id: 2 },
+        },
+        ret_read
+    );
+
+    let ret_write: Vec<u8> = ret_read.try_into().unwrap();
+    assert_eq!(vec![0x03, 0x01, 0x00, 0x00, 0x02, 0x00, 0x00], ret_write);
+
+    // a record smaller than its fields
+    let data: Vec<u8> = vec![0x00, 0x01, 0x02];
+    assert!(matches!(
+        Table::try_from(data.as_slice()),
+        Err(DekuError::Assertion(_))
+    ));
+}
+
+#[test]
+fn test_struct_bytes_exceeded() {
+    #[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+    #[deku(bytes = 3)]
+    struct Message {
+        #[deku(update = "self.data.len()")]
+        len: u8,
+        #[deku(count = "len")]
+        data: Vec<u8>,
+    }
+
+    let data: Vec<u8> = vec![0x02, 0xAA, 0xBB];
+    let ret_read = Message::try_from(data.as_slice()).unwrap();
+    assert_eq!(
+        Message {
+            len: 2,
+            data: vec![0xAA, 0xBB],
+        },
+        ret_read
+    );
+
+    let data: Vec<u8> = vec![0x03, 0xAA, 0xBB, 0xCC];
+    let ret_read = Message::try_from(data.as_slice());
+    #[cfg(feature = "descriptive-errors")]
+    assert_eq!(
+        DekuError::Assertion("Struct exceeds declared size: Message: 32 bits, expected 24".into()),
+        ret_read.unwrap_err()
+    );
+    #[cfg(not(feature = "descriptive-errors"))]
+    assert!(matches!(ret_read, Err(DekuError::Assertion(_))));
+
+    let value = Message {
+        len: 3,
+        data: vec![0xAA, 0xBB, 0xCC],
+    };
+    assert!(matches!(value.to_bytes(), Err(DekuError::Assertion(_))));
+}
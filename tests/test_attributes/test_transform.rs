@@ -0,0 +1,99 @@
+use deku::prelude::*;
+use deku::transform::Transform;
+
+/// XOR with a repeating key
+struct Xor;
+
+impl Transform<&[u8]> for Xor {
+    fn decode(&self, input: &[u8], key: &[u8]) -> Result<Vec<u8>, DekuError> {
+        if key.is_empty() {
+            return Err(deku::deku_error!(DekuError::InvalidParam, "empty key"));
+        }
+        Ok(input
+            .iter()
+            .zip(key.iter().cycle())
+            .map(|(b, k)| b ^ k)
+            .collect())
+    }
+
+    fn encode(&self, input: &[u8], key: &[u8]) -> Result<Vec<u8>, DekuError> {
+        self.decode(input, key)
+    }
+}
+
+/// Add a constant to every byte
+struct Add(u8);
+
+impl Transform for Add {
+    fn decode(&self, input: &[u8], _: ()) -> Result<Vec<u8>, DekuError> {
+        Ok(input.iter().map(|b| b.wrapping_sub(self.0)).collect())
+    }
+
+    fn encode(&self, input: &[u8], _: ()) -> Result<Vec<u8>, DekuError> {
+        Ok(input.iter().map(|b| b.wrapping_add(self.0)).collect())
+    }
+}
+
+#[derive(PartialEq, Debug, DekuRead, DekuWrite)]
+#[deku(endian = "big", ctx = "key: &[u8]")]
+struct Record {
+    len: u8,
+    #[deku(
+        transform = "Xor",
+        transform_ctx = "key",
+        bytes = "len",
+        count = "len / 2"
+    )]
+    values: Vec<u16>,
+    #[deku(transform = "Add(*len)", bytes = 1)]
+    check: u8,
+}
+
+#[test]
+fn test_transform() {
+    let key: &[u8] = &[0xAA, 0x55];
+    let data: &[u8] = &[0x04, 0xAB, 0x57, 0xAA, 0x56, 0x09];
+
+    let mut cursor = std::io::Cursor::new(data);
+    let mut reader = Reader::new(&mut cursor);
+    let value = Record::from_reader_with_ctx(&mut reader, key).unwrap();
+    assert_eq!(
+        Record {
+            len: 4,
+            values: vec![0x0102, 0x0003],
+            check: 0x05,
+        },
+        value
+    );
+
+    let mut out = Vec::new();
+    let mut writer = Writer::new(std::io::Cursor::new(&mut out));
+    value.to_writer(&mut writer, key).unwrap();
+    assert_eq!(data, out);
+}
+
+#[test]
+fn test_transform_error() {
+    let data: &[u8] = &[0x02, 0xAB, 0x57, 0x09];
+    let mut cursor = std::io::Cursor::new(data);
+    let mut reader = Reader::new(&mut cursor);
+    assert!(matches!(
+        Record::from_reader_with_ctx(&mut reader, &[]),
+        Err(DekuError::InvalidParam(_))
+    ));
+}
+
+#[test]
+fn test_transform_len_mismatch() {
+    let value = Record {
+        len: 3,
+        values: vec![0x0102, 0x0003],
+        check: 0x05,
+    };
+    let mut out = Vec::new();
+    let mut writer = Writer::new(std::io::Cursor::new(&mut out));
+    assert!(matches!(
+        value.to_writer(&mut writer, &[0xAA, 0x55]),
+        Err(DekuError::InvalidParam(_))
+    ));
+}
@@ -0,0 +1,125 @@
+#![cfg(all(feature = "bytes", feature = "std"))]
+
+use bytes::{Bytes, BytesMut};
+use deku::buf::DekuCodec;
+use deku::prelude::*;
+use hexlit::hex;
+
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "big")]
+struct Frame {
+    #[deku(assert = "*kind != 0xff")]
+    kind: u8,
+    #[deku(update = "self.payload.len()")]
+    len: u8,
+    #[deku(count = "len")]
+    payload: Bytes,
+}
+
+fn frame(kind: u8, payload: &[u8]) -> Frame {
+    Frame {
+        kind,
+        len: payload.len() as u8,
+        payload: Bytes::copy_from_slice(payload),
+    }
+}
+
+#[test]
+fn test_reader_shared() {
+    let input = Bytes::copy_from_slice(&hex!("0103aabbcc"));
+    let mut reader = Reader::new_shared(input.clone());
+    let value = Frame::from_reader_with_ctx(&mut reader, ()).unwrap();
+    assert_eq!(frame(1, &hex!("aabbcc")), value);
+    assert_eq!(value.payload.as_ptr(), input[2..].as_ptr());
+}
+
+#[test]
+fn test_codec_decode() {
+    let mut codec = DekuCodec::<Frame>::new();
+    let stream = hex!("0102aabb 0201cc 0303ddeeff");
+    let mut src = BytesMut::new();
+    let mut frames = Vec::new();
+
+    // one byte at a time
+    for byte in stream {
+        src.extend_from_slice(&[byte]);
+        while let Some(frame) = codec.decode(&mut src).unwrap() {
+            frames.push(frame);
+        }
+        assert!(src.is_empty());
+    }
+    assert_eq!(
+        frames,
+        [
+            frame(1, &hex!("aabb")),
+            frame(2, &hex!("cc")),
+            frame(3, &hex!("ddeeff")),
+        ]
+    );
+    assert_eq!(codec.decode_eof(&mut src).unwrap(), None);
+}
+
+#[test]
+fn test_codec_decode_shared() {
+    let mut codec = DekuCodec::<Frame>::new();
+    let mut src = BytesMut::from(&hex!("0102aabb 0201cc 03")[..]);
+    let start = src.as_ptr();
+
+    let first = codec.decode(&mut src).unwrap().unwrap();
+    let second = codec.decode(&mut src).unwrap().unwrap();
+    assert_eq!(first.payload.as_ptr(), start.wrapping_add(2));
+    assert_eq!(second.payload.as_ptr(), start.wrapping_add(6));
+    assert_eq!(codec.decode(&mut src).unwrap(), None);
+    assert_eq!(codec.pending(), hex!("03"));
+
+    // the rest of a frame still shared with decoded ones
+    src.extend_from_slice(&hex!("01dd"));
+    assert_eq!(codec.decode(&mut src).unwrap(), Some(frame(3, &hex!("dd"))));
+    assert_eq!(first, frame(1, &hex!("aabb")));
+}
+
+#[test]
+fn test_codec_decode_errors() {
+    let mut codec = DekuCodec::<Frame>::new();
+    let mut src = BytesMut::from(&hex!("0105aabb")[..]);
+    assert_eq!(codec.decode(&mut src).unwrap(), None);
+    assert!(matches!(
+        codec.decode_eof(&mut src),
+        Err(DekuError::Incomplete(_))
+    ));
+
+    let mut codec = DekuCodec::<Frame>::new();
+    let mut src = BytesMut::from(&hex!("ff00")[..]);
+    assert!(matches!(
+        codec.decode(&mut src),
+        Err(DekuError::Assertion(_))
+    ));
+    assert_eq!(codec.pending(), hex!("ff00"));
+}
+
+#[test]
+fn test_codec_encode() {
+    let mut codec = DekuCodec::<Frame>::new();
+    let mut dst = BytesMut::new();
+    codec.encode(&frame(1, &hex!("aabb")), &mut dst).unwrap();
+    codec.encode(&frame(2, &hex!("")), &mut dst).unwrap();
+    assert_eq!(dst, hex!("0102aabb 0200")[..]);
+
+    // nothing is left of a frame which failed to encode
+    assert!(codec.encode(&frame(0xff, &hex!("cc")), &mut dst).is_err());
+    assert_eq!(dst, hex!("0102aabb 0200")[..]);
+}
+
+#[test]
+fn test_to_io_slices_bytes() {
+    let payload = Bytes::from(vec![0xaa; 300]);
+    let value = Frame {
+        kind: 1,
+        len: 0,
+        payload: payload.clone(),
+    };
+    let writer = value.to_io_slices().unwrap();
+    let slices: Vec<&[u8]> = writer.slices().collect();
+    assert_eq!(slices.len(), 2);
+    assert_eq!(slices[1].as_ptr(), payload.as_ptr());
+}
@@ -0,0 +1,19 @@
+use deku::prelude::*;
+
+// the bulk layout of a primitive can't be used for another type
+struct Custom(bool);
+
+impl DekuReader<'_> for Custom {
+    fn from_reader_with_ctx<R: deku::no_std_io::Read + deku::no_std_io::Seek>(
+        reader: &mut Reader<R>,
+        _: (),
+    ) -> Result<Self, DekuError> {
+        Ok(Self(u8::from_reader_with_ctx(reader, ())? != 0))
+    }
+
+    fn bulk_layout(_: ()) -> Option<deku::BulkLayout<Self>> {
+        <u8 as DekuReader<'_>>::bulk_layout(())
+    }
+}
+
+fn main() {}
@@ -0,0 +1,24 @@
+use deku::prelude::*;
+
+// unknown compression
+#[derive(DekuRead)]
+struct Test1 {
+    #[deku(compress = "brotli")]
+    a: Vec<u8>,
+}
+
+// `compress` conflicts with `reader`
+#[derive(DekuRead)]
+struct Test2 {
+    #[deku(compress = "zlib", reader = "u8::from_reader_with_ctx(deku::reader, ())")]
+    a: u8,
+}
+
+// `compress_limit` requires `compress`
+#[derive(DekuRead)]
+struct Test3 {
+    #[deku(compress_limit = "1024")]
+    a: u8,
+}
+
+fn main() {}
@@ -24,20 +24,13 @@ struct Test4 {
     a: u8,
 }
 
-// test `bits` only allowed on enum
+// test conflict `bits` and `bytes` on struct
 #[derive(DekuRead)]
-#[deku(bits = 1)]
+#[deku(bits = 8, bytes = 1)]
 struct Test5 {
     a: u8,
 }
 
-// test `bytes` only allowed on enum
-#[derive(DekuRead)]
-#[deku(bits = 1)]
-struct Test6 {
-    a: u8,
-}
-
 // test `id_type` only allowed on enum
 #[derive(DekuRead)]
 #[deku(id_type = "test")]
@@ -0,0 +1,31 @@
+use deku::prelude::*;
+
+// `pad_value` conflicts with `pad_pattern`
+#[derive(DekuRead)]
+struct Test1 {
+    #[deku(pad_bytes_before = "1", pad_value = "0xff", pad_pattern = b"\xde\xad")]
+    a: u8,
+}
+
+// `pad_value` requires a `pad_*` attribute
+#[derive(DekuRead)]
+struct Test2 {
+    #[deku(pad_value = "0xff")]
+    a: u8,
+}
+
+// `pad_pattern` cannot be empty
+#[derive(DekuWrite)]
+#[deku(pad_pattern = b"")]
+struct Test3 {
+    a: u8,
+}
+
+// `pad_value` must fit in a byte
+#[derive(DekuWrite)]
+struct Test4 {
+    #[deku(pad_bytes_before = "1", pad_value = "0x1ff")]
+    a: u8,
+}
+
+fn main() {}
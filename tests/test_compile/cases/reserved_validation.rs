@@ -0,0 +1,24 @@
+use deku::prelude::*;
+
+// `value` requires `reserved`
+#[derive(DekuRead)]
+struct Test1 {
+    #[deku(value = 1)]
+    a: u8,
+}
+
+// `reserved` conflicts with `bits`
+#[derive(DekuRead)]
+struct Test2 {
+    #[deku(reserved = 4, bits = 4)]
+    a: (),
+}
+
+// unknown `check`
+#[derive(DekuRead)]
+struct Test3 {
+    #[deku(reserved = 4, check = "loose")]
+    a: (),
+}
+
+fn main() {}
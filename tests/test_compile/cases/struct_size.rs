@@ -0,0 +1,19 @@
+use deku::prelude::*;
+
+// fields exceed the declared struct size
+#[derive(DekuRead, DekuWrite, DekuSize)]
+#[deku(bits = 12)]
+struct Test1 {
+    #[deku(bits = 4)]
+    a: u8,
+    b: u16,
+}
+
+// fields exceed the declared struct size
+#[derive(DekuRead, DekuWrite, DekuSize)]
+#[deku(bytes = 2, magic = b"\x01")]
+struct Test2 {
+    a: u16,
+}
+
+fn main() {}
@@ -0,0 +1,17 @@
+use deku::prelude::*;
+
+// `transform_ctx` requires `transform`
+#[derive(DekuRead)]
+struct Test1 {
+    #[deku(transform_ctx = "()")]
+    a: u8,
+}
+
+// `transform` conflicts with `compress`
+#[derive(DekuRead)]
+struct Test2 {
+    #[deku(transform = "Xor", compress = "zlib")]
+    a: u8,
+}
+
+fn main() {}
@@ -0,0 +1,26 @@
+use deku::prelude::*;
+
+// `try_variants` is only for enums
+#[derive(DekuRead)]
+#[deku(try_variants)]
+struct Test1 {
+    a: u8,
+}
+
+// `try_variants` conflicts with `id_type`
+#[derive(DekuRead)]
+#[deku(try_variants, id_type = "u8")]
+enum Test2 {
+    A(u8),
+}
+
+// `try_variants` conflicts with `id` on variants
+#[derive(DekuRead)]
+#[deku(try_variants)]
+enum Test3 {
+    A(u8),
+    #[deku(id = "1")]
+    B(u16),
+}
+
+fn main() {}
@@ -0,0 +1,37 @@
+use deku::prelude::*;
+
+#[derive(DekuWrite)]
+struct Seeks {
+    #[deku(seek_from_current = "1")]
+    byte: u8,
+}
+
+#[derive(DekuWrite)]
+struct Nested {
+    inner: Seeks,
+}
+
+struct Custom;
+
+impl DekuWriter for Custom {
+    fn to_writer<W: deku::no_std_io::Write + deku::no_std_io::Seek>(
+        &self,
+        _: &mut Writer<W>,
+        _: (),
+    ) -> Result<(), DekuError> {
+        Ok(())
+    }
+}
+
+#[derive(DekuWrite)]
+struct HasCustom {
+    custom: Custom,
+}
+
+fn main() {
+    let mut out = [0u8; 8];
+    let mut writer = Writer::new(&mut out[..]);
+    let _ = Seeks { byte: 1 }.to_writer_no_seek(&mut writer, ());
+    let _ = Nested { inner: Seeks { byte: 1 } }.to_writer_no_seek(&mut writer, ());
+    let _ = HasCustom { custom: Custom }.to_writer_no_seek(&mut writer, ());
+}
@@ -440,3 +440,93 @@ fn test_repr_assignment_with_id_via_ctx() {
         Message::from_reader((&mut cursor, 0)).unwrap().1
     );
 }
+
+#[cfg(feature = "bits")]
+#[derive(PartialEq, Debug, DekuRead, DekuWrite, DekuSize)]
+#[deku(try_variants)]
+enum TryVariants {
+    Short {
+        #[deku(bits = 4, assert_eq = "0xa")]
+        tag: u8,
+        #[deku(bits = 4)]
+        value: u8,
+    },
+    Long(
+        #[deku(bits = 4, assert = "*field_0 != 0xa")] u8,
+        #[deku(bits = 12, endian = "big")] u16,
+    ),
+}
+
+#[cfg(all(feature = "bits", feature = "descriptive-errors"))]
+#[rstest(input, expected,
+    case(&hex!("a5"), TryVariants::Short { tag: 0xa, value: 0x5 }),
+    case(&hex!("b123"), TryVariants::Long(0xb, 0x123)),
+
+    #[should_panic(expected = "Incomplete(NeedSize { bits: 12 })")]
+    case(&hex!("b1"), TryVariants::Long(0xb, 0x1)),
+)]
+fn test_try_variants(input: &[u8], expected: TryVariants) {
+    let ret_read = TryVariants::try_from(input).unwrap();
+    assert_eq!(expected, ret_read);
+
+    let ret_write: Vec<u8> = ret_read.try_into().unwrap();
+    assert_eq!(input, ret_write);
+
+    assert_eq!(TryVariants::SIZE_BITS, 16);
+}
+
+#[cfg(feature = "bits")]
+#[test]
+fn test_try_variants_incomplete() {
+    // `Long` needs more data, which a stream could still provide
+    let input = hex!("b1");
+    let mut cursor = Cursor::new(&input);
+    let mut reader = Reader::new(&mut cursor);
+    let err = TryVariants::from_reader_with_ctx(&mut reader, ()).unwrap_err();
+    assert_eq!(DekuError::Incomplete(deku::error::NeedSize::new(12)), err);
+
+    assert!(matches!(
+        TryVariants::from_bytes((&[], 0)),
+        Err(DekuError::Incomplete(_))
+    ));
+}
+
+#[test]
+fn test_try_variants_last_error() {
+    #[derive(PartialEq, Debug, DekuRead)]
+    #[deku(try_variants)]
+    enum Tagged {
+        A(#[deku(assert_eq = "1")] u8),
+        B(#[deku(assert_eq = "2")] u8),
+    }
+
+    let err = Tagged::from_bytes((&[3], 0)).unwrap_err();
+    assert!(matches!(err, DekuError::Parse(_)));
+    #[cfg(all(feature = "std", feature = "descriptive-errors"))]
+    assert!(err.to_string().ends_with(
+        r#"last error: Assertion("Field failed assertion: Tagged.field_0: field_0 == 2")"#
+    ));
+}
+
+#[cfg(feature = "bits")]
+#[test]
+fn test_try_variants_leftover() {
+    // the variants are tried from the middle of a byte
+    #[derive(PartialEq, Debug, DekuRead)]
+    struct Container {
+        #[deku(bits = 4)]
+        first: u8,
+        inner: TryVariants,
+    }
+
+    let input = hex!("1b0120");
+    let (rest, ret_read) = Container::from_bytes((&input, 0)).unwrap();
+    assert_eq!(rest, (&input[2..], 4));
+    assert_eq!(
+        Container {
+            first: 0x1,
+            inner: TryVariants::Long(0xb, 0x012),
+        },
+        ret_read
+    );
+}
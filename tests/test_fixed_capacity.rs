@@ -0,0 +1,84 @@
+#![cfg(all(feature = "heapless", feature = "arrayvec", feature = "std"))]
+
+use arrayvec::ArrayVec;
+use deku::prelude::*;
+use hexlit::hex;
+
+#[derive(Debug, PartialEq, DekuRead, DekuWrite, DekuSize)]
+#[deku(endian = "big")]
+struct Record {
+    len: u8,
+    #[deku(count = "len")]
+    data: heapless::Vec<u8, 4>,
+    #[deku(until = "|b: &u8| *b == 0")]
+    name: heapless::String<8>,
+    #[deku(bytes_read = "4")]
+    values: ArrayVec<u16, 2>,
+}
+
+#[test]
+fn test_fixed_capacity() {
+    let data = hex!("02aabb6869000102030405");
+    let (rest, record) = Record::from_bytes((&data, 0)).unwrap();
+    assert_eq!((rest.0.len(), rest.1), (1, 0));
+    assert_eq!(
+        Record {
+            len: 2,
+            data: heapless::Vec::from_slice(&[0xaa, 0xbb]).unwrap(),
+            name: heapless::String::try_from("hi\0").unwrap(),
+            values: [0x0102, 0x0304].into_iter().collect(),
+        },
+        record
+    );
+
+    // the maximum size, every container full
+    assert_eq!(Record::SIZE_BYTES, Some(1 + 4 + 8 + 4));
+    let mut buf = [0; Record::SIZE_BYTES.unwrap()];
+    let written = record.to_slice(&mut buf).unwrap();
+    assert_eq!(&data[..data.len() - 1], &buf[..written]);
+}
+
+#[test]
+fn test_fixed_capacity_exceeded() {
+    let data = hex!("05aabbccddee00");
+    assert!(matches!(
+        Record::from_bytes((&data, 0)),
+        Err(DekuError::Parse(_))
+    ));
+
+    let data = hex!("00616263646566676800");
+    assert!(matches!(
+        Record::from_bytes((&data, 0)),
+        Err(DekuError::Parse(_))
+    ));
+}
+
+#[test]
+fn test_fixed_capacity_partial_element() {
+    // the window ends in the middle of the second element
+    #[derive(Debug, PartialEq, DekuRead)]
+    #[deku(endian = "big")]
+    struct Heapless {
+        #[deku(bytes_read = "3")]
+        values: heapless::Vec<u16, 8>,
+        tail: u8,
+    }
+
+    #[derive(Debug, PartialEq, DekuRead)]
+    #[deku(endian = "big")]
+    struct Array {
+        #[deku(bytes_read = "3")]
+        values: ArrayVec<u16, 8>,
+        tail: u8,
+    }
+
+    let data = hex!("01020304050607");
+    assert!(matches!(
+        Heapless::from_bytes((&data, 0)),
+        Err(DekuError::Parse(_))
+    ));
+    assert!(matches!(
+        Array::from_bytes((&data, 0)),
+        Err(DekuError::Parse(_))
+    ));
+}
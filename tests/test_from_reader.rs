@@ -72,3 +72,71 @@ fn test_from_reader_enum() {
     assert_eq!(first_amt_read, 8);
     assert_eq!(TestDeku::VariantA(0b0110), ret_read);
 }
+
+#[test]
+fn test_from_reader_peek() {
+    use deku::ctx::Endian;
+
+    /// A length that is either one byte, or `0xff` followed by a big endian u16
+    fn read_len<R: std::io::Read + std::io::Seek>(
+        reader: &mut Reader<R>,
+    ) -> Result<u16, DekuError> {
+        if reader.peek::<u8, _>(())? == 0xff {
+            let _ = u8::from_reader_with_ctx(reader, ())?;
+            u16::from_reader_with_ctx(reader, Endian::Big)
+        } else {
+            u8::from_reader_with_ctx(reader, ()).map(u16::from)
+        }
+    }
+
+    #[derive(Debug, PartialEq, DekuRead)]
+    struct TestDeku {
+        #[deku(reader = "read_len(deku::reader)")]
+        len: u16,
+        data: u8,
+    }
+
+    let test_data = [0x02, 0xaa, 0xff, 0x01, 0x00, 0xbb];
+    let mut s = NoSeek::new(&test_data[..]);
+    let (amt_read, ret_read) = TestDeku::from_reader((&mut s, 0)).unwrap();
+    assert_eq!(amt_read, 16);
+    assert_eq!(TestDeku { len: 2, data: 0xaa }, ret_read);
+
+    let (amt_read, ret_read) = TestDeku::from_reader((&mut s, 0)).unwrap();
+    assert_eq!(amt_read, 32);
+    assert_eq!(
+        TestDeku {
+            len: 0x100,
+            data: 0xbb
+        },
+        ret_read
+    );
+}
+
+#[test]
+fn test_from_reader_peek_padded() {
+    #[derive(Debug, PartialEq, DekuRead)]
+    struct Padded {
+        a: u8,
+        #[deku(pad_bytes_before = "1")]
+        b: u8,
+        #[deku(bits = 4, pad_bits_before = "2", pad_bits_after = "10")]
+        c: u8,
+    }
+
+    fn peek_then_read<R: std::io::Read + std::io::Seek>(reader: &mut Reader<R>) {
+        let expected = Padded {
+            a: 0x01,
+            b: 0x03,
+            c: 0b1010,
+        };
+        assert_eq!(expected, reader.peek::<Padded, _>(()).unwrap());
+        assert_eq!(0, reader.bits_read);
+        assert_eq!(expected, Padded::from_reader_with_ctx(reader, ()).unwrap());
+        assert_eq!(u8::from_reader_with_ctx(reader, ()).unwrap(), 0xee);
+    }
+
+    let test_data = [0x01, 0x02, 0x03, 0b0010_1000, 0x00, 0xee];
+    peek_then_read(&mut Reader::new(Cursor::new(&test_data[..])));
+    peek_then_read(&mut Reader::new(NoSeek::new(&test_data[..])));
+}
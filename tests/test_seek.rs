@@ -234,3 +234,48 @@ fn test_seek_error_preserves_bits_read() {
     assert!(result.is_err());
     assert_eq!(reader.bits_read, bits_before);
 }
+
+#[derive(DekuRead, DekuWrite, Debug, PartialEq, Eq)]
+pub struct SeekForwardNoSeek {
+    skip: u8,
+    #[deku(seek_from_current = "*skip")]
+    byte: u8,
+    #[deku(pad_bytes_before = "1")]
+    after: u8,
+}
+
+#[rstest(input, expected,
+    case(&hex!("01 00 aa 00 bb"), SeekForwardNoSeek { skip: 1, byte: 0xaa, after: 0xbb }),
+    case(&hex!("00 aa 00 bb"), SeekForwardNoSeek { skip: 0, byte: 0xaa, after: 0xbb }),
+)]
+fn test_seek_forward_no_seek(input: &[u8], expected: SeekForwardNoSeek) {
+    let mut cursor = NoSeek::new(input);
+    let mut reader = Reader::new(&mut cursor);
+    let ret_read = SeekForwardNoSeek::from_reader_with_ctx(&mut reader, ()).unwrap();
+    assert_eq!(ret_read, expected);
+
+    let mut buf = vec![];
+    let mut cursor = NoSeek::new(&mut buf);
+    let mut writer = Writer::new(&mut cursor);
+    ret_read.to_writer(&mut writer, ()).unwrap();
+    assert_eq!(buf, input);
+}
+
+#[derive(DekuRead, Debug, PartialEq, Eq)]
+pub struct SeekBackwardNoSeek {
+    byte: u8,
+    #[deku(seek_rewind)]
+    again: u8,
+}
+
+#[test]
+fn test_seek_backward_no_seek() {
+    let input = hex!("aabb");
+    let mut cursor = NoSeek::new(input.as_slice());
+    let mut reader = Reader::new(&mut cursor);
+    let res = SeekBackwardNoSeek::from_reader_with_ctx(&mut reader, ());
+    assert!(matches!(
+        res,
+        Err(DekuError::Io(std::io::ErrorKind::Unsupported))
+    ));
+}
@@ -0,0 +1,122 @@
+#![cfg(all(feature = "bits", feature = "std"))]
+
+use deku::prelude::*;
+
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+#[deku(
+    ctx = "endian: deku::ctx::Endian",
+    ctx_default = "deku::ctx::Endian::Big",
+    endian = "endian"
+)]
+struct Inner {
+    #[deku(update = "self.data.len()")]
+    len: u16,
+    #[deku(count = "len")]
+    data: Vec<u8>,
+}
+
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "little")]
+struct Packet {
+    #[deku(bits = 4)]
+    version: u8,
+    #[deku(bits = 4)]
+    flags: u8,
+    id: u32,
+    inner: Inner,
+    block: [u8; 512],
+    #[deku(cond = "*flags & 1 == 1", count = "200")]
+    trailer: Option<Vec<u16>>,
+}
+
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+#[deku(id_type = "u8")]
+enum Message {
+    #[deku(id = 0)]
+    Ping(u8),
+    #[deku(id = 1)]
+    Data(Inner),
+}
+
+fn is_borrowed(slices: &[&[u8]], data: &[u8]) -> bool {
+    slices.iter().any(|s| core::ptr::eq(*s, data))
+}
+
+#[test]
+fn test_to_io_slices_struct() {
+    let packet = Packet {
+        version: 1,
+        flags: 1,
+        id: 0xdeadbeef,
+        inner: Inner {
+            len: 300,
+            data: vec![0xaa; 300],
+        },
+        block: [0xbb; 512],
+        trailer: Some(vec![0x0102; 200]),
+    };
+    let writer = packet.to_io_slices().unwrap();
+    let slices: Vec<&[u8]> = writer.slices().collect();
+
+    assert!(is_borrowed(&slices, &packet.inner.data));
+    assert!(is_borrowed(&slices, &packet.block));
+    // header, data, block and trailer, which is only borrowed without a byte swap
+    assert_eq!(slices.len(), 4);
+    assert_eq!(writer.to_vec(), packet.to_bytes().unwrap());
+
+    let mut out = Vec::new();
+    writer.write_to(&mut out).unwrap();
+    assert_eq!(out, packet.to_bytes().unwrap());
+}
+
+#[test]
+fn test_to_io_slices_small() {
+    // below the threshold, everything is coalesced into one owned chunk
+    let inner = Inner {
+        len: 3,
+        data: vec![1, 2, 3],
+    };
+    let writer = inner.to_io_slices().unwrap();
+    let slices: Vec<&[u8]> = writer.slices().collect();
+    assert_eq!(slices, [&[0x00, 0x03, 0x01, 0x02, 0x03][..]]);
+}
+
+#[test]
+fn test_to_io_slices_enum() {
+    let message = Message::Data(Inner {
+        len: 256,
+        data: vec![0xcc; 256],
+    });
+    let writer = message.to_io_slices().unwrap();
+    let slices: Vec<&[u8]> = writer.slices().collect();
+    let Message::Data(inner) = &message else {
+        unreachable!()
+    };
+    assert!(is_borrowed(&slices, &inner.data));
+    assert_eq!(writer.to_vec(), message.to_bytes().unwrap());
+
+    let message = Message::Ping(7);
+    assert_eq!(message.to_io_slices().unwrap().to_vec(), [0x00, 0x07]);
+}
+
+#[test]
+fn test_to_io_slices_unaligned() {
+    #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+    struct Unaligned {
+        #[deku(bits = 4)]
+        nibble: u8,
+        #[deku(count = "512")]
+        data: Vec<u8>,
+    }
+
+    // leftover bits shift the data, so it is copied
+    let value = Unaligned {
+        nibble: 0xf,
+        data: vec![0x11; 512],
+    };
+    let writer = value.to_io_slices().unwrap();
+    let slices: Vec<&[u8]> = writer.slices().collect();
+    assert!(!is_borrowed(&slices, &value.data));
+    assert_eq!(writer.len(), 513);
+    assert_eq!(writer.to_vec(), value.to_bytes().unwrap());
+}
@@ -0,0 +1,99 @@
+#![cfg(feature = "std")]
+
+use core::str::FromStr;
+
+use deku::ctx::Endian;
+use deku::prelude::*;
+
+#[test]
+fn test_modbus_registers() {
+    #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+    #[deku(endian = "cdab")]
+    struct Registers {
+        status: u16,
+        voltage: f32,
+        energy: u32,
+        #[deku(endian = "big")]
+        serial: u32,
+        #[deku(endian = "little-word-swapped")]
+        offset: i32,
+    }
+
+    let data = [
+        0x00, 0x01, // status
+        0xE9, 0x79, 0x42, 0xF6, // 123.456
+        0x56, 0x78, 0x12, 0x34, // 0x12345678
+        0x12, 0x34, 0x56, 0x78, // 0x12345678
+        0xFF, 0xFF, 0xFE, 0xFF, // -2
+    ];
+
+    let (_, value) = Registers::from_bytes((&data, 0)).unwrap();
+    assert_eq!(
+        Registers {
+            status: 1,
+            voltage: 123.456,
+            energy: 0x1234_5678,
+            serial: 0x1234_5678,
+            offset: -2,
+        },
+        value
+    );
+    assert_eq!(data.to_vec(), value.to_bytes().unwrap());
+}
+
+#[test]
+#[cfg(feature = "bits")]
+fn test_word_swapped_bits() {
+    #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+    #[deku(endian = "big-word-swapped")]
+    struct Packed {
+        #[deku(bits = 4)]
+        flags: u8,
+        #[deku(bits = 32)]
+        value: u32,
+        #[deku(bits = 4)]
+        tail: u8,
+    }
+
+    let data = [0xA5, 0x67, 0x81, 0x23, 0x4B];
+    let (_, value) = Packed::from_bytes((&data, 0)).unwrap();
+    assert_eq!(
+        Packed {
+            flags: 0xA,
+            value: 0x1234_5678,
+            tail: 0xB,
+        },
+        value
+    );
+    assert_eq!(data.to_vec(), value.to_bytes().unwrap());
+}
+
+#[test]
+fn test_word_swapped_ctx() {
+    #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+    #[deku(ctx = "endian: deku::ctx::Endian", endian = "endian")]
+    struct Value {
+        value: u32,
+    }
+
+    let endian = Endian::from_str("badc").unwrap();
+    let data = [0x34, 0x12, 0x78, 0x56];
+    let mut cursor = std::io::Cursor::new(&data);
+    let mut reader = Reader::new(&mut cursor);
+    let value = Value::from_reader_with_ctx(&mut reader, endian).unwrap();
+    assert_eq!(0x1234_5678, value.value);
+
+    let mut out = vec![];
+    let mut writer = Writer::new(std::io::Cursor::new(&mut out));
+    value.to_writer(&mut writer, endian).unwrap();
+    writer.finalize().unwrap();
+    assert_eq!(data.to_vec(), out);
+}
+
+#[test]
+fn test_word_swapped_byte_order() {
+    assert!(Endian::Big.is_be() && !Endian::Big.is_le());
+    assert!(Endian::Little.is_le() && !Endian::Little.is_be());
+    assert!(Endian::BigWordSwapped.is_be() && !Endian::BigWordSwapped.is_le());
+    assert!(Endian::LittleWordSwapped.is_le() && !Endian::LittleWordSwapped.is_be());
+}
@@ -0,0 +1,88 @@
+#![cfg(feature = "std")]
+
+use deku::prelude::*;
+use hexlit::hex;
+
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+#[deku(ctx = "endian: deku::ctx::Endian", endian = "endian")]
+struct Header {
+    kind: u8,
+    #[deku(pad_bytes_before = "1")]
+    len: u16,
+}
+
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+#[deku(endian = "big")]
+struct Packet {
+    header: Header,
+    #[deku(count = "header.len")]
+    data: Vec<u8>,
+    #[deku(skip, default = "0")]
+    cached: u32,
+}
+
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+#[deku(id_type = "u8")]
+enum Tree {
+    #[deku(id = 0)]
+    Leaf(u8),
+    #[deku(id = 1)]
+    Node(Box<Tree>, Box<Tree>),
+}
+
+#[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+struct Generic<T: for<'a> DekuReader<'a> + DekuWriter> {
+    value: T,
+}
+
+#[test]
+fn test_to_writer_no_seek() {
+    let packet = Packet {
+        header: Header { kind: 1, len: 2 },
+        data: vec![0xaa, 0xbb],
+        cached: 0,
+    };
+
+    let mut out = Vec::new();
+    let mut writer = Writer::new(&mut out);
+    packet.to_writer_no_seek(&mut writer, ()).unwrap();
+    packet.to_writer_no_seek(&mut writer, ()).unwrap();
+    writer.finalize().unwrap();
+    assert_eq!(hex!("01 00 0002 aabb 01 00 0002 aabb").to_vec(), out);
+}
+
+#[test]
+fn test_to_writer_no_seek_recursive() {
+    let tree = Tree::Node(Box::new(Tree::Leaf(1)), Box::new(Tree::Leaf(2)));
+
+    let mut out = Vec::new();
+    let mut writer = Writer::new(&mut out);
+    tree.to_writer_no_seek(&mut writer, ()).unwrap();
+    assert_eq!(tree.to_bytes().unwrap(), out);
+}
+
+#[test]
+fn test_to_writer_no_seek_generic() {
+    let value = Generic { value: 0x0102u16 };
+
+    let mut out = Vec::new();
+    let mut writer = Writer::new(&mut out);
+    value.to_writer_no_seek(&mut writer, ()).unwrap();
+    assert_eq!(value.to_bytes().unwrap(), out);
+}
+
+#[cfg(feature = "bits")]
+#[test]
+fn test_to_writer_no_seek_bits() {
+    #[derive(Debug, PartialEq, DekuRead, DekuWrite)]
+    struct Nibble(#[deku(bits = 4)] u8);
+
+    let mut out = Vec::new();
+    let mut writer = Writer::new(&mut out);
+    Nibble(0xa).to_writer_no_seek(&mut writer, ()).unwrap();
+    Nibble(0xb).to_writer_no_seek(&mut writer, ()).unwrap();
+    Nibble(0xc).to_writer_no_seek(&mut writer, ()).unwrap();
+    assert_eq!(12, writer.bit_position());
+    writer.finalize().unwrap();
+    assert_eq!(hex!("abc0").to_vec(), out);
+}